@@ -0,0 +1,96 @@
+//! Collision broadphase benchmarks. At the default 60 Hz tick rate a simulation tick has a frame
+//! budget of 16.7 ms; `fight_tick` measures whole ticks of a fight with thousands of projectiles in flight.
+
+use std::hint::black_box;
+use std::time::Duration;
+use bevy::prelude::*;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use gun_shield_elements::assets::WeaponRegistry;
+use gun_shield_elements::collision::SpatialIndex;
+use gun_shield_elements::components::{Boss, Collider, Faction, Health, Player, Projectile, ProjectileBundle};
+use gun_shield_elements::constants::GameBoundaries;
+use gun_shield_elements::headless::{headless_app, HeadlessAppExt};
+use gun_shield_elements::resources::EncounterAssets;
+use gun_shield_elements::states::AppState;
+
+/// Evenly spread but irregular positions over the arena
+fn scattered_positions(count: usize) -> Vec<Vec2> {
+    let extent = Vec2::new(GameBoundaries::width(), GameBoundaries::height());
+    (0..count)
+        .map(|i| (Vec2::new((i as f32 * 0.618_034).fract(), (i as f32 * 0.754_878).fract()) - 0.5) * extent)
+        .collect()
+}
+
+fn spatial_index(c: &mut Criterion) {
+    let mut group = c.benchmark_group("spatial_index");
+    for count in [1_000, 5_000, 20_000] {
+        let mut world = World::new();
+        let colliders: Vec<(Entity, Vec2)> = scattered_positions(count)
+            .into_iter()
+            .map(|position| (world.spawn_empty().id(), position))
+            .collect();
+        let mut index = SpatialIndex::default();
+
+        // Rebuild the index, then check what every projectile touches as the collision systems do
+        group.bench_with_input(BenchmarkId::new("rebuild_and_query", count), &colliders, |b, colliders| {
+            b.iter(|| {
+                index.clear();
+                for (entity, position) in colliders {
+                    index.insert(*entity, *position, 6.0);
+                }
+                let touching: usize = colliders.iter().map(|(_, position)| index.query_circle(*position, 6.0).count()).sum();
+                black_box(touching)
+            });
+        });
+    }
+    group.finish();
+}
+
+/// A fight against the first boss with `count` stationary projectiles spread over the arena, half fired by
+/// the player and half by the boss. The player and boss can't die, so the fight lasts the whole benchmark.
+fn fight_with_projectiles(count: usize) -> App {
+    let mut app = headless_app();
+    app.update();
+    let loaded = app.wait_for(Duration::from_secs(10), |world| {
+        let sequence = &world.resource::<EncounterAssets>().sequence;
+        world.resource::<AssetServer>().is_loaded_with_dependencies(sequence)
+            && !world.resource::<Assets<WeaponRegistry>>().is_empty()
+    });
+    assert!(loaded, "encounter sequence and weapon registry should load");
+    app.enter_state(AppState::Game);
+    let spawned = app.wait_for(Duration::from_secs(10), |world| {
+        world.query_filtered::<(), With<Boss>>().iter(world).next().is_some()
+    });
+    assert!(spawned, "boss should spawn");
+
+    let world = app.world_mut();
+    for mut health in world.query_filtered::<&mut Health, Or<(With<Player>, With<Boss>)>>().iter_mut(world) {
+        *health = Health::new(f32::MAX);
+    }
+    let player = world.query_filtered::<Entity, With<Player>>().single(world).expect("player should exist");
+    let boss = world.query_filtered::<Entity, With<Boss>>().single(world).expect("boss should exist");
+    for (i, position) in scattered_positions(count).into_iter().enumerate() {
+        let (faction, owner) = if i % 2 == 0 { (Faction::Player, player) } else { (Faction::Enemy, boss) };
+        world.spawn(ProjectileBundle::new(
+            Projectile::new(1.0, Vec3::X, 1.0e6, faction, owner),
+            0.0,
+            Collider::circle(6.0),
+            position.extend(0.9),
+            Handle::default(),
+            Handle::default(),
+        ));
+    }
+    app
+}
+
+fn fight_tick(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fight_tick");
+    for count in [1_000, 5_000] {
+        let mut app = fight_with_projectiles(count);
+        group.bench_function(BenchmarkId::new("projectiles", count), |b| b.iter(|| app.update()));
+    }
+    group.finish();
+}
+
+criterion_group!(benches, spatial_index, fight_tick);
+criterion_main!(benches);
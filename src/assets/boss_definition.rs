@@ -0,0 +1,319 @@
+use bevy::prelude::*;
+use bevy::asset::{AssetLoader, LoadContext, io::Reader};
+use serde::Deserialize;
+use std::collections::HashMap;
+use crate::events::DamageKind;
+use crate::components::{Collider, ShieldBlockResponse};
+use crate::resources::DifficultyMultipliers;
+
+/// Data-driven boss description loaded from `assets/bosses/*.boss.ron`
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct BossDefinition {
+    /// Display name, shown on the boss health bar
+    pub name: String,
+    /// Which AI drives this boss
+    pub kind: BossKind,
+    pub stats: BossStats,
+    /// Radius of the circular body collider
+    pub collider_radius: f32,
+    /// Main body visual
+    pub body: BossPartDefinition,
+    /// Decorative parts attached to the body
+    #[serde(default)]
+    pub parts: Vec<BossPartLayout>,
+    /// Skills available to the boss AI
+    #[serde(default)]
+    pub skills: Vec<BossSkillDefinition>,
+    /// Damage multipliers per damage kind (missing kinds default to 1.0)
+    #[serde(default)]
+    pub resistances: HashMap<DamageKind, f32>,
+    /// Phases entered as health drops, sorted from highest to lowest threshold when loaded
+    #[serde(default)]
+    pub phases: Vec<BossPhaseDefinition>,
+}
+
+impl BossDefinition {
+    /// Copy of this definition with its health, damage, dash cooldowns and dash windups scaled by a difficulty
+    pub fn scaled(&self, multipliers: &DifficultyMultipliers) -> BossDefinition {
+        let mut definition = self.clone();
+        definition.stats.max_health *= multipliers.boss_health;
+        definition.stats.contact_damage_per_second *= multipliers.boss_damage;
+        for skill in &mut definition.skills {
+            match skill {
+                BossSkillDefinition::Dash(dash) => {
+                    dash.damage *= multipliers.boss_damage;
+                    dash.cooldown *= multipliers.dash_cooldown;
+                    dash.windup *= multipliers.telegraph;
+                }
+                BossSkillDefinition::LayMines(mines) => mines.damage *= multipliers.boss_damage,
+                BossSkillDefinition::FireBullets(bullets) => bullets.projectile.damage *= multipliers.boss_damage,
+            }
+        }
+        for phase in &mut definition.phases {
+            phase.dash_cooldown *= multipliers.dash_cooldown;
+            if let Some(windup) = &mut phase.windup {
+                *windup *= multipliers.telegraph;
+            }
+        }
+        definition
+    }
+
+    /// Get the dash skill parameters, if the boss has one
+    pub fn dash_skill(&self) -> Option<&DashSkillDefinition> {
+        self.skills.iter().find_map(|skill| match skill {
+            BossSkillDefinition::Dash(dash) => Some(dash),
+            _ => None,
+        })
+    }
+
+    /// Get the mine-laying skill parameters, if the boss has one
+    pub fn mine_skill(&self) -> Option<&MineSkillDefinition> {
+        self.skills.iter().find_map(|skill| match skill {
+            BossSkillDefinition::LayMines(mines) => Some(mines),
+            _ => None,
+        })
+    }
+
+    /// Get all bullet pattern skills, in definition order
+    pub fn bullet_skills(&self) -> Vec<BulletPatternSkillDefinition> {
+        self.skills.iter().filter_map(|skill| match skill {
+            BossSkillDefinition::FireBullets(bullets) => Some(bullets.clone()),
+            _ => None,
+        }).collect()
+    }
+}
+
+/// Boss AI archetype, selects the marker component and AI systems
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum BossKind {
+    Mine,
+}
+
+/// Core boss stats
+#[derive(Debug, Clone, Deserialize)]
+pub struct BossStats {
+    pub max_health: f32,
+    /// Constant movement speed toward the player when not using skills
+    pub move_speed: f32,
+    /// Contact damage per second while touching the player
+    pub contact_damage_per_second: f32,
+    /// Minimum time between contact damage applications
+    pub contact_damage_interval: f32,
+}
+
+/// Shape and color of a boss visual part
+#[derive(Debug, Clone, Deserialize)]
+pub struct BossPartDefinition {
+    pub shape: BossPartShape,
+    /// sRGB color components
+    pub color: (f32, f32, f32),
+}
+
+impl BossPartDefinition {
+    pub fn mesh(&self) -> Mesh {
+        match self.shape {
+            BossPartShape::Circle { radius } => Circle::new(radius).into(),
+            BossPartShape::Square { size } => Rectangle::new(size, size).into(),
+        }
+    }
+
+    /// Hitbox matching the mesh, turning with the boss
+    pub fn collider(&self) -> Collider {
+        match self.shape {
+            BossPartShape::Circle { radius } => Collider::circle(radius),
+            BossPartShape::Square { size } => Collider::OrientedBox { half_size: Vec2::splat(size / 2.0) },
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        Color::srgb(self.color.0, self.color.1, self.color.2)
+    }
+}
+
+/// Supported part shapes
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum BossPartShape {
+    Circle { radius: f32 },
+    Square { size: f32 },
+}
+
+/// Layout of decorative parts around the boss body
+#[derive(Debug, Clone, Deserialize)]
+pub enum BossPartLayout {
+    /// `count` parts evenly spaced on a circle of `distance` around the body center
+    Ring {
+        count: u32,
+        distance: f32,
+        part: BossPartDefinition,
+        /// Whether these parts are spikes the boss can detach as mines
+        #[serde(default)]
+        detachable: bool,
+    },
+}
+
+/// Skill list entries
+#[derive(Debug, Clone, Deserialize)]
+pub enum BossSkillDefinition {
+    Dash(DashSkillDefinition),
+    LayMines(MineSkillDefinition),
+    FireBullets(BulletPatternSkillDefinition),
+}
+
+/// Parameters for the dash skill
+#[derive(Debug, Clone, Deserialize)]
+pub struct DashSkillDefinition {
+    pub speed: f32,
+    pub damage: f32,
+    /// Seconds between dashes
+    pub cooldown: f32,
+    /// Distance covered by each dash
+    pub distance: f32,
+    /// Failsafe maximum duration of a dash in seconds
+    pub max_duration: f32,
+    /// Boss only dashes when the player is farther than this
+    pub min_range: f32,
+    /// Telegraph time before the dash starts
+    pub windup: f32,
+    /// Time the boss stays vulnerable after the dash
+    pub recovery: f32,
+}
+
+/// Parameters for the mine-laying skill
+#[derive(Debug, Clone, Deserialize)]
+pub struct MineSkillDefinition {
+    /// Seconds between casts
+    pub cooldown: f32,
+    /// Number of spikes detached per cast
+    pub mines_per_cast: u32,
+    /// Distance from the boss center where detached mines land
+    pub scatter_distance: f32,
+    /// Time before a dropped mine can trigger
+    pub arming_time: f32,
+    /// Player proximity that triggers an armed mine
+    pub trigger_radius: f32,
+    /// Radius of the explosion damage
+    pub explosion_radius: f32,
+    pub damage: f32,
+    /// Time for a detached spike to grow back on the boss
+    pub regrow_time: f32,
+}
+
+/// Parameters for a bullet pattern skill
+#[derive(Debug, Clone, Deserialize)]
+pub struct BulletPatternSkillDefinition {
+    /// Seconds between volleys
+    pub cooldown: f32,
+    /// First phase index in which this skill is used (0 = from the start)
+    #[serde(default)]
+    pub min_phase: usize,
+    pub pattern: BulletPattern,
+    pub projectile: HostileProjectileDefinition,
+}
+
+/// Shape of a bullet volley
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub enum BulletPattern {
+    /// `count` bullets fired at once, evenly spaced around the boss
+    Ring { count: u32 },
+    /// `shots` rounds of `arms` evenly spaced bullets, rotated by `rotation_step` radians each round
+    Spiral { arms: u32, shots: u32, interval: f32, rotation_step: f32 },
+    /// `shots` rounds of `bullets` aimed at the player, fanned over `spread` radians
+    AimedBurst { shots: u32, bullets: u32, spread: f32, interval: f32 },
+}
+
+/// Projectile fired by a bullet pattern skill
+#[derive(Debug, Clone, Deserialize)]
+pub struct HostileProjectileDefinition {
+    pub speed: f32,
+    pub damage: f32,
+    pub radius: f32,
+    /// Seconds before the projectile despawns
+    pub lifetime: f32,
+    /// sRGB color components
+    pub color: (f32, f32, f32),
+    /// What the player's shield does to this projectile
+    pub on_block: ShieldBlockResponse,
+}
+
+impl HostileProjectileDefinition {
+    pub fn color(&self) -> Color {
+        Color::srgb(self.color.0, self.color.1, self.color.2)
+    }
+}
+
+/// A boss phase entered when health drops to `health_threshold` or below
+#[derive(Debug, Clone, Deserialize)]
+pub struct BossPhaseDefinition {
+    /// Fraction of max health (0.0 to 1.0) at which this phase starts
+    pub health_threshold: f32,
+    /// Seconds between dash chains in this phase
+    pub dash_cooldown: f32,
+    /// Number of consecutive dashes per chain
+    #[serde(default = "default_dash_chain")]
+    pub dash_chain: u32,
+    /// Pause between dashes of a chain
+    #[serde(default = "default_chain_delay")]
+    pub chain_delay: f32,
+    /// Constant movement speed when not dashing
+    pub move_speed: f32,
+    #[serde(default)]
+    pub movement: BossMovementPattern,
+    /// Overrides the dash windup (telegraph) time in this phase
+    #[serde(default)]
+    pub windup: Option<f32>,
+}
+
+fn default_dash_chain() -> u32 {
+    1
+}
+
+fn default_chain_delay() -> f32 {
+    0.3
+}
+
+/// How the boss moves between skills
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub enum BossMovementPattern {
+    /// Move straight toward the player
+    #[default]
+    Chase,
+    /// Circle around the player at the given distance
+    Orbit { radius: f32 },
+}
+
+/// Errors produced while loading a boss definition
+#[derive(Debug, thiserror::Error)]
+pub enum BossDefinitionLoaderError {
+    #[error("could not read boss definition: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("could not parse boss definition RON: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+/// Asset loader for `.boss.ron` files
+#[derive(Default)]
+pub struct BossDefinitionLoader;
+
+impl AssetLoader for BossDefinitionLoader {
+    type Asset = BossDefinition;
+    type Settings = ();
+    type Error = BossDefinitionLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut definition = ron::de::from_bytes::<BossDefinition>(&bytes)?;
+        // Phases are entered in order as health drops, whatever order the file lists them in
+        definition.phases.sort_by(|a, b| b.health_threshold.total_cmp(&a.health_threshold));
+        Ok(definition)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["boss.ron"]
+    }
+}
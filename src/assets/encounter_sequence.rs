@@ -0,0 +1,256 @@
+use bevy::prelude::*;
+use bevy::asset::{AssetLoader, LoadContext, io::Reader};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use crate::assets::BossDefinition;
+use crate::components::{Collider, Shield};
+use crate::constants::AppColors;
+
+/// Data-driven campaign loaded from `assets/encounters/*.encounters.ron`:
+/// the encounters fought in order, and what beating each of them unlocks
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct EncounterSequence {
+    /// Weapons available before any encounter is beaten, by name
+    pub starting_weapons: Vec<String>,
+    pub encounters: Vec<EncounterDefinition>,
+}
+
+impl EncounterSequence {
+    /// Whether the encounter at `index` can be played: the first one always, the others once the previous one is beaten
+    pub fn is_unlocked(&self, index: usize, beaten: &BTreeSet<String>) -> bool {
+        match index {
+            0 => !self.encounters.is_empty(),
+            _ => index < self.encounters.len() && beaten.contains(&self.encounters[index - 1].name),
+        }
+    }
+
+    /// Starting weapons plus everything unlocked by the `beaten` encounters
+    pub fn loadout(&self, beaten: &BTreeSet<String>) -> Loadout {
+        let mut loadout = Loadout {
+            weapons: Some(self.starting_weapons.clone()),
+            shield_upgrades: Vec::new(),
+        };
+        let unlocks = self.encounters
+            .iter()
+            .filter(|encounter| beaten.contains(&encounter.name))
+            .flat_map(|encounter| &encounter.unlocks);
+        for unlock in unlocks {
+            match unlock {
+                Unlock::Weapon(name) => {
+                    if let Some(weapons) = &mut loadout.weapons && !weapons.contains(name) {
+                        weapons.push(name.clone());
+                    }
+                }
+                Unlock::Shield(upgrade) => loadout.shield_upgrades.push(*upgrade),
+            }
+        }
+        loadout
+    }
+}
+
+/// One boss fight of the campaign
+#[derive(Debug, Clone, Deserialize)]
+pub struct EncounterDefinition {
+    /// Display name, shown on the level select screen
+    pub name: String,
+    /// Asset path of the boss definition
+    pub boss: String,
+    /// Boss definition handle, filled in by the loader
+    #[serde(skip)]
+    pub boss_definition: Handle<BossDefinition>,
+    /// World position the boss spawns at
+    pub boss_position: (f32, f32),
+    #[serde(default)]
+    pub arena: ArenaDefinition,
+    /// Rewards for beating this encounter
+    #[serde(default)]
+    pub unlocks: Vec<Unlock>,
+}
+
+/// Look of the arena an encounter is fought in, and the cover standing in it
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArenaDefinition {
+    /// sRGB color components of the arena floor
+    pub floor_color: (f32, f32, f32),
+    #[serde(default)]
+    pub obstacles: Vec<ObstacleDefinition>,
+}
+
+impl Default for ArenaDefinition {
+    fn default() -> Self {
+        let floor = AppColors::BACKGROUND.to_srgba();
+        Self {
+            floor_color: (floor.red, floor.green, floor.blue),
+            obstacles: Vec::new(),
+        }
+    }
+}
+
+impl ArenaDefinition {
+    pub fn floor_color(&self) -> Color {
+        Color::srgb(self.floor_color.0, self.floor_color.1, self.floor_color.2)
+    }
+}
+
+/// An obstacle blocking movement, projectiles and dashes
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct ObstacleDefinition {
+    pub shape: ObstacleShape,
+    /// World position of the obstacle's center
+    pub position: (f32, f32),
+    /// Counterclockwise turn in radians
+    #[serde(default)]
+    pub rotation: f32,
+}
+
+impl ObstacleDefinition {
+    pub fn transform(&self) -> Transform {
+        Transform::from_xyz(self.position.0, self.position.1, 0.4).with_rotation(Quat::from_rotation_z(self.rotation))
+    }
+
+    pub fn mesh(&self) -> Mesh {
+        match self.shape {
+            ObstacleShape::Pillar { radius } => Circle::new(radius).into(),
+            ObstacleShape::Wall { length, thickness } => Rectangle::new(length, thickness).into(),
+            ObstacleShape::Crate { size, .. } => Rectangle::new(size, size).into(),
+        }
+    }
+
+    /// Collider matching the mesh, turned with the obstacle
+    pub fn collider(&self) -> Collider {
+        match self.shape {
+            ObstacleShape::Pillar { radius } => Collider::circle(radius),
+            ObstacleShape::Wall { length, thickness } => Collider::OrientedBox { half_size: Vec2::new(length, thickness) / 2.0 },
+            ObstacleShape::Crate { size, .. } => Collider::OrientedBox { half_size: Vec2::splat(size / 2.0) },
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        match self.shape {
+            ObstacleShape::Pillar { .. } | ObstacleShape::Wall { .. } => Color::srgb(0.35, 0.35, 0.4),
+            ObstacleShape::Crate { .. } => Color::srgb(0.45, 0.3, 0.15),
+        }
+    }
+
+    /// Health of destructible obstacles, `None` for those that stand forever
+    pub fn health(&self) -> Option<f32> {
+        match self.shape {
+            ObstacleShape::Crate { health, .. } => Some(health),
+            ObstacleShape::Pillar { .. } | ObstacleShape::Wall { .. } => None,
+        }
+    }
+}
+
+/// Supported obstacle shapes
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum ObstacleShape {
+    Pillar { radius: f32 },
+    /// Straight wall along its local X axis
+    Wall { length: f32, thickness: f32 },
+    /// Square crate destroyed once its health runs out
+    Crate { size: f32, health: f32 },
+}
+
+/// Reward for beating an encounter
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum Unlock {
+    /// A weapon of the registry, by name
+    Weapon(String),
+    Shield(ShieldUpgrade),
+}
+
+impl Unlock {
+    /// Short description for the results screen
+    pub fn describe(&self) -> String {
+        match self {
+            Unlock::Weapon(name) => format!("New weapon: {}", name),
+            Unlock::Shield(ShieldUpgrade::DamageReduction(amount)) => format!("Shield blocks {:.0}% more damage", amount * 100.0),
+            Unlock::Shield(ShieldUpgrade::ParryWindow(seconds)) => format!("Parry window +{:.2}s", seconds),
+            Unlock::Shield(ShieldUpgrade::ParryStagger(seconds)) => format!("Parry stagger +{:.1}s", seconds),
+        }
+    }
+}
+
+/// Permanent improvement to the player's shield
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ShieldUpgrade {
+    /// Added fraction of blocked damage that is negated
+    DamageReduction(f32),
+    /// Added seconds of perfect-parry window
+    ParryWindow(f32),
+    /// Added seconds a parried boss stays staggered
+    ParryStagger(f32),
+}
+
+impl ShieldUpgrade {
+    pub fn apply(&self, shield: &mut Shield) {
+        match *self {
+            ShieldUpgrade::DamageReduction(amount) => {
+                shield.damage_reduction = (shield.damage_reduction + amount).min(0.9);
+            }
+            ShieldUpgrade::ParryWindow(seconds) => {
+                let window = shield.parry_window.duration().as_secs_f32() + seconds;
+                shield.parry_window.set_duration(std::time::Duration::from_secs_f32(window));
+            }
+            ShieldUpgrade::ParryStagger(seconds) => shield.parry_stagger_duration += seconds,
+        }
+    }
+}
+
+/// The weapons and shield upgrades the player fights with
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Loadout {
+    /// Names of the usable weapons, `None` for every weapon of the registry
+    pub weapons: Option<Vec<String>>,
+    pub shield_upgrades: Vec<ShieldUpgrade>,
+}
+
+impl Loadout {
+    pub fn allows_weapon(&self, name: &str) -> bool {
+        self.weapons.as_ref().is_none_or(|weapons| weapons.iter().any(|weapon| weapon == name))
+    }
+
+    pub fn upgrade_shield(&self, shield: &mut Shield) {
+        for upgrade in &self.shield_upgrades {
+            upgrade.apply(shield);
+        }
+    }
+}
+
+/// Errors produced while loading an encounter sequence
+#[derive(Debug, thiserror::Error)]
+pub enum EncounterSequenceLoaderError {
+    #[error("could not read encounter sequence: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("could not parse encounter sequence RON: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+/// Asset loader for `.encounters.ron` files, which also loads every boss they reference
+#[derive(Default)]
+pub struct EncounterSequenceLoader;
+
+impl AssetLoader for EncounterSequenceLoader {
+    type Asset = EncounterSequence;
+    type Settings = ();
+    type Error = EncounterSequenceLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut sequence = ron::de::from_bytes::<EncounterSequence>(&bytes)?;
+        for encounter in &mut sequence.encounters {
+            encounter.boss_definition = load_context.load(encounter.boss.clone());
+        }
+        Ok(sequence)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["encounters.ron"]
+    }
+}
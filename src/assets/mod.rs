@@ -0,0 +1,7 @@
+mod boss_definition;
+mod weapon_definition;
+mod encounter_sequence;
+
+pub use boss_definition::*;
+pub use weapon_definition::*;
+pub use encounter_sequence::*;
@@ -0,0 +1,168 @@
+use bevy::prelude::*;
+use bevy::asset::{AssetLoader, LoadContext, io::Reader};
+use serde::Deserialize;
+use crate::components::Collider;
+
+/// Data-driven list of player weapons loaded from `assets/weapons/*.weapons.ron`.
+/// Weapons are selected by their index (number keys 1-9 select the first nine).
+#[derive(Asset, TypePath, Debug, Clone, Deserialize)]
+pub struct WeaponRegistry {
+    pub weapons: Vec<WeaponDefinition>,
+}
+
+/// A weapon archetype
+#[derive(Debug, Clone, Deserialize)]
+pub struct WeaponDefinition {
+    /// Display name
+    pub name: String,
+    /// Trigger behaviour
+    #[serde(default)]
+    pub fire_mode: FireMode,
+    /// Volleys per second
+    pub fire_rate: f32,
+    /// Damage dealt by each projectile
+    pub damage: f32,
+    /// Volleys per magazine (a burst volley or shotgun blast uses one round)
+    pub magazine_size: u32,
+    /// Seconds to refill the magazine
+    pub reload_time: f32,
+    /// Projectiles fired per volley
+    #[serde(default = "default_pellets")]
+    pub pellets: u32,
+    /// Total fan angle of a volley in radians
+    #[serde(default)]
+    pub spread: f32,
+    /// Number of additional targets each projectile passes through
+    #[serde(default)]
+    pub pierce: u32,
+    pub projectile: WeaponProjectileDefinition,
+    /// Extra effects applied to every target hit
+    #[serde(default)]
+    pub on_hit: Vec<OnHitEffect>,
+}
+
+fn default_pellets() -> u32 {
+    1
+}
+
+impl WeaponDefinition {
+    /// Directions of the pellets of one volley fanned around `aim`
+    pub fn volley_directions(&self, aim: Vec2) -> Vec<Vec2> {
+        if self.pellets <= 1 {
+            return vec![aim];
+        }
+        let base_angle = aim.to_angle();
+        (0..self.pellets)
+            .map(|i| {
+                let offset = self.spread * (i as f32 / (self.pellets - 1) as f32 - 0.5);
+                Vec2::from_angle(base_angle + offset)
+            })
+            .collect()
+    }
+}
+
+/// How holding the trigger turns into volleys
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub enum FireMode {
+    /// One volley every `1 / fire_rate` seconds
+    #[default]
+    Auto,
+    /// `shots` volleys `interval` seconds apart, then waits `1 / fire_rate` seconds
+    Burst { shots: u32, interval: f32 },
+    /// Charges for `charge_time` seconds before each volley
+    Charge { charge_time: f32 },
+}
+
+/// Projectile fired by a weapon
+#[derive(Debug, Clone, Deserialize)]
+pub struct WeaponProjectileDefinition {
+    pub speed: f32,
+    /// Collider radius, also the visual thickness
+    pub radius: f32,
+    /// Seconds before the projectile despawns
+    pub lifetime: f32,
+    #[serde(default)]
+    pub shape: ProjectileShape,
+    /// sRGB color components
+    pub color: (f32, f32, f32),
+    /// Turn rate in radians per second towards the nearest boss, `None` flies straight
+    #[serde(default)]
+    pub homing: Option<f32>,
+}
+
+impl WeaponProjectileDefinition {
+    pub fn mesh(&self) -> Mesh {
+        match self.shape {
+            ProjectileShape::Circle => Circle::new(self.radius).into(),
+            ProjectileShape::Beam { length } => Rectangle::new(length, self.radius * 2.0).into(),
+        }
+    }
+
+    /// Collider matching the mesh, beams are rounded off into a capsule along their length
+    pub fn collider(&self) -> Collider {
+        match self.shape {
+            ProjectileShape::Circle => Collider::circle(self.radius),
+            ProjectileShape::Beam { length } => Collider::Capsule {
+                radius: self.radius,
+                half_length: (length / 2.0 - self.radius).max(0.0),
+            },
+        }
+    }
+
+    pub fn color(&self) -> Color {
+        Color::srgb(self.color.0, self.color.1, self.color.2)
+    }
+}
+
+/// Visual shape of a projectile
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub enum ProjectileShape {
+    #[default]
+    Circle,
+    /// Elongated bolt aligned with the travel direction
+    Beam { length: f32 },
+}
+
+/// Effect applied to a target when a projectile hits it
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub enum OnHitEffect {
+    /// Area damage around the impact point
+    Explode { radius: f32, damage: f32 },
+    /// Damage over time on the target
+    Burn { damage_per_second: f32, duration: f32 },
+}
+
+/// Errors produced while loading a weapon registry
+#[derive(Debug, thiserror::Error)]
+pub enum WeaponRegistryLoaderError {
+    #[error("could not read weapon registry: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("could not parse weapon registry RON: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+/// Asset loader for `.weapons.ron` files
+#[derive(Default)]
+pub struct WeaponRegistryLoader;
+
+impl AssetLoader for WeaponRegistryLoader {
+    type Asset = WeaponRegistry;
+    type Settings = ();
+    type Error = WeaponRegistryLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let registry = ron::de::from_bytes::<WeaponRegistry>(&bytes)?;
+        Ok(registry)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["weapons.ron"]
+    }
+}
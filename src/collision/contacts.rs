@@ -0,0 +1,82 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use std::collections::BTreeSet;
+use crate::components::{Boss, BossPart};
+use crate::events::{CollisionOngoing, CollisionStarted};
+
+/// Two entities whose colliders touch, stored in entity order so each pair has a single representation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CollisionPair {
+    pub first: Entity,
+    pub second: Entity,
+}
+
+impl CollisionPair {
+    pub fn new(a: Entity, b: Entity) -> Self {
+        Self {
+            first: a.min(b),
+            second: a.max(b),
+        }
+    }
+
+    /// The pair as (the entity accepted by `is_a`, the entity accepted by `is_b`), whichever order it is stored in
+    pub fn matching(&self, is_a: impl Fn(Entity) -> bool, is_b: impl Fn(Entity) -> bool) -> Option<(Entity, Entity)> {
+        if is_a(self.first) && is_b(self.second) {
+            Some((self.first, self.second))
+        } else if is_a(self.second) && is_b(self.first) {
+            Some((self.second, self.first))
+        } else {
+            None
+        }
+    }
+}
+
+/// Resource with the pairs that touched on the latest tick, to tell started, ongoing and ended collisions apart
+#[derive(Resource, Debug, Default)]
+pub struct ActiveCollisions {
+    pairs: BTreeSet<CollisionPair>,
+}
+
+impl ActiveCollisions {
+    pub fn contains(&self, pair: &CollisionPair) -> bool {
+        self.pairs.contains(pair)
+    }
+
+    /// Replace the touching pairs with this tick's, returning the previous tick's
+    pub fn replace(&mut self, pairs: BTreeSet<CollisionPair>) -> BTreeSet<CollisionPair> {
+        std::mem::replace(&mut self.pairs, pairs)
+    }
+
+    pub fn clear(&mut self) {
+        self.pairs.clear();
+    }
+}
+
+/// System parameter reading the pairs touching this tick, whether their collision just started or is ongoing
+#[derive(SystemParam)]
+pub struct TouchingPairs<'w, 's> {
+    started: EventReader<'w, 's, CollisionStarted>,
+    ongoing: EventReader<'w, 's, CollisionOngoing>,
+}
+
+impl TouchingPairs<'_, '_> {
+    pub fn read(&mut self) -> impl Iterator<Item = CollisionPair> + '_ {
+        self.started.read().map(|event| event.pair).chain(self.ongoing.read().map(|event| event.pair))
+    }
+}
+
+/// System parameter telling which boss a collider belongs to, either the boss body or one of its parts
+#[derive(SystemParam)]
+pub struct BossHitboxes<'w, 's> {
+    bosses: Query<'w, 's, (), With<Boss>>,
+    parts: Query<'w, 's, &'static ChildOf, With<BossPart>>,
+}
+
+impl BossHitboxes<'_, '_> {
+    pub fn boss_of(&self, entity: Entity) -> Option<Entity> {
+        if self.bosses.contains(entity) {
+            return Some(entity);
+        }
+        self.parts.get(entity).ok().map(ChildOf::parent).filter(|parent| self.bosses.contains(*parent))
+    }
+}
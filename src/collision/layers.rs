@@ -0,0 +1,116 @@
+use bevy::prelude::*;
+
+/// Collision category of a collider
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CollisionLayer {
+    Player,
+    Boss,
+    PlayerProjectile,
+    EnemyProjectile,
+    /// The player's raised shield
+    Shield,
+    /// Mines and other things that hurt the player on touch
+    Hazard,
+    Wall,
+}
+
+impl CollisionLayer {
+    pub const ALL: [CollisionLayer; 7] = [
+        Self::Player,
+        Self::Boss,
+        Self::PlayerProjectile,
+        Self::EnemyProjectile,
+        Self::Shield,
+        Self::Hazard,
+        Self::Wall,
+    ];
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Component with the collision layers a collider is on. Only colliders on layers the
+/// `CollisionMatrix` pairs up produce collision events.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollisionLayers {
+    memberships: u8,
+}
+
+impl CollisionLayers {
+    /// On no layer, colliding with nothing
+    pub const NONE: Self = Self { memberships: 0 };
+
+    pub fn new(layer: CollisionLayer) -> Self {
+        Self { memberships: layer.bit() }
+    }
+
+    /// Also put the collider on `layer`
+    pub fn with(mut self, layer: CollisionLayer) -> Self {
+        self.memberships |= layer.bit();
+        self
+    }
+
+    pub fn contains(&self, layer: CollisionLayer) -> bool {
+        self.memberships & layer.bit() != 0
+    }
+}
+
+/// Resource with which pairs of layers collide, always symmetric
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct CollisionMatrix {
+    /// For each layer, the bits of the layers it collides with
+    masks: [u8; CollisionLayer::ALL.len()],
+}
+
+impl Default for CollisionMatrix {
+    fn default() -> Self {
+        use CollisionLayer::*;
+        let mut matrix = Self { masks: [0; CollisionLayer::ALL.len()] };
+        for (a, b) in [
+            (Player, Boss),
+            (Player, EnemyProjectile),
+            (Player, Hazard),
+            (Player, Wall),
+            (Shield, EnemyProjectile),
+            (Boss, PlayerProjectile),
+            (Boss, Wall),
+            (PlayerProjectile, Wall),
+            (EnemyProjectile, Wall),
+        ] {
+            matrix.set(a, b, true);
+        }
+        matrix
+    }
+}
+
+impl CollisionMatrix {
+    /// Make layers `a` and `b` collide or not, both ways
+    pub fn set(&mut self, a: CollisionLayer, b: CollisionLayer, collides: bool) {
+        for (layer, other) in [(a, b), (b, a)] {
+            if collides {
+                self.masks[layer as usize] |= other.bit();
+            } else {
+                self.masks[layer as usize] &= !other.bit();
+            }
+        }
+    }
+
+    /// Whether colliders on `a` and `b` collide, through any of their layers
+    pub fn collides(&self, a: CollisionLayers, b: CollisionLayers) -> bool {
+        self.mask_of(a) & b.memberships != 0
+    }
+
+    /// Whether colliders on `layers` collide with anything at all
+    pub fn collides_with_any(&self, layers: CollisionLayers) -> bool {
+        self.mask_of(layers) != 0
+    }
+
+    /// Bits of every layer that collides with one of `layers`
+    fn mask_of(&self, layers: CollisionLayers) -> u8 {
+        CollisionLayer::ALL
+            .iter()
+            .filter(|layer| layers.contains(**layer))
+            .fold(0, |mask, layer| mask | self.masks[*layer as usize])
+    }
+}
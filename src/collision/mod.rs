@@ -0,0 +1,9 @@
+mod contacts;
+mod layers;
+mod shapes;
+mod spatial_index;
+
+pub use contacts::*;
+pub use layers::*;
+pub use shapes::*;
+pub use spatial_index::*;
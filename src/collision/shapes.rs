@@ -0,0 +1,396 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use crate::components::{Collider, InterpolatedTransform};
+
+/// How close two shapes have to come to count as touching when searching for a time of impact
+const CONTACT_TOLERANCE: f32 = 0.01;
+/// Steps of the time of impact search before settling for whether the shapes touch at the end of the tick
+const MAX_IMPACT_STEPS: usize = 32;
+
+/// The part of a shape that is inflated by its margin
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Core {
+    Point(Vec2),
+    Segment(Vec2, Vec2),
+    /// Solid box
+    Box { center: Vec2, half_size: Vec2, rotation: Rot2 },
+    /// Circular arc of `radius` around `center`, spanning `half_angle` radians each side of `direction`
+    Arc { center: Vec2, radius: f32, direction: Vec2, half_angle: f32 },
+}
+
+/// A collider placed in the world: every point within `margin` of its core
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WorldShape {
+    core: Core,
+    margin: f32,
+}
+
+impl WorldShape {
+    /// `collider` at the position and rotation of `transform`, scale is ignored
+    pub fn of(collider: &Collider, transform: &Transform) -> Self {
+        let position = transform.translation.truncate();
+        let rotation = Rot2::radians((transform.rotation * Vec3::X).truncate().to_angle());
+        match *collider {
+            Collider::Circle { radius } => Self { core: Core::Point(position), margin: radius },
+            Collider::AlignedBox { half_size } => Self {
+                core: Core::Box { center: position, half_size, rotation: Rot2::IDENTITY },
+                margin: 0.0,
+            },
+            Collider::OrientedBox { half_size } => Self {
+                core: Core::Box { center: position, half_size, rotation },
+                margin: 0.0,
+            },
+            Collider::Capsule { radius, half_length } => {
+                let axis = rotation * Vec2::X * half_length;
+                Self { core: Core::Segment(position - axis, position + axis), margin: radius }
+            }
+            Collider::Arc { radius, thickness, center_angle, half_angle } => Self {
+                core: Core::Arc {
+                    center: position,
+                    radius,
+                    direction: rotation * Vec2::from_angle(center_angle),
+                    half_angle: half_angle.clamp(0.0, std::f32::consts::PI),
+                },
+                margin: thickness / 2.0,
+            },
+        }
+    }
+
+    /// Center of the smallest circle around the shape that the `SpatialIndex` keeps
+    pub fn center(&self) -> Vec2 {
+        match self.core {
+            Core::Point(point) => point,
+            Core::Segment(start, end) => start.midpoint(end),
+            Core::Box { center, .. } | Core::Arc { center, .. } => center,
+        }
+    }
+
+    /// Radius of the circle around `center` containing the whole shape
+    pub fn bounding_radius(&self) -> f32 {
+        let core_radius = match self.core {
+            Core::Point(_) => 0.0,
+            Core::Segment(start, end) => start.distance(end) / 2.0,
+            Core::Box { half_size, .. } => half_size.length(),
+            Core::Arc { radius, .. } => radius,
+        };
+        core_radius + self.margin
+    }
+
+    /// Whether the two shapes share any point
+    pub fn overlaps(&self, other: &WorldShape) -> bool {
+        let distance = core_distance(&self.core, &other.core);
+        distance == 0.0 || distance < self.margin + other.margin
+    }
+
+    /// The shape moved by `offset`
+    pub fn translated(&self, offset: Vec2) -> Self {
+        let core = match self.core {
+            Core::Point(point) => Core::Point(point + offset),
+            Core::Segment(start, end) => Core::Segment(start + offset, end + offset),
+            Core::Box { center, half_size, rotation } => Core::Box { center: center + offset, half_size, rotation },
+            Core::Arc { center, radius, direction, half_angle } => Core::Arc { center: center + offset, radius, direction, half_angle },
+        };
+        Self { core, margin: self.margin }
+    }
+
+    /// Fraction of the tick at which this shape, which moved by `displacement` relative to `other` during the
+    /// tick to end up where it is, first touched `other`. `None` if they never touched.
+    pub fn time_of_impact(&self, displacement: Vec2, other: &WorldShape) -> Option<f32> {
+        let travel = displacement.length();
+        if travel == 0.0 {
+            return self.overlaps(other).then_some(0.0);
+        }
+
+        // Advance along the path by the gap between the shapes, which can't skip past the first contact
+        let mut time = 0.0;
+        for _ in 0..MAX_IMPACT_STEPS {
+            let moved = self.translated(-displacement * (1.0 - time));
+            let gap = core_distance(&moved.core, &other.core) - (self.margin + other.margin);
+            if gap <= CONTACT_TOLERANCE {
+                return Some(time);
+            }
+            time += gap / travel;
+            if time > 1.0 {
+                return None;
+            }
+        }
+        self.overlaps(other).then_some(1.0)
+    }
+
+    /// Shortest move taking this shape out of `other`, `None` if they don't overlap. Only circles are pushed,
+    /// out of circles, capsules and boxes.
+    pub fn push_out(&self, other: &WorldShape) -> Option<Vec2> {
+        let Core::Point(point) = self.core else {
+            return None;
+        };
+        let reach = self.margin + other.margin;
+        let closest = match other.core {
+            Core::Point(center) => center,
+            Core::Segment(start, end) => closest_on_segment(point, start, end),
+            Core::Box { center, half_size, rotation } => {
+                let local = rotation.inverse() * (point - center);
+                if local.x.abs() <= half_size.x && local.y.abs() <= half_size.y {
+                    // Out through the nearest side
+                    let depth = half_size - local.abs();
+                    let push = if depth.x < depth.y {
+                        Vec2::new((depth.x + reach) * local.x.signum(), 0.0)
+                    } else {
+                        Vec2::new(0.0, (depth.y + reach) * local.y.signum())
+                    };
+                    return Some(rotation * push);
+                }
+                center + rotation * local.clamp(-half_size, half_size)
+            }
+            Core::Arc { .. } => return None,
+        };
+        let offset = point - closest;
+        let distance = offset.length();
+        if distance >= reach {
+            return None;
+        }
+        let direction = if distance > 0.0 { offset / distance } else { Vec2::X };
+        Some(direction * (reach - distance))
+    }
+}
+
+/// A collider placed in the world at the end of the tick, with how far it moved during the tick
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WorldCollider {
+    pub entity: Entity,
+    pub shape: WorldShape,
+    pub displacement: Vec2,
+}
+
+/// System parameter placing every collider in the world. Colliders on children are moved along with
+/// their parent, whose `GlobalTransform` is not up to date during the simulation.
+#[derive(SystemParam)]
+pub struct WorldColliders<'w, 's> {
+    colliders: Query<'w, 's, (Entity, &'static Transform, &'static Collider, Option<&'static InterpolatedTransform>, Option<&'static ChildOf>)>,
+    parents: Query<'w, 's, (&'static Transform, Option<&'static InterpolatedTransform>)>,
+}
+
+impl WorldColliders<'_, '_> {
+    pub fn iter(&self) -> impl Iterator<Item = WorldCollider> + '_ {
+        self.colliders.iter().map(|(entity, transform, collider, interpolated, child_of)| {
+            let parent = child_of.and_then(|child_of| self.parents.get(child_of.parent()).ok());
+            // Children move along with their parent
+            let (transform, mover, interpolated) = match parent {
+                Some((parent_transform, parent_interpolated)) => (parent_transform.mul_transform(*transform), *parent_transform, parent_interpolated),
+                None => (*transform, *transform, interpolated),
+            };
+            // Simulated entities moved from where they were at the start of the tick
+            let displacement = interpolated
+                .and_then(|interpolated| interpolated.previous)
+                .map_or(Vec2::ZERO, |previous| (mover.translation - previous.translation).truncate());
+            WorldCollider {
+                entity,
+                shape: WorldShape::of(collider, &transform),
+                displacement,
+            }
+        })
+    }
+}
+
+/// Shortest distance between two cores, 0 when they intersect
+fn core_distance(a: &Core, b: &Core) -> f32 {
+    match (*a, *b) {
+        (Core::Point(p), Core::Point(q)) => p.distance(q),
+        (Core::Point(p), Core::Segment(start, end)) | (Core::Segment(start, end), Core::Point(p)) => point_segment_distance(p, start, end),
+        (Core::Point(p), Core::Box { center, half_size, rotation }) | (Core::Box { center, half_size, rotation }, Core::Point(p)) => {
+            point_box_distance(p, center, half_size, rotation)
+        }
+        (Core::Point(p), arc @ Core::Arc { .. }) | (arc @ Core::Arc { .. }, Core::Point(p)) => point_arc_distance(p, &arc),
+        (Core::Segment(a1, a2), Core::Segment(b1, b2)) => segment_segment_distance(a1, a2, b1, b2),
+        (Core::Segment(start, end), Core::Box { center, half_size, rotation }) | (Core::Box { center, half_size, rotation }, Core::Segment(start, end)) => {
+            if box_contains(start, center, half_size, rotation) || box_contains(end, center, half_size, rotation) {
+                return 0.0;
+            }
+            box_edges(center, half_size, rotation)
+                .map(|(edge_start, edge_end)| segment_segment_distance(start, end, edge_start, edge_end))
+                .into_iter()
+                .fold(f32::INFINITY, f32::min)
+        }
+        (Core::Segment(start, end), arc @ Core::Arc { .. }) | (arc @ Core::Arc { .. }, Core::Segment(start, end)) => segment_arc_distance(start, end, &arc),
+        (Core::Box { center: a_center, half_size: a_half, rotation: a_rotation }, Core::Box { center: b_center, half_size: b_half, rotation: b_rotation }) => {
+            let a_corners = box_corners(a_center, a_half, a_rotation);
+            let b_corners = box_corners(b_center, b_half, b_rotation);
+            // Convex shapes intersect when a corner of one is inside the other or their edges cross
+            if a_corners.iter().any(|corner| box_contains(*corner, b_center, b_half, b_rotation))
+                || b_corners.iter().any(|corner| box_contains(*corner, a_center, a_half, a_rotation))
+            {
+                return 0.0;
+            }
+            let mut distance = f32::INFINITY;
+            for (a1, a2) in box_edges(a_center, a_half, a_rotation) {
+                for (b1, b2) in box_edges(b_center, b_half, b_rotation) {
+                    distance = distance.min(segment_segment_distance(a1, a2, b1, b2));
+                }
+            }
+            distance
+        }
+        (Core::Box { center, half_size, rotation }, arc @ Core::Arc { .. }) | (arc @ Core::Arc { .. }, Core::Box { center, half_size, rotation }) => {
+            // An arc that doesn't cross the edges is either inside the box or outside it
+            if arc_ends(&arc).iter().any(|end| box_contains(*end, center, half_size, rotation)) {
+                return 0.0;
+            }
+            box_edges(center, half_size, rotation)
+                .map(|(edge_start, edge_end)| segment_arc_distance(edge_start, edge_end, &arc))
+                .into_iter()
+                .fold(f32::INFINITY, f32::min)
+        }
+        (a @ Core::Arc { .. }, b @ Core::Arc { .. }) => arc_arc_distance(&a, &b),
+    }
+}
+
+fn closest_on_segment(point: Vec2, start: Vec2, end: Vec2) -> Vec2 {
+    let along = end - start;
+    let length_squared = along.length_squared();
+    if length_squared == 0.0 {
+        return start;
+    }
+    start + along * ((point - start).dot(along) / length_squared).clamp(0.0, 1.0)
+}
+
+fn point_segment_distance(point: Vec2, start: Vec2, end: Vec2) -> f32 {
+    point.distance(closest_on_segment(point, start, end))
+}
+
+fn segment_segment_distance(a1: Vec2, a2: Vec2, b1: Vec2, b2: Vec2) -> f32 {
+    // Strictly crossing segments, touching ones are found by the endpoint distances below
+    let side = |p: Vec2, q: Vec2, r: Vec2| (q - p).perp_dot(r - p);
+    let (d1, d2) = (side(b1, b2, a1), side(b1, b2, a2));
+    let (d3, d4) = (side(a1, a2, b1), side(a1, a2, b2));
+    if d1 * d2 < 0.0 && d3 * d4 < 0.0 {
+        return 0.0;
+    }
+    point_segment_distance(a1, b1, b2)
+        .min(point_segment_distance(a2, b1, b2))
+        .min(point_segment_distance(b1, a1, a2))
+        .min(point_segment_distance(b2, a1, a2))
+}
+
+fn box_contains(point: Vec2, center: Vec2, half_size: Vec2, rotation: Rot2) -> bool {
+    let local = rotation.inverse() * (point - center);
+    local.x.abs() <= half_size.x && local.y.abs() <= half_size.y
+}
+
+fn point_box_distance(point: Vec2, center: Vec2, half_size: Vec2, rotation: Rot2) -> f32 {
+    let local = rotation.inverse() * (point - center);
+    (local.abs() - half_size).max(Vec2::ZERO).length()
+}
+
+fn box_corners(center: Vec2, half_size: Vec2, rotation: Rot2) -> [Vec2; 4] {
+    [Vec2::new(-1.0, -1.0), Vec2::new(1.0, -1.0), Vec2::new(1.0, 1.0), Vec2::new(-1.0, 1.0)]
+        .map(|corner| center + rotation * (corner * half_size))
+}
+
+fn box_edges(center: Vec2, half_size: Vec2, rotation: Rot2) -> [(Vec2, Vec2); 4] {
+    let corners = box_corners(center, half_size, rotation);
+    [0, 1, 2, 3].map(|i| (corners[i], corners[(i + 1) % 4]))
+}
+
+/// Whether the direction `unit` from the arc's center falls within the arc
+fn arc_spans(arc: &Core, unit: Vec2) -> bool {
+    let Core::Arc { direction, half_angle, .. } = *arc else {
+        return false;
+    };
+    unit.dot(direction) >= half_angle.cos()
+}
+
+fn arc_ends(arc: &Core) -> [Vec2; 2] {
+    let Core::Arc { center, radius, direction, half_angle } = *arc else {
+        return [Vec2::NAN; 2];
+    };
+    [-half_angle, half_angle].map(|angle| center + Rot2::radians(angle) * direction * radius)
+}
+
+fn point_arc_distance(point: Vec2, arc: &Core) -> f32 {
+    let Core::Arc { center, radius, .. } = *arc else {
+        return f32::INFINITY;
+    };
+    let offset = point - center;
+    let length = offset.length();
+    if length > 0.0 && arc_spans(arc, offset / length) {
+        return (length - radius).abs();
+    }
+    let [first, last] = arc_ends(arc);
+    point.distance(first).min(point.distance(last))
+}
+
+/// Points where the line through `start` and `end` meets the circle, as fractions of the way along it
+fn line_circle_crossings(start: Vec2, end: Vec2, center: Vec2, radius: f32) -> Option<[f32; 2]> {
+    let along = end - start;
+    let offset = start - center;
+    let a = along.length_squared();
+    let b = 2.0 * along.dot(offset);
+    let c = offset.length_squared() - radius * radius;
+    let discriminant = b * b - 4.0 * a * c;
+    if a == 0.0 || discriminant < 0.0 {
+        return None;
+    }
+    let root = discriminant.sqrt();
+    Some([(-b - root) / (2.0 * a), (-b + root) / (2.0 * a)])
+}
+
+fn segment_arc_distance(start: Vec2, end: Vec2, arc: &Core) -> f32 {
+    let Core::Arc { center, radius, .. } = *arc else {
+        return f32::INFINITY;
+    };
+    let crosses = line_circle_crossings(start, end, center, radius).is_some_and(|crossings| {
+        crossings.into_iter().any(|t| {
+            (0.0..=1.0).contains(&t) && arc_spans(arc, (start + (end - start) * t - center).normalize_or_zero())
+        })
+    });
+    if crosses {
+        return 0.0;
+    }
+    // Otherwise the closest points involve an endpoint of either, or the point of the segment nearest the center
+    let [first, last] = arc_ends(arc);
+    [
+        point_arc_distance(start, arc),
+        point_arc_distance(end, arc),
+        point_arc_distance(closest_on_segment(center, start, end), arc),
+        point_segment_distance(first, start, end),
+        point_segment_distance(last, start, end),
+    ]
+    .into_iter()
+    .fold(f32::INFINITY, f32::min)
+}
+
+fn arc_arc_distance(a: &Core, b: &Core) -> f32 {
+    let (Core::Arc { center: a_center, radius: a_radius, .. }, Core::Arc { center: b_center, radius: b_radius, .. }) = (*a, *b) else {
+        return f32::INFINITY;
+    };
+    let offset = b_center - a_center;
+    let distance = offset.length();
+
+    // Points where the two circles cross
+    if distance > 0.0 && distance <= a_radius + b_radius && distance >= (a_radius - b_radius).abs() {
+        let axis = offset / distance;
+        let along = (a_radius * a_radius - b_radius * b_radius + distance * distance) / (2.0 * distance);
+        let across = (a_radius * a_radius - along * along).max(0.0).sqrt();
+        for point in [a_center + axis * along + axis.perp() * across, a_center + axis * along - axis.perp() * across] {
+            if arc_spans(a, (point - a_center).normalize_or_zero()) && arc_spans(b, (point - b_center).normalize_or_zero()) {
+                return 0.0;
+            }
+        }
+    }
+
+    let mut closest = arc_ends(a)
+        .map(|end| point_arc_distance(end, b))
+        .into_iter()
+        .chain(arc_ends(b).map(|end| point_arc_distance(end, a)))
+        .fold(f32::INFINITY, f32::min);
+    if distance > 0.0 {
+        // Otherwise the closest points lie on the line through both centers
+        let axis = offset / distance;
+        for a_side in [axis, -axis] {
+            for b_side in [axis, -axis] {
+                if arc_spans(a, a_side) && arc_spans(b, b_side) {
+                    closest = closest.min((a_center + a_side * a_radius).distance(b_center + b_side * b_radius));
+                }
+            }
+        }
+    }
+    closest
+}
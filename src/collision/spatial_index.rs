@@ -0,0 +1,286 @@
+use bevy::prelude::*;
+use crate::constants::GameBoundaries;
+
+/// A circle collider as stored in the `SpatialIndex`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpatialEntry {
+    pub entity: Entity,
+    pub position: Vec2,
+    pub radius: f32,
+}
+
+/// Where a ray first touched a collider
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit {
+    pub entity: Entity,
+    /// Distance along the ray to the collider's edge, 0 when the ray starts inside it
+    pub distance: f32,
+    pub point: Vec2,
+}
+
+/// An entry with the first grid cell it covers, so entries covering several cells are reported once
+#[derive(Debug, Clone, Copy)]
+struct Slot {
+    entry: SpatialEntry,
+    first_cell: (usize, usize),
+}
+
+/// Resource with every circle collider sorted into a uniform grid over the arena, rebuilt each tick,
+/// so collision checks only look at colliders in nearby cells. Colliders outside `GameBoundaries`
+/// are kept in the edge cells.
+#[derive(Resource, Debug, Clone)]
+pub struct SpatialIndex {
+    cell_size: f32,
+    /// Lower-left corner of the grid
+    origin: Vec2,
+    columns: usize,
+    rows: usize,
+    /// Indices into `slots` of the entries overlapping each cell, row by row
+    cells: Vec<Vec<usize>>,
+    slots: Vec<Slot>,
+    /// Area covered by every entry, which bounds how far ray casts have to walk
+    bounds: Rect,
+}
+
+impl Default for SpatialIndex {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CELL_SIZE)
+    }
+}
+
+impl SpatialIndex {
+    /// About twice the boss radius, so most colliders cover one to four cells
+    pub const DEFAULT_CELL_SIZE: f32 = 128.0;
+
+    /// Empty grid over the arena with square cells of `cell_size`
+    pub fn new(cell_size: f32) -> Self {
+        let columns = (GameBoundaries::width() / cell_size).ceil().max(1.0) as usize;
+        let rows = (GameBoundaries::height() / cell_size).ceil().max(1.0) as usize;
+        Self {
+            cell_size,
+            origin: Vec2::new(GameBoundaries::min_x(), GameBoundaries::min_y()),
+            columns,
+            rows,
+            cells: vec![Vec::new(); columns * rows],
+            slots: Vec::new(),
+            bounds: Rect::EMPTY,
+        }
+    }
+
+    /// Remove every entry, keeping the allocations for the next rebuild
+    pub fn clear(&mut self) {
+        for cell in &mut self.cells {
+            cell.clear();
+        }
+        self.slots.clear();
+        self.bounds = Rect::EMPTY;
+    }
+
+    /// Add a circle collider. Colliders at non-finite positions are ignored.
+    pub fn insert(&mut self, entity: Entity, position: Vec2, radius: f32) {
+        if !position.is_finite() || !radius.is_finite() {
+            return;
+        }
+        let (first_cell, last_cell) = self.cell_range(position, radius);
+        let index = self.slots.len();
+        self.slots.push(Slot {
+            entry: SpatialEntry { entity, position, radius },
+            first_cell,
+        });
+        for row in first_cell.1..=last_cell.1 {
+            for column in first_cell.0..=last_cell.0 {
+                self.cells[row * self.columns + column].push(index);
+            }
+        }
+        self.bounds = self.bounds.union(Rect::from_center_half_size(position, Vec2::splat(radius)));
+    }
+
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Every entry overlapping the circle at `center`, each reported once
+    pub fn query_circle(&self, center: Vec2, radius: f32) -> impl Iterator<Item = SpatialEntry> + '_ {
+        let (first_cell, last_cell) = self.cell_range(center, radius);
+        (first_cell.1..=last_cell.1)
+            .flat_map(move |row| (first_cell.0..=last_cell.0).map(move |column| (column, row)))
+            .flat_map(move |(column, row)| {
+                self.cells[row * self.columns + column]
+                    .iter()
+                    .map(move |&index| &self.slots[index])
+                    // Report an entry only in the first cell it shares with the query
+                    .filter(move |slot| {
+                        column == slot.first_cell.0.max(first_cell.0) && row == slot.first_cell.1.max(first_cell.1)
+                    })
+            })
+            .map(|slot| slot.entry)
+            .filter(move |entry| entry.position.distance_squared(center) < (radius + entry.radius).powi(2))
+    }
+
+    /// The entry accepted by `filter` whose center is nearest to `center`, within `max_distance`.
+    /// Searches rings of cells outwards from `center`, stopping once no farther ring can hold anything nearer.
+    pub fn nearest(&self, center: Vec2, max_distance: f32, mut filter: impl FnMut(&SpatialEntry) -> bool) -> Option<SpatialEntry> {
+        let (center_column, center_row) = self.cell_of(center);
+        let mut best: Option<(f32, SpatialEntry)> = None;
+
+        for ring in 0..=self.columns.max(self.rows) {
+            // Entries centered in this ring or beyond are at least this far away
+            let ring_distance = ring.saturating_sub(1) as f32 * self.cell_size;
+            if ring_distance > max_distance || best.is_some_and(|(distance, _)| distance <= ring_distance) {
+                break;
+            }
+
+            let ring = ring as isize;
+            for row_offset in -ring..=ring {
+                // Only the edges of the ring, its inside was searched already
+                let column_step = if row_offset.abs() == ring { 1 } else { (2 * ring).max(1) };
+                for column_offset in (-ring..=ring).step_by(column_step as usize) {
+                    let (Some(column), Some(row)) = (
+                        center_column.checked_add_signed(column_offset).filter(|column| *column < self.columns),
+                        center_row.checked_add_signed(row_offset).filter(|row| *row < self.rows),
+                    ) else {
+                        continue;
+                    };
+                    for &index in &self.cells[row * self.columns + column] {
+                        let entry = self.slots[index].entry;
+                        let distance = entry.position.distance(center);
+                        if distance <= max_distance
+                            && best.is_none_or(|(best_distance, _)| distance < best_distance)
+                            && filter(&entry)
+                        {
+                            best = Some((distance, entry));
+                        }
+                    }
+                }
+            }
+        }
+        best.map(|(_, entry)| entry)
+    }
+
+    /// The first entry accepted by `filter` that the ray from `origin` along `direction` touches within `max_distance`.
+    /// Walks the cells the ray crosses in order, stopping at the first cell past the nearest hit.
+    pub fn ray_cast(
+        &self,
+        origin: Vec2,
+        direction: Vec2,
+        max_distance: f32,
+        mut filter: impl FnMut(&SpatialEntry) -> bool,
+    ) -> Option<RayHit> {
+        let direction = direction.try_normalize()?;
+        if self.is_empty() {
+            return None;
+        }
+        // Nothing can be hit outside the area covered by the entries
+        let (enter, exit) = ray_interval(origin, direction, self.bounds)?;
+        let start = enter.max(0.0);
+        let end = exit.min(max_distance);
+        if start > end {
+            return None;
+        }
+
+        // Cell coordinates on an unbounded grid, clamped to the real grid when looking cells up
+        let start_point = origin + direction * start;
+        let mut cell = ((start_point - self.origin) / self.cell_size).floor();
+        let step = direction.signum();
+        let next_boundary = |cell: f32, step: f32, origin: f32| origin + (cell + step.max(0.0)) * self.cell_size;
+        let mut next_crossing = Vec2::new(
+            if direction.x != 0.0 { start + (next_boundary(cell.x, step.x, self.origin.x) - start_point.x) / direction.x } else { f32::INFINITY },
+            if direction.y != 0.0 { start + (next_boundary(cell.y, step.y, self.origin.y) - start_point.y) / direction.y } else { f32::INFINITY },
+        );
+        let crossing_step = self.cell_size / direction.abs();
+
+        let mut best: Option<RayHit> = None;
+        let mut last_cell = None;
+        loop {
+            let clamped = (
+                cell.x.clamp(0.0, (self.columns - 1) as f32) as usize,
+                cell.y.clamp(0.0, (self.rows - 1) as f32) as usize,
+            );
+            // Several cells past the edge of the grid clamp to the same real cell
+            if last_cell != Some(clamped) {
+                last_cell = Some(clamped);
+                for &index in &self.cells[clamped.1 * self.columns + clamped.0] {
+                    let entry = self.slots[index].entry;
+                    let Some(distance) = ray_circle_distance(origin, direction, entry.position, entry.radius) else {
+                        continue;
+                    };
+                    if distance <= max_distance
+                        && best.is_none_or(|hit| distance < hit.distance)
+                        && filter(&entry)
+                    {
+                        best = Some(RayHit {
+                            entity: entry.entity,
+                            distance,
+                            point: origin + direction * distance,
+                        });
+                    }
+                }
+            }
+
+            let cell_exit = next_crossing.min_element();
+            if cell_exit > end || best.is_some_and(|hit| hit.distance <= cell_exit) {
+                break;
+            }
+            if next_crossing.x < next_crossing.y {
+                cell.x += step.x;
+                next_crossing.x += crossing_step.x;
+            } else {
+                cell.y += step.y;
+                next_crossing.y += crossing_step.y;
+            }
+        }
+        best
+    }
+
+    /// Grid cell containing `point`, clamped to the grid
+    fn cell_of(&self, point: Vec2) -> (usize, usize) {
+        let cell = ((point - self.origin) / self.cell_size).floor();
+        (
+            cell.x.clamp(0.0, (self.columns - 1) as f32) as usize,
+            cell.y.clamp(0.0, (self.rows - 1) as f32) as usize,
+        )
+    }
+
+    /// First and last grid cells overlapped by the square around a circle
+    fn cell_range(&self, center: Vec2, radius: f32) -> ((usize, usize), (usize, usize)) {
+        (self.cell_of(center - Vec2::splat(radius)), self.cell_of(center + Vec2::splat(radius)))
+    }
+}
+
+/// Distances along a ray (with a normalized direction) at which it enters and leaves `rect`
+fn ray_interval(origin: Vec2, direction: Vec2, rect: Rect) -> Option<(f32, f32)> {
+    let mut enter = f32::NEG_INFINITY;
+    let mut exit = f32::INFINITY;
+    for axis in 0..2 {
+        let (origin, direction, min, max) = (origin[axis], direction[axis], rect.min[axis], rect.max[axis]);
+        if direction == 0.0 {
+            if origin < min || origin > max {
+                return None;
+            }
+            continue;
+        }
+        let (near, far) = ((min - origin) / direction, (max - origin) / direction);
+        enter = enter.max(near.min(far));
+        exit = exit.min(near.max(far));
+    }
+    (enter <= exit && exit >= 0.0).then_some((enter, exit))
+}
+
+/// Distance along a ray (with a normalized direction) to where it enters a circle, 0 if it starts inside
+fn ray_circle_distance(origin: Vec2, direction: Vec2, center: Vec2, radius: f32) -> Option<f32> {
+    let offset = origin - center;
+    let outside = offset.length_squared() - radius * radius;
+    if outside <= 0.0 {
+        return Some(0.0);
+    }
+    let along = offset.dot(direction);
+    let discriminant = along * along - outside;
+    if along > 0.0 || discriminant < 0.0 {
+        return None;
+    }
+    Some(-along - discriminant.sqrt())
+}
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+/// Component for damage over time applied by burning on-hit effects
+#[derive(Component, Debug, Clone)]
+pub struct Burning {
+    /// Entity credited with the damage
+    pub source: Entity,
+    pub damage_per_second: f32,
+    pub duration: Timer,
+    pub tick_timer: Timer,
+}
+
+impl Burning {
+    /// Seconds between damage applications
+    const TICK_INTERVAL: f32 = 0.5;
+
+    pub fn new(source: Entity, damage_per_second: f32, duration: f32) -> Self {
+        Self {
+            source,
+            damage_per_second,
+            duration: Timer::from_seconds(duration, TimerMode::Once),
+            tick_timer: Timer::from_seconds(Self::TICK_INTERVAL, TimerMode::Repeating),
+        }
+    }
+
+    /// Advance the burn, returns the damage to apply this frame if a tick elapsed
+    pub fn update(&mut self, delta_time: std::time::Duration) -> Option<f32> {
+        self.duration.tick(delta_time);
+        let ticks = self.tick_timer.tick(delta_time).times_finished_this_tick();
+        (ticks > 0).then_some(self.damage_per_second * Self::TICK_INTERVAL * ticks as f32)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.duration.finished()
+    }
+}
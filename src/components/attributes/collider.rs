@@ -1,13 +1,38 @@
 use bevy::prelude::*;
+use crate::collision::WorldShape;
 
-/// Component for circular collider with radius
-#[derive(Component, Debug, Clone)]
-pub struct Collider {
-    pub radius: f32,
+/// Component for the shape an entity collides with, in its local space
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub enum Collider {
+    Circle { radius: f32 },
+    /// Box that keeps its sides aligned with the world axes, whatever the entity's rotation
+    AlignedBox { half_size: Vec2 },
+    /// Box that turns with the entity
+    OrientedBox { half_size: Vec2 },
+    /// Rounded segment along the local X axis, for elongated or fast moving things
+    Capsule { radius: f32, half_length: f32 },
+    /// Band of `thickness` along a circular arc of `radius` around the entity, spanning
+    /// `half_angle` radians each side of `center_angle`
+    Arc { radius: f32, thickness: f32, center_angle: f32, half_angle: f32 },
 }
 
 impl Collider {
-    pub fn new(radius: f32) -> Self {
-        Self { radius }
+    pub fn circle(radius: f32) -> Self {
+        Self::Circle { radius }
     }
-} 
\ No newline at end of file
+
+    /// Distance from the entity's origin to the farthest point of the shape
+    pub fn bounding_radius(&self) -> f32 {
+        match *self {
+            Self::Circle { radius } => radius,
+            Self::AlignedBox { half_size } | Self::OrientedBox { half_size } => half_size.length(),
+            Self::Capsule { radius, half_length } => half_length + radius,
+            Self::Arc { radius, thickness, .. } => radius + thickness / 2.0,
+        }
+    }
+
+    /// Whether this collider at `transform` overlaps `other` at `other_transform`
+    pub fn overlaps(&self, transform: &Transform, other: &Collider, other_transform: &Transform) -> bool {
+        WorldShape::of(self, transform).overlaps(&WorldShape::of(other, other_transform))
+    }
+}
@@ -4,7 +4,7 @@ use bevy::prelude::*;
 #[derive(Component, Debug, Clone)]
 pub struct CollisionDamage {
     pub damage_per_second: f32,
-    pub last_damage_time: f32, // Track when we last applied damage to prevent multiple hits per frame
+    pub cooldown: f32,         // Time left before damage can be applied again, to prevent multiple hits per frame
     pub damage_interval: f32,  // Minimum time between damage applications (e.g., 0.5 seconds)
 }
 
@@ -12,17 +12,22 @@ impl CollisionDamage {
     pub fn new(damage_per_second: f32, damage_interval: f32) -> Self {
         Self {
             damage_per_second,
-            last_damage_time: 0.0,
+            cooldown: 0.0,
             damage_interval,
         }
     }
+
+    /// Advance the cooldown by a tick's `delta` seconds
+    pub fn tick(&mut self, delta: f32) {
+        self.cooldown = (self.cooldown - delta).max(0.0);
+    }
     
-    pub fn can_damage(&self, current_time: f32) -> bool {
-        current_time - self.last_damage_time >= self.damage_interval
+    pub fn can_damage(&self) -> bool {
+        self.cooldown <= 0.0
     }
     
-    pub fn apply_damage(&mut self, current_time: f32) -> f32 {
-        self.last_damage_time = current_time;
+    pub fn apply_damage(&mut self) -> f32 {
+        self.cooldown = self.damage_interval;
         self.damage_per_second * self.damage_interval
     }
-} 
\ No newline at end of file
+}
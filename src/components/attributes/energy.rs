@@ -30,6 +30,10 @@ impl Energy {
         self.current = (self.current + self.recharge_rate * delta).min(self.max);
     }
 
+    pub fn restore(&mut self, amount: f32) {
+        self.current = (self.current + amount).min(self.max);
+    }
+
     pub fn can_consume(&self, amount: f32) -> bool {
         self.current >= amount
     }
@@ -1,19 +1,11 @@
 use bevy::prelude::*;
 
 /// Component for invulnerability state
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Default)]
 pub struct Invulnerability {
     pub is_invulnerable: bool,
 }
 
-impl Default for Invulnerability {
-    fn default() -> Self {
-        Self {
-            is_invulnerable: false,
-        }
-    }
-}
-
 impl Invulnerability {
     pub fn new() -> Self {
         Self {
@@ -4,6 +4,8 @@ mod collision_damage;
 mod collider;
 mod energy;
 mod invulnerability;
+mod resistances;
+mod burning;
 
 pub use health::*;
 pub use speed::*;
@@ -11,3 +13,5 @@ pub use collision_damage::*;
 pub use collider::*;
 pub use energy::*;
 pub use invulnerability::*;
+pub use resistances::*;
+pub use burning::*;
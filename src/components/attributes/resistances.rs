@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::events::DamageKind;
+
+/// Component for per-damage-kind damage multipliers (1.0 = normal, 0.0 = immune)
+#[derive(Component, Debug, Clone, Default)]
+pub struct Resistances {
+    pub multipliers: HashMap<DamageKind, f32>,
+}
+
+impl Resistances {
+    pub fn multiplier(&self, kind: DamageKind) -> f32 {
+        self.multipliers.get(&kind).copied().unwrap_or(1.0)
+    }
+}
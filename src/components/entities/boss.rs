@@ -1,19 +1,105 @@
 use bevy::prelude::*;
-use crate::components::attributes::{Health, Speed, CollisionDamage, Collider};
+use crate::components::attributes::{Health, Speed, CollisionDamage, Collider, Resistances};
+use crate::collision::{CollisionLayer, CollisionLayers};
 use crate::components::boundary::BoundedMovement;
+use crate::components::interpolation::InterpolatedTransform;
+use crate::assets::{BossDefinition, DashSkillDefinition, MineSkillDefinition, BulletPatternSkillDefinition, BulletPattern, HostileProjectileDefinition, BossPhaseDefinition, BossMovementPattern};
 
 /// Marker component for all boss entities
 #[derive(Component, Debug)]
+#[require(InterpolatedTransform)]
 pub struct Boss;
 
 /// Marker component for the Mine boss type
 #[derive(Component, Debug)]
 pub struct MineBoss;
 
+/// Stage of a telegraphed boss attack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AttackStage {
+    /// Attack can be started
+    #[default]
+    Ready,
+    /// Telegraph is shown, attack has not started yet
+    Windup,
+    /// Attack is hitting
+    Active,
+    /// Boss is recovering and cannot attack
+    Recovery,
+    /// Boss was interrupted (e.g. parried) and cannot act
+    Staggered,
+}
+
+/// Generic Windup -> Active -> Recovery lifecycle shared by boss skills
+#[derive(Debug)]
+pub struct AttackLifecycle {
+    pub stage: AttackStage,
+    pub windup: Timer,
+    pub recovery: Timer,
+    pub stagger: Timer,
+}
+
+impl AttackLifecycle {
+    pub fn new(windup_seconds: f32, recovery_seconds: f32) -> Self {
+        Self {
+            stage: AttackStage::Ready,
+            windup: Timer::from_seconds(windup_seconds, TimerMode::Once),
+            recovery: Timer::from_seconds(recovery_seconds, TimerMode::Once),
+            stagger: Timer::from_seconds(0.0, TimerMode::Once),
+        }
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.stage == AttackStage::Ready
+    }
+
+    pub fn start_windup(&mut self) {
+        self.stage = AttackStage::Windup;
+        self.windup.reset();
+    }
+
+    /// Called by the skill when its active part ends
+    pub fn start_recovery(&mut self) {
+        self.stage = AttackStage::Recovery;
+        self.recovery.reset();
+    }
+
+    /// Cancel whatever stage the attack is in and stagger for `seconds`
+    pub fn cancel(&mut self, seconds: f32) {
+        self.stage = AttackStage::Staggered;
+        self.stagger = Timer::from_seconds(seconds, TimerMode::Once);
+    }
+
+    /// Advance the windup/recovery timers, returns the new stage if it changed
+    pub fn tick(&mut self, delta: std::time::Duration) -> Option<AttackStage> {
+        match self.stage {
+            AttackStage::Windup if self.windup.tick(delta).finished() => {
+                self.stage = AttackStage::Active;
+                Some(AttackStage::Active)
+            }
+            AttackStage::Recovery if self.recovery.tick(delta).finished() => {
+                self.stage = AttackStage::Ready;
+                Some(AttackStage::Ready)
+            }
+            AttackStage::Staggered if self.stagger.tick(delta).finished() => {
+                self.stage = AttackStage::Ready;
+                Some(AttackStage::Ready)
+            }
+            _ => None,
+        }
+    }
+
+    /// Windup completion from 0.0 to 1.0
+    pub fn windup_progress(&self) -> f32 {
+        self.windup.fraction()
+    }
+}
+
 /// Component for boss skills and abilities
 #[derive(Component, Debug)]
 pub struct BossSkills {
-    pub dash_cooldown: Timer,
+    pub dash_attack: AttackLifecycle, // Telegraphed lifecycle of the dash
+    pub dash_cooldown: Timer, // Stays finished until the next chain, so a boss busy when it runs out dashes right after
     pub is_dashing: bool,
     pub dash_target: Vec3,
     pub dash_start_position: Vec3, // Track where the dash started
@@ -21,44 +107,70 @@ pub struct BossSkills {
     pub dash_damage: f32,
     pub has_hit_player: bool, // Track if we've already hit the player during this dash
     pub dash_distance: f32, // Distance for each dash
+    pub dash_min_range: f32, // Only dash when the player is farther than this
     pub dash_timer: Timer, // Maximum time for a dash to prevent getting stuck
-    pub last_position: Vec3, // Track last position to detect if stuck
-    pub stuck_frames: u32, // Count frames where boss hasn't moved significantly
+    pub dash_chain_length: u32, // Number of consecutive dashes per cooldown
+    pub dash_chain_remaining: u32, // Follow-up dashes left in the current chain
+    pub chain_delay: Timer, // Pause between dashes of a chain
 }
 
-impl Default for BossSkills {
-    fn default() -> Self {
+impl BossSkills {
+    /// Seconds a boss stays stunned after dashing into an obstacle
+    pub const OBSTACLE_STUN_DURATION: f32 = 1.5;
+
+    pub fn new(dash: &DashSkillDefinition) -> Self {
         Self {
-            dash_cooldown: Timer::from_seconds(3.0, TimerMode::Repeating),
+            dash_attack: AttackLifecycle::new(dash.windup, dash.recovery),
+            dash_cooldown: Timer::from_seconds(dash.cooldown, TimerMode::Once),
             is_dashing: false,
             dash_target: Vec3::ZERO,
             dash_start_position: Vec3::ZERO,
-            dash_speed: 1200.0,
-            dash_damage: 25.0,
+            dash_speed: dash.speed,
+            dash_damage: dash.damage,
             has_hit_player: false,
-            dash_distance: 800.0, // Default dash distance
-            dash_timer: Timer::from_seconds(2.0, TimerMode::Once), // Max 2 seconds per dash
-            last_position: Vec3::ZERO,
-            stuck_frames: 0,
+            dash_distance: dash.distance,
+            dash_min_range: dash.min_range,
+            dash_timer: Timer::from_seconds(dash.max_duration, TimerMode::Once), // Failsafe max time per dash
+            dash_chain_length: 1,
+            dash_chain_remaining: 0,
+            chain_delay: Timer::from_seconds(0.3, TimerMode::Once),
         }
     }
-}
 
-impl BossSkills {
     pub fn can_dash(&self) -> bool {
-        self.dash_cooldown.finished() && !self.is_dashing
+        if !self.dash_attack.is_ready() {
+            return false;
+        }
+        if self.dash_chain_remaining > 0 {
+            // Follow-up dash of a chain only waits for the short chain delay
+            self.chain_delay.finished()
+        } else {
+            self.dash_cooldown.finished()
+        }
     }
 
-    pub fn start_dash(&mut self, target: Vec3, start_position: Vec3) {
+    /// Commit to a dash towards `target` and start its telegraphed windup
+    pub fn begin_dash_windup(&mut self, target: Vec3) {
         if self.can_dash() {
-            self.is_dashing = true;
+            if self.dash_chain_remaining > 0 {
+                self.dash_chain_remaining -= 1;
+            } else {
+                // First dash of a chain starts the cooldown
+                self.dash_chain_remaining = self.dash_chain_length.saturating_sub(1);
+                self.dash_cooldown.reset();
+            }
             self.dash_target = target;
+            self.dash_attack.start_windup();
+        }
+    }
+
+    /// Launch the dash once its windup has finished
+    pub fn start_dash(&mut self, start_position: Vec3) {
+        if self.dash_attack.stage == AttackStage::Active {
+            self.is_dashing = true;
             self.dash_start_position = start_position;
-            self.dash_cooldown.reset();
             self.has_hit_player = false; // Reset hit tracking for new dash
             self.dash_timer.reset(); // Reset dash timer
-            self.last_position = start_position;
-            self.stuck_frames = 0;
         }
     }
 
@@ -70,32 +182,37 @@ impl BossSkills {
             // Check if we've traveled the full dash distance
             let distance_traveled = self.dash_start_position.distance(current_position);
             
-            // Check if we're stuck (not moving much)
-            let movement_this_frame = self.last_position.distance(current_position);
-            if movement_this_frame < 1.0 { // Less than 1 unit of movement
-                self.stuck_frames += 1;
-            } else {
-                self.stuck_frames = 0;
-            }
-            self.last_position = current_position;
-            
             // Complete dash if any of these conditions are met:
             // 1. Traveled full distance
             // 2. Reached target (within 50 units)
-            // 3. Been stuck for too many frames (likely hit boundary)
-            // 4. Dash timer expired (failsafe)
+            // 3. Dash timer expired (failsafe)
+            // Running into the arena edge or an obstacle ends the dash where it happens
             let reached_target = current_position.distance(self.dash_target) < 50.0;
-            let stuck_too_long = self.stuck_frames >= 10; // 10 frames without significant movement
             let time_expired = self.dash_timer.finished();
             
-            if distance_traveled >= self.dash_distance || reached_target || stuck_too_long || time_expired {
-                self.is_dashing = false;
+            if distance_traveled >= self.dash_distance || reached_target || time_expired {
+                self.end_dash();
                 return true; // Dash completed
             }
         }
         false
     }
+
+    /// End the dash where the boss is and recover before the next one
+    pub fn end_dash(&mut self) {
+        self.is_dashing = false;
+        self.chain_delay.reset();
+        self.dash_attack.start_recovery();
+    }
     
+    /// Interrupt the current dash (or its windup) and the rest of the chain
+    pub fn stagger(&mut self, seconds: f32) {
+        self.is_dashing = false;
+        self.dash_chain_remaining = 0;
+        self.dash_cooldown.reset();
+        self.dash_attack.cancel(seconds);
+    }
+
     pub fn can_hit_player(&self) -> bool {
         self.is_dashing && !self.has_hit_player
     }
@@ -103,6 +220,223 @@ impl BossSkills {
     pub fn mark_player_hit(&mut self) {
         self.has_hit_player = true;
     }
+
+    /// Apply the dash parameters of a new boss phase
+    pub fn apply_phase(&mut self, phase: &BossPhaseDefinition) {
+        self.dash_cooldown.set_duration(std::time::Duration::from_secs_f32(phase.dash_cooldown));
+        self.chain_delay.set_duration(std::time::Duration::from_secs_f32(phase.chain_delay));
+        self.dash_chain_length = phase.dash_chain.max(1);
+        if let Some(windup) = phase.windup {
+            self.dash_attack.windup.set_duration(std::time::Duration::from_secs_f32(windup));
+        }
+    }
+}
+
+/// Component for the mine-laying skill
+#[derive(Component, Debug)]
+pub struct MineSkill {
+    /// Stays finished until the next cast, so a cast held back by a dash happens right after it
+    pub cooldown: Timer,
+    pub mines_per_cast: u32,
+    pub scatter_distance: f32,
+    pub arming_time: f32,
+    pub trigger_radius: f32,
+    pub explosion_radius: f32,
+    pub damage: f32,
+    pub regrow_time: f32,
+}
+
+impl MineSkill {
+    pub fn new(definition: &MineSkillDefinition) -> Self {
+        Self {
+            cooldown: Timer::from_seconds(definition.cooldown, TimerMode::Once),
+            mines_per_cast: definition.mines_per_cast,
+            scatter_distance: definition.scatter_distance,
+            arming_time: definition.arming_time,
+            trigger_radius: definition.trigger_radius,
+            explosion_radius: definition.explosion_radius,
+            damage: definition.damage,
+            regrow_time: definition.regrow_time,
+        }
+    }
+}
+
+/// Runtime state of one bullet pattern skill
+#[derive(Debug)]
+pub struct BulletPatternSkill {
+    /// Stays finished until the next volley, so a volley held back by a dash fires right after it
+    pub cooldown: Timer,
+    pub min_phase: usize,
+    pub pattern: BulletPattern,
+    pub projectile: HostileProjectileDefinition,
+    /// Rounds left in the current volley
+    pub shots_remaining: u32,
+    /// Time until the next round of the current volley
+    pub shot_timer: Timer,
+    /// Base angle of the next round, advanced by spiral patterns
+    pub angle: f32,
+}
+
+impl BulletPatternSkill {
+    pub fn new(definition: &BulletPatternSkillDefinition) -> Self {
+        let interval = match definition.pattern {
+            BulletPattern::Ring { .. } => 0.0,
+            BulletPattern::Spiral { interval, .. } | BulletPattern::AimedBurst { interval, .. } => interval,
+        };
+
+        Self {
+            cooldown: Timer::from_seconds(definition.cooldown, TimerMode::Once),
+            min_phase: definition.min_phase,
+            pattern: definition.pattern,
+            projectile: definition.projectile.clone(),
+            shots_remaining: 0,
+            shot_timer: Timer::from_seconds(interval, TimerMode::Repeating),
+            angle: 0.0,
+        }
+    }
+
+    /// Number of rounds in one volley
+    pub fn shots_per_volley(&self) -> u32 {
+        match self.pattern {
+            BulletPattern::Ring { .. } => 1,
+            BulletPattern::Spiral { shots, .. } | BulletPattern::AimedBurst { shots, .. } => shots,
+        }
+    }
+
+    pub fn is_firing(&self) -> bool {
+        self.shots_remaining > 0
+    }
+
+    /// Start a new volley, the first round fires immediately
+    pub fn start_volley(&mut self) {
+        self.shots_remaining = self.shots_per_volley();
+        self.cooldown.reset();
+        self.shot_timer.reset();
+        self.angle = 0.0;
+    }
+
+    /// Directions of the bullets in the next round, `aim` is the boss -> player direction
+    pub fn next_round(&mut self, aim: Vec2) -> Vec<Vec2> {
+        self.shots_remaining = self.shots_remaining.saturating_sub(1);
+
+        match self.pattern {
+            BulletPattern::Ring { count } => evenly_spaced(count, aim.to_angle()),
+            BulletPattern::Spiral { arms, rotation_step, .. } => {
+                let directions = evenly_spaced(arms, self.angle);
+                self.angle += rotation_step;
+                directions
+            }
+            BulletPattern::AimedBurst { bullets, spread, .. } => {
+                let base_angle = aim.to_angle();
+                if bullets <= 1 {
+                    return vec![aim];
+                }
+                (0..bullets)
+                    .map(|i| {
+                        let offset = spread * (i as f32 / (bullets - 1) as f32 - 0.5);
+                        Vec2::from_angle(base_angle + offset)
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+fn evenly_spaced(count: u32, start_angle: f32) -> Vec<Vec2> {
+    (0..count)
+        .map(|i| Vec2::from_angle(start_angle + std::f32::consts::TAU * i as f32 / count as f32))
+        .collect()
+}
+
+/// Component holding all bullet pattern skills of a boss
+#[derive(Component, Debug)]
+pub struct BulletSkills {
+    pub skills: Vec<BulletPatternSkill>,
+}
+
+impl BulletSkills {
+    pub fn new(definitions: &[BulletPatternSkillDefinition]) -> Self {
+        Self {
+            skills: definitions.iter().map(BulletPatternSkill::new).collect(),
+        }
+    }
+}
+
+/// Component for a boss spike that can be detached as a mine and regrows afterwards
+#[derive(Component, Debug)]
+pub struct MineSpike {
+    pub attached: bool,
+    pub regrow_timer: Timer,
+}
+
+impl Default for MineSpike {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MineSpike {
+    pub fn new() -> Self {
+        Self {
+            attached: true,
+            regrow_timer: Timer::from_seconds(0.0, TimerMode::Once),
+        }
+    }
+
+    pub fn detach(&mut self, regrow_time: f32) {
+        self.attached = false;
+        self.regrow_timer = Timer::from_seconds(regrow_time, TimerMode::Once);
+    }
+
+    /// Advance regrowth, returns true when the spike has just grown back
+    pub fn update_regrowth(&mut self, delta_time: std::time::Duration) -> bool {
+        if !self.attached && self.regrow_timer.tick(delta_time).finished() {
+            self.attached = true;
+            return true;
+        }
+        false
+    }
+}
+
+/// Component for the boss phase state machine driven by health thresholds
+#[derive(Component, Debug)]
+pub struct BossPhase {
+    /// Current phase index, 0 is the base phase from the boss stats
+    pub current: usize,
+    /// Phases after the base phase, ordered from highest to lowest threshold
+    pub phases: Vec<BossPhaseDefinition>,
+    /// Movement pattern of the current phase
+    pub movement: BossMovementPattern,
+}
+
+impl BossPhase {
+    pub fn new(phases: Vec<BossPhaseDefinition>) -> Self {
+        Self {
+            current: 0,
+            phases,
+            movement: BossMovementPattern::Chase,
+        }
+    }
+
+    /// Get the phase index matching the given health percentage
+    pub fn phase_for_health(&self, health_percentage: f32) -> usize {
+        self.phases
+            .iter()
+            .take_while(|phase| health_percentage <= phase.health_threshold)
+            .count()
+    }
+
+    /// Get the health thresholds of all phases after the base phase
+    pub fn thresholds(&self) -> impl Iterator<Item = f32> + '_ {
+        self.phases.iter().map(|phase| phase.health_threshold)
+    }
+}
+
+/// Component for the world-space telegraph drawn while a boss winds up its dash
+#[derive(Component, Debug)]
+pub struct DashTelegraph {
+    /// Boss that owns this telegraph
+    pub boss: Entity,
 }
 
 /// Component for rotating animation
@@ -131,16 +465,17 @@ impl RotationAnimation {
     }
 }
 
-/// Bundle for Mine boss with all necessary components
+/// Bundle for any boss built from a `BossDefinition`
 #[derive(Bundle)]
-pub struct MineBossBundle {
+pub struct BossBundle {
     pub boss: Boss,
-    pub mine_boss: MineBoss,
+    pub name: Name,
     pub health: Health,
     pub speed: Speed,
-    pub skills: BossSkills,
     pub collision_damage: CollisionDamage,
     pub collider: Collider,
+    pub layers: CollisionLayers,
+    pub resistances: Resistances,
     pub rotation_animation: RotationAnimation,
     pub bounded_movement: BoundedMovement,
     pub transform: Transform,
@@ -148,22 +483,27 @@ pub struct MineBossBundle {
     pub material: MeshMaterial2d<ColorMaterial>,
 }
 
-impl MineBossBundle {
+impl BossBundle {
     pub fn new(
-        max_health: f32,
-        speed: f32,
+        definition: &BossDefinition,
         position: Vec3,
         mesh: Handle<Mesh>,
         material: Handle<ColorMaterial>,
     ) -> Self {
         Self {
             boss: Boss,
-            mine_boss: MineBoss,
-            health: Health::new(max_health),
-            speed: Speed::new(speed),
-            skills: BossSkills::default(),
-            collision_damage: CollisionDamage::new(20.0, 0.5), // 20 DPS, damage every 0.5 seconds
-            collider: Collider::new(30.0), // Boss radius
+            name: Name::new(definition.name.clone()),
+            health: Health::new(definition.stats.max_health),
+            speed: Speed::new(definition.stats.move_speed),
+            collision_damage: CollisionDamage::new(
+                definition.stats.contact_damage_per_second,
+                definition.stats.contact_damage_interval,
+            ),
+            collider: Collider::circle(definition.collider_radius),
+            layers: CollisionLayers::new(CollisionLayer::Boss),
+            resistances: Resistances {
+                multipliers: definition.resistances.clone(),
+            },
             rotation_animation: RotationAnimation::default(),
             bounded_movement: BoundedMovement,
             transform: Transform::from_translation(position),
@@ -173,24 +513,35 @@ impl MineBossBundle {
     }
 }
 
-/// Bundle for Mine boss spikes (the 8 squares around the circle)
+/// Marker component for a part of a boss body with its own hitbox, hits on it count as hits on the boss
+#[derive(Component, Debug)]
+pub struct BossPart;
+
+/// Bundle for boss parts around the body (e.g. the Mine boss spikes)
 #[derive(Bundle)]
-pub struct MineSpikeBundle {
+pub struct BossPartBundle {
+    pub part: BossPart,
+    pub collider: Collider,
+    pub layers: CollisionLayers,
     pub transform: Transform,
     pub mesh: Mesh2d,
     pub material: MeshMaterial2d<ColorMaterial>,
 }
 
-impl MineSpikeBundle {
+impl BossPartBundle {
     pub fn new(
         position: Vec3,
+        collider: Collider,
         mesh: Handle<Mesh>,
         material: Handle<ColorMaterial>,
     ) -> Self {
         Self {
+            part: BossPart,
+            collider,
+            layers: CollisionLayers::new(CollisionLayer::Boss),
             transform: Transform::from_translation(position),
             mesh: Mesh2d(mesh),
             material: MeshMaterial2d(material),
         }
     }
-} 
\ No newline at end of file
+}
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use crate::components::attributes::Collider;
+use crate::collision::{CollisionLayer, CollisionLayers};
+use crate::components::entities::MineSkill;
+
+/// Component for a stationary mine dropped by the Mine boss
+#[derive(Component, Debug)]
+pub struct Mine {
+    /// Boss that laid this mine
+    pub owner: Entity,
+    /// Timer until the mine can trigger
+    pub arming_timer: Timer,
+    pub explosion_radius: f32,
+    pub damage: f32,
+}
+
+impl Mine {
+    pub fn is_armed(&self) -> bool {
+        self.arming_timer.finished()
+    }
+
+    /// Advance arming, returns true when the mine has just become armed
+    pub fn update_arming(&mut self, delta_time: std::time::Duration) -> bool {
+        self.arming_timer.tick(delta_time).just_finished()
+    }
+}
+
+/// Component for the short-lived explosion flash of a mine
+#[derive(Component, Debug)]
+pub struct ExplosionFlash {
+    pub timer: Timer,
+}
+
+/// Bundle for mine entities, the collider radius is the proximity trigger radius
+#[derive(Bundle)]
+pub struct MineBundle {
+    pub mine: Mine,
+    pub collider: Collider,
+    pub layers: CollisionLayers,
+    pub transform: Transform,
+    pub mesh: Mesh2d,
+    pub material: MeshMaterial2d<ColorMaterial>,
+}
+
+impl MineBundle {
+    pub fn new(
+        owner: Entity,
+        skill: &MineSkill,
+        position: Vec3,
+        mesh: Handle<Mesh>,
+        material: Handle<ColorMaterial>,
+    ) -> Self {
+        Self {
+            mine: Mine {
+                owner,
+                arming_timer: Timer::from_seconds(skill.arming_time, TimerMode::Once),
+                explosion_radius: skill.explosion_radius,
+                damage: skill.damage,
+            },
+            collider: Collider::circle(skill.trigger_radius),
+            layers: CollisionLayers::new(CollisionLayer::Hazard),
+            transform: Transform::from_translation(position),
+            mesh: Mesh2d(mesh),
+            material: MeshMaterial2d(material),
+        }
+    }
+}
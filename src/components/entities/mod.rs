@@ -1,7 +1,11 @@
 pub mod player;
 pub mod boss;
 pub mod weapon;
+pub mod mine;
+pub mod obstacle;
 
 pub use player::*;
 pub use boss::*;
 pub use weapon::*;
+pub use mine::*;
+pub use obstacle::*;
@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use crate::assets::ObstacleDefinition;
+use crate::components::attributes::Collider;
+use crate::collision::{CollisionLayer, CollisionLayers};
+
+/// Marker component for arena cover that blocks movement, projectiles and dashes
+#[derive(Component, Debug)]
+pub struct Obstacle;
+
+/// Bundle for obstacle entities, destructible ones also get a `Health`
+#[derive(Bundle)]
+pub struct ObstacleBundle {
+    pub obstacle: Obstacle,
+    pub collider: Collider,
+    pub layers: CollisionLayers,
+    pub transform: Transform,
+    pub mesh: Mesh2d,
+    pub material: MeshMaterial2d<ColorMaterial>,
+}
+
+impl ObstacleBundle {
+    pub fn new(definition: &ObstacleDefinition, mesh: Handle<Mesh>, material: Handle<ColorMaterial>) -> Self {
+        Self {
+            obstacle: Obstacle,
+            collider: definition.collider(),
+            layers: CollisionLayers::new(CollisionLayer::Wall),
+            transform: definition.transform(),
+            mesh: Mesh2d(mesh),
+            material: MeshMaterial2d(material),
+        }
+    }
+}
@@ -1,10 +1,13 @@
 use bevy::prelude::*;
-use crate::components::attributes::{Health, Speed, Collider, Energy, Invulnerability};
+use crate::components::attributes::{Health, Speed, Collider, Energy, Invulnerability, Resistances};
+use crate::collision::{CollisionLayer, CollisionLayers};
 use crate::components::boundary::BoundedMovement;
+use crate::components::interpolation::InterpolatedTransform;
 use crate::constants::GameBoundaries;
 
 /// Marker component for the player entity
 #[derive(Component, Debug)]
+#[require(InterpolatedTransform)]
 pub struct Player;
 
 /// Marker component for the direction indicator that shows where the player is facing
@@ -103,9 +106,27 @@ pub struct Shield {
     pub activation_energy_cost: f32,
     /// Damage reduction when shield blocks an attack (0.0 = no reduction, 1.0 = full block)
     pub damage_reduction: f32,
+    /// Perfect-parry window started when the shield is raised
+    pub parry_window: Timer,
+    /// How long a parried boss is staggered
+    pub parry_stagger_duration: f32,
+    /// Whether the activation energy was already refunded by a parry this activation
+    pub parry_refunded: bool,
+}
+
+impl Default for Shield {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Shield {
+    /// Distance from the player center to the middle of the shield arc, where the direction indicator sits
+    pub const ARC_RADIUS: f32 = 32.0;
+    pub const THICKNESS: f32 = 4.0;
+    /// Distance from the player center to the outer edge of the shield arc
+    pub const OUTER_RADIUS: f32 = Self::ARC_RADIUS + Self::THICKNESS / 2.0;
+
     pub fn new() -> Self {
         Self {
             length: 0.0,
@@ -115,12 +136,37 @@ impl Shield {
             energy_drain_rate: 20.0, // Energy per second while active
             activation_energy_cost: 10.0, // Minimum energy required to activate
             damage_reduction: 0.5, // 50% damage reduction
+            parry_window: Self::finished_timer(0.2), // No parry until the shield is raised
+            parry_stagger_duration: 1.5,
+            parry_refunded: false,
         }
     }
 
+    fn finished_timer(seconds: f32) -> Timer {
+        let mut timer = Timer::from_seconds(seconds, TimerMode::Once);
+        timer.tick(timer.duration());
+        timer
+    }
+
     pub fn activate(&mut self, target_length: f32) {
         self.is_active = true;
         self.target_length = target_length;
+        self.parry_window.reset();
+        self.parry_refunded = false;
+    }
+
+    /// Whether the shield was raised recently enough to perfect-parry
+    pub fn is_parrying(&self) -> bool {
+        self.is_active && !self.parry_window.finished()
+    }
+
+    /// Energy to refund for a parry, only the first parry of an activation refunds
+    pub fn take_parry_refund(&mut self) -> f32 {
+        if self.parry_refunded {
+            return 0.0;
+        }
+        self.parry_refunded = true;
+        self.activation_energy_cost
     }
 
     pub fn deactivate(&mut self) {
@@ -138,8 +184,37 @@ impl Shield {
             return false;
         }
 
+        Self::arc_contains(self.length, attack_angle, shield_center_angle)
+    }
+
+    /// Check if an attack from the given angle is perfectly parried.
+    /// The arc is still growing during the parry window, so the full target arc is used.
+    pub fn can_parry_attack(&self, attack_angle: f32, shield_center_angle: f32) -> bool {
+        self.is_parrying() && Self::arc_contains(self.target_length, attack_angle, shield_center_angle)
+    }
+
+    /// Arc collider covering what the shield currently blocks, including the full parry arc while it is
+    /// still growing during the parry window. `indicator_local_position` is relative to the player like the shield.
+    pub fn collider(&self, indicator_local_position: Vec3) -> Collider {
+        let length = if !self.is_active {
+            0.0
+        } else if self.is_parrying() {
+            self.length.max(self.target_length)
+        } else {
+            self.length
+        };
+        Collider::Arc {
+            radius: Self::ARC_RADIUS,
+            thickness: Self::THICKNESS,
+            center_angle: indicator_local_position.truncate().to_angle(),
+            half_angle: length * std::f32::consts::PI,
+        }
+    }
+
+    /// Check if `attack_angle` lies within an arc of the given length centered on `shield_center_angle`
+    fn arc_contains(length: f32, attack_angle: f32, shield_center_angle: f32) -> bool {
         // Calculate the shield arc's angular coverage
-        let half_arc_angle = self.length * std::f32::consts::PI; // length * PI gives us half the arc
+        let half_arc_angle = length * std::f32::consts::PI; // length * PI gives us half the arc
         let shield_start_angle = shield_center_angle - half_arc_angle;
         let shield_end_angle = shield_center_angle + half_arc_angle;
 
@@ -158,6 +233,32 @@ impl Shield {
         }
     }
 
+    /// Check if the shield blocks an attack arriving from `attack_direction` (world space, player -> attacker).
+    /// The direction indicator is a child of the player, so its local offset is rotated into world space.
+    pub fn blocks_from_direction(&self, attack_direction: Vec2, player_rotation: Quat, indicator_local_position: Vec3) -> bool {
+        Self::world_angles(attack_direction, player_rotation, indicator_local_position)
+            .is_some_and(|(attack_angle, shield_center_angle)| self.can_block_attack(attack_angle, shield_center_angle))
+    }
+
+    /// Check if an attack arriving from `attack_direction` is perfectly parried, see `blocks_from_direction`
+    pub fn parries_from_direction(&self, attack_direction: Vec2, player_rotation: Quat, indicator_local_position: Vec3) -> bool {
+        Self::world_angles(attack_direction, player_rotation, indicator_local_position)
+            .is_some_and(|(attack_angle, shield_center_angle)| self.can_parry_attack(attack_angle, shield_center_angle))
+    }
+
+    /// World-space attack angle and shield center angle, `None` for undirected attacks
+    fn world_angles(attack_direction: Vec2, player_rotation: Quat, indicator_local_position: Vec3) -> Option<(f32, f32)> {
+        if attack_direction == Vec2::ZERO {
+            return None;
+        }
+
+        let attack_angle = attack_direction.y.atan2(attack_direction.x);
+        let indicator_world_offset = (player_rotation * indicator_local_position).truncate();
+        let shield_center_angle = indicator_world_offset.y.atan2(indicator_world_offset.x);
+
+        Some((attack_angle, shield_center_angle))
+    }
+
     /// Normalize angle to [-PI, PI] range
     fn normalize_angle(angle: f32) -> f32 {
         let mut normalized = angle;
@@ -179,8 +280,10 @@ pub struct PlayerBundle {
     pub speed: Speed,
     pub energy: Energy,
     pub collider: Collider,
+    pub layers: CollisionLayers,
     pub dash: PlayerDash,
     pub invulnerability: Invulnerability,
+    pub resistances: Resistances,
     pub bounded_movement: BoundedMovement,
     pub transform: Transform,
     pub mesh: Mesh2d,
@@ -200,9 +303,11 @@ impl PlayerBundle {
             health: Health::new(max_health),
             speed: Speed::new(speed),
             energy: Energy::new(100.0, 25.0), // 100 max energy, 25 per second recharge
-            collider: Collider::new(25.0), // Player radius
+            collider: Collider::circle(25.0), // Player radius
+            layers: CollisionLayers::new(CollisionLayer::Player),
             dash: PlayerDash::default(),
             invulnerability: Invulnerability::new(),
+            resistances: Resistances::default(),
             bounded_movement: BoundedMovement,
             transform: Transform::from_translation(position),
             mesh: Mesh2d(mesh),
@@ -238,6 +343,8 @@ impl DirectionIndicatorBundle {
 #[derive(Bundle)]
 pub struct ShieldBundle {
     pub shield: Shield,
+    pub collider: Collider,
+    pub layers: CollisionLayers,
     pub transform: Transform,
     pub mesh: Mesh2d,
     pub material: MeshMaterial2d<ColorMaterial>,
@@ -250,6 +357,8 @@ impl ShieldBundle {
     ) -> Self {
         Self {
             shield: Shield::new(),
+            collider: Shield::new().collider(Vec3::X),
+            layers: CollisionLayers::NONE, // Only blocks while raised
             transform: Transform::from_translation(Vec3::new(0.0, 0.0, 0.05)), // Local position relative to parent player
             mesh: Mesh2d(mesh),
             material: MeshMaterial2d(material),
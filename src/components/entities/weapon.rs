@@ -1,44 +1,221 @@
 use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
 use crate::components::attributes::{Speed, Collider};
+use crate::collision::{CollisionLayer, CollisionLayers};
+use crate::components::interpolation::InterpolatedTransform;
+use crate::assets::{WeaponDefinition, FireMode, OnHitEffect};
 
-/// Marker component for weapons
+/// Player-selectable trigger behaviour
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TriggerMode {
+    /// Keeps firing while the trigger is held
+    #[default]
+    Auto,
+    /// Fires one volley (or burst/charge) per trigger pull
+    Semi,
+}
+
+/// Component for the player's weapon, the equipped archetype is looked up in the `WeaponRegistry`
 #[derive(Component, Debug)]
 pub struct Weapon {
+    /// Index of the equipped weapon in the `WeaponRegistry`
+    pub current: usize,
     /// Timer to track when weapon can fire next
     pub fire_timer: Timer,
-    /// Damage dealt by projectiles from this weapon
-    pub damage: f32,
-    /// Speed of projectiles fired by this weapon
-    pub projectile_speed: f32,
-    /// Whether the weapon is currently firing (for automatic weapons)
+    /// Time until the next volley of a burst
+    pub burst_timer: Timer,
+    /// Volleys left in the current burst
+    pub burst_remaining: u32,
+    /// Charge progress of charge weapons
+    pub charge_timer: Timer,
+    /// Whether the weapon is currently firing (trigger held and not locked)
     pub is_firing: bool,
+    pub trigger_mode: TriggerMode,
+    /// Semi mode locks the trigger after a volley until it is released
+    pub trigger_locked: bool,
+    /// Rounds left in each weapon's magazine by registry index, missing entries are full
+    pub magazines: HashMap<usize, u32>,
+    /// Reload progress, only ticking while `is_reloading`
+    pub reload_timer: Timer,
+    pub is_reloading: bool,
+}
+
+impl Default for Weapon {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Weapon {
-    pub fn new(fire_rate: f32, damage: f32, projectile_speed: f32) -> Self {
+    pub fn new() -> Self {
         Self {
-            fire_timer: Timer::from_seconds(1.0 / fire_rate, TimerMode::Repeating),
-            damage,
-            projectile_speed,
+            current: 0,
+            fire_timer: Timer::from_seconds(0.0, TimerMode::Once),
+            burst_timer: Timer::from_seconds(0.0, TimerMode::Once),
+            burst_remaining: 0,
+            charge_timer: Timer::from_seconds(0.0, TimerMode::Once),
             is_firing: false,
+            trigger_mode: TriggerMode::default(),
+            trigger_locked: false,
+            magazines: HashMap::new(),
+            reload_timer: Timer::from_seconds(0.0, TimerMode::Once),
+            is_reloading: false,
         }
     }
-    
-    pub fn can_fire(&self) -> bool {
-        self.fire_timer.finished()
+
+    /// Equip the weapon at `index`, dropping any burst, charge or reload in progress
+    pub fn select(&mut self, index: usize) {
+        self.current = index;
+        self.burst_remaining = 0;
+        self.charge_timer.reset();
+        self.is_reloading = false;
     }
-    
-    pub fn fire(&mut self) {
-        self.fire_timer.reset();
+
+    pub fn toggle_trigger_mode(&mut self) {
+        self.trigger_mode = match self.trigger_mode {
+            TriggerMode::Auto => TriggerMode::Semi,
+            TriggerMode::Semi => TriggerMode::Auto,
+        };
     }
-    
-    pub fn update(&mut self, delta_time: std::time::Duration) {
+
+    /// Update the trigger state from the fire button
+    pub fn set_trigger(&mut self, held: bool) {
+        if !held {
+            self.trigger_locked = false;
+        }
+        self.is_firing = held && !self.trigger_locked;
+    }
+
+    /// Rounds left in the equipped weapon's magazine
+    pub fn ammo(&self, definition: &WeaponDefinition) -> u32 {
+        self.magazines.get(&self.current).copied().unwrap_or(definition.magazine_size)
+    }
+
+    /// Start reloading the equipped weapon unless it is full or already reloading
+    pub fn start_reload(&mut self, definition: &WeaponDefinition) {
+        if self.is_reloading || self.ammo(definition) >= definition.magazine_size {
+            return;
+        }
+        self.is_reloading = true;
+        self.burst_remaining = 0;
+        self.charge_timer.reset();
+        self.reload_timer = Timer::from_seconds(definition.reload_time, TimerMode::Once);
+    }
+
+    /// Reload completion from 0.0 to 1.0
+    pub fn reload_progress(&self) -> f32 {
+        self.reload_timer.fraction()
+    }
+
+    /// Use one round of the equipped weapon, reloading automatically when the magazine runs dry
+    fn consume_round(&mut self, definition: &WeaponDefinition) {
+        let ammo = self.ammo(definition).saturating_sub(1);
+        self.magazines.insert(self.current, ammo);
+        if ammo == 0 {
+            self.start_reload(definition);
+        }
+    }
+
+    /// Advance the weapon timers, returns true if a volley should be fired this frame.
+    /// Without a target to `aim` at the weapon holds its fire, bursts included, and uses no rounds.
+    pub fn update(&mut self, delta_time: std::time::Duration, definition: &WeaponDefinition, aiming: bool) -> bool {
         self.fire_timer.tick(delta_time);
+
+        if self.is_reloading {
+            if self.reload_timer.tick(delta_time).finished() {
+                self.is_reloading = false;
+                self.magazines.insert(self.current, definition.magazine_size);
+            }
+            return false;
+        }
+
+        if !aiming {
+            self.charge_timer.reset();
+            return false;
+        }
+
+        // Remaining volleys of a burst fire on their own
+        if self.burst_remaining > 0 {
+            if self.burst_timer.tick(delta_time).finished() {
+                self.burst_remaining -= 1;
+                self.burst_timer.reset();
+                self.consume_round(definition);
+                return true;
+            }
+            return false;
+        }
+
+        if !self.is_firing {
+            // Releasing the trigger drops the charge
+            self.charge_timer.reset();
+            return false;
+        }
+
+        if !self.fire_timer.finished() {
+            return false;
+        }
+
+        if self.ammo(definition) == 0 {
+            self.start_reload(definition);
+            return false;
+        }
+
+        match definition.fire_mode {
+            FireMode::Auto => {}
+            FireMode::Burst { shots, interval } => {
+                self.burst_remaining = shots.saturating_sub(1);
+                self.burst_timer = Timer::from_seconds(interval, TimerMode::Once);
+            }
+            FireMode::Charge { charge_time } => {
+                self.charge_timer.set_duration(std::time::Duration::from_secs_f32(charge_time));
+                if !self.charge_timer.tick(delta_time).finished() {
+                    return false;
+                }
+                self.charge_timer.reset();
+            }
+        }
+
+        if self.trigger_mode == TriggerMode::Semi {
+            self.trigger_locked = true;
+        }
+        self.fire_timer = Timer::from_seconds(1.0 / definition.fire_rate, TimerMode::Once);
+        self.consume_round(definition);
+        true
     }
 }
 
+/// Team a projectile belongs to, decides what it can hit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Faction {
+    /// Fired by (or reflected by) the player, hits bosses
+    Player,
+    /// Fired by bosses, hits the player
+    Enemy,
+}
+
+impl Faction {
+    /// Collision layer of projectiles of this faction
+    pub fn collision_layer(&self) -> CollisionLayer {
+        match self {
+            Self::Player => CollisionLayer::PlayerProjectile,
+            Self::Enemy => CollisionLayer::EnemyProjectile,
+        }
+    }
+}
+
+/// Component for what happens when a hostile projectile hits the player's shield
+#[derive(Component, Debug, Clone, Copy, Deserialize)]
+pub enum ShieldBlockResponse {
+    /// Projectile is sent back at the boss that fired it
+    Reflect,
+    /// Projectile is destroyed and restores player energy
+    Absorb { energy: f32 },
+}
+
 /// Marker component for projectiles
 #[derive(Component, Debug)]
+#[require(InterpolatedTransform)]
 pub struct Projectile {
     /// Damage dealt by this projectile
     pub damage: f32,
@@ -46,16 +223,60 @@ pub struct Projectile {
     pub direction: Vec3,
     /// Timer to track when projectile should despawn
     pub lifetime_timer: Timer,
+    /// Team of the projectile
+    pub faction: Faction,
+    /// Entity that fired (or last reflected) the projectile
+    pub owner: Entity,
+    /// Number of additional targets the projectile can pass through
+    pub pierce_remaining: u32,
+    /// Targets already hit, so piercing projectiles hit each target once
+    pub hit_entities: Vec<Entity>,
+    /// Extra effects applied to every target hit
+    pub on_hit: Vec<OnHitEffect>,
 }
 
 impl Projectile {
-    pub fn new(damage: f32, direction: Vec3, lifetime: f32) -> Self {
+    pub fn new(damage: f32, direction: Vec3, lifetime: f32, faction: Faction, owner: Entity) -> Self {
         Self {
             damage,
             direction: direction.normalize_or_zero(),
             lifetime_timer: Timer::from_seconds(lifetime, TimerMode::Once),
+            faction,
+            owner,
+            pierce_remaining: 0,
+            hit_entities: Vec::new(),
+            on_hit: Vec::new(),
         }
     }
+
+    pub fn with_pierce(mut self, pierce: u32) -> Self {
+        self.pierce_remaining = pierce;
+        self
+    }
+
+    pub fn with_on_hit(mut self, on_hit: Vec<OnHitEffect>) -> Self {
+        self.on_hit = on_hit;
+        self
+    }
+
+    /// Register a hit on `target`, returns true if the projectile is used up
+    pub fn register_hit(&mut self, target: Entity) -> bool {
+        self.hit_entities.push(target);
+        if self.pierce_remaining == 0 {
+            return true;
+        }
+        self.pierce_remaining -= 1;
+        false
+    }
+
+    /// Send the projectile off in a new direction under a new owner and team
+    pub fn reflect(&mut self, new_owner: Entity, faction: Faction, direction: Vec3) {
+        self.owner = new_owner;
+        self.faction = faction;
+        self.direction = direction.normalize_or_zero();
+        self.lifetime_timer.reset();
+        self.hit_entities.clear();
+    }
     
     pub fn update(&mut self, delta_time: std::time::Duration) -> bool {
         self.lifetime_timer.tick(delta_time);
@@ -63,6 +284,13 @@ impl Projectile {
     }
 }
 
+/// Component for projectiles that steer towards the nearest boss
+#[derive(Component, Debug)]
+pub struct Homing {
+    /// Maximum turn rate in radians per second
+    pub turn_rate: f32,
+}
+
 /// Bundle for weapon entities
 #[derive(Bundle)]
 pub struct WeaponBundle {
@@ -70,10 +298,16 @@ pub struct WeaponBundle {
     pub transform: Transform,
 }
 
+impl Default for WeaponBundle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl WeaponBundle {
-    pub fn new(fire_rate: f32, damage: f32, projectile_speed: f32) -> Self {
+    pub fn new() -> Self {
         Self {
-            weapon: Weapon::new(fire_rate, damage, projectile_speed),
+            weapon: Weapon::new(),
             transform: Transform::default(),
         }
     }
@@ -85,6 +319,7 @@ pub struct ProjectileBundle {
     pub projectile: Projectile,
     pub speed: Speed,
     pub collider: Collider,
+    pub layers: CollisionLayers,
     pub transform: Transform,
     pub mesh: Mesh2d,
     pub material: MeshMaterial2d<ColorMaterial>,
@@ -92,21 +327,27 @@ pub struct ProjectileBundle {
 
 impl ProjectileBundle {
     pub fn new(
-        damage: f32,
-        direction: Vec3,
+        projectile: Projectile,
         speed: f32,
-        lifetime: f32,
+        collider: Collider,
         position: Vec3,
         mesh: Handle<Mesh>,
         material: Handle<ColorMaterial>,
     ) -> Self {
         Self {
-            projectile: Projectile::new(damage, direction, lifetime),
+            layers: CollisionLayers::new(projectile.faction.collision_layer()),
+            projectile,
             speed: Speed::new(speed),
-            collider: Collider::new(3.0), // Small projectile radius
+            collider,
             transform: Transform::from_translation(position),
             mesh: Mesh2d(mesh),
             material: MeshMaterial2d(material),
         }
     }
+
+    /// Rotate the projectile visual to face its travel direction (for elongated shapes)
+    pub fn facing_direction(mut self) -> Self {
+        self.transform.rotation = Quat::from_rotation_z(self.projectile.direction.truncate().to_angle());
+        self
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+/// Component that smooths an entity's `Transform` between simulation ticks.
+/// During `FixedUpdate` the `Transform` holds the simulated state, while rendering it holds a blend
+/// of the last two ticks.
+#[derive(Component, Debug, Default, Clone)]
+pub struct InterpolatedTransform {
+    /// Simulated transform at the start of the last tick
+    pub previous: Option<Transform>,
+    /// Simulated transform at the end of the last tick
+    pub current: Option<Transform>,
+    /// Blended transform written for rendering, used to detect teleports from outside the simulation
+    pub rendered: Option<Transform>,
+}
+
+impl InterpolatedTransform {
+    /// Blend between the last two ticks, `overstep` is the fraction of the next tick already elapsed
+    pub fn interpolate(&self, overstep: f32) -> Option<Transform> {
+        let (previous, current) = (self.previous?, self.current?);
+        Some(Transform {
+            translation: previous.translation.lerp(current.translation, overstep),
+            rotation: previous.rotation.slerp(current.rotation, overstep),
+            scale: previous.scale.lerp(current.scale, overstep),
+        })
+    }
+
+    /// Translation `fraction` of the way through the current tick, for an entity simulated to `transform`
+    pub fn translation_at(&self, transform: &Transform, fraction: f32) -> Vec3 {
+        self.previous.map_or(transform.translation, |previous| previous.translation.lerp(transform.translation, fraction))
+    }
+
+    /// Snap to `transform` without blending, for entities moved outside the simulation
+    pub fn teleport(&mut self, transform: Transform) {
+        self.previous = Some(transform);
+        self.current = Some(transform);
+        self.rendered = None;
+    }
+}
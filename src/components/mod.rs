@@ -2,8 +2,10 @@ pub mod ui;
 pub mod entities;
 pub mod attributes;
 pub mod boundary;
+pub mod interpolation;
 
 pub use ui::*;
 pub use entities::*;
 pub use attributes::*;
 pub use boundary::*;
+pub use interpolation::*;
@@ -1,4 +1,6 @@
 use bevy::prelude::*;
+use crate::input::InputSlot;
+use crate::persistence::{CycleSetting, SliderSetting};
 
 /// Marker component for the main menu UI
 #[derive(Component)]
@@ -16,10 +18,68 @@ pub struct DebugUI;
 #[derive(Component)]
 pub enum MenuButton {
     StartGame,
+    /// Cycles through the difficulties, its label shows the current one
+    Difficulty,
+    LevelSelect,
     DebugMode,
+    Controls,
+    Settings,
+    WatchReplay,
     ExitToDesktop,
 }
 
+/// Marker component for the level select screen UI
+#[derive(Component)]
+pub struct LevelSelectUI;
+
+/// Component to identify different level select screen buttons
+#[derive(Component)]
+pub enum LevelSelectButton {
+    /// Start the encounter at this index of the `EncounterSequence`
+    Encounter(usize),
+    Back,
+}
+
+/// Marker component for the settings screen UI
+#[derive(Component)]
+pub struct SettingsUI;
+
+/// Component to identify different settings screen buttons
+#[derive(Component)]
+pub enum SettingsButton {
+    Previous(CycleSetting),
+    Next(CycleSetting),
+    Back,
+}
+
+/// Component for the text showing the current value of a choice setting
+#[derive(Component)]
+pub struct SettingValueLabel(pub CycleSetting);
+
+/// Component for a settings slider bar, clicked or dragged to set its value
+#[derive(Component)]
+pub struct SettingSlider(pub SliderSetting);
+
+/// Component for the filled part of a settings slider
+#[derive(Component)]
+pub struct SettingSliderFill(pub SliderSetting);
+
+/// Marker component for the controls (key rebinding) screen UI
+#[derive(Component)]
+pub struct ControlsMenuUI;
+
+/// Component to identify different controls screen buttons
+#[derive(Component)]
+pub enum ControlsButton {
+    Rebind(InputSlot),
+    ResetDefaults,
+    Back,
+}
+
+/// Component for the text showing the current bindings of an input slot
+#[derive(Component)]
+pub struct BindingLabel(pub InputSlot);
+
 /// Marker component for pause overlay UI
 #[derive(Component)]
 pub struct PauseOverlayUI;
@@ -52,6 +112,10 @@ pub struct EnergyBarUI;
 #[derive(Component)]
 pub struct EnergyBarFill;
 
+/// Marker component for the ammo counter shown next to the energy bar
+#[derive(Component)]
+pub struct AmmoDisplayUI;
+
 /// Marker component for boss health bar UI
 #[derive(Component)]
 pub struct BossHealthBarUI;
@@ -60,6 +124,15 @@ pub struct BossHealthBarUI;
 #[derive(Component)]
 pub struct BossHealthBarFill;
 
+/// Component for a phase threshold marker on the boss health bar
+#[derive(Component)]
+pub struct BossPhaseMarker {
+    /// Boss whose phases this marker tracks
+    pub boss: Entity,
+    /// Phase index that starts at this marker's threshold
+    pub phase: usize,
+}
+
 /// Marker component for game over UI
 #[derive(Component)]
 pub struct GameOverUI;
@@ -68,6 +141,42 @@ pub struct GameOverUI;
 #[derive(Component)]
 pub enum GameOverButton {
     RestartGame,
+    WatchReplay,
     BackToMenu,
     ExitToDesktop,
-}
\ No newline at end of file
+}
+/// Marker component for victory screen UI
+#[derive(Component)]
+pub struct VictoryUI;
+
+/// Component to identify different victory screen buttons
+#[derive(Component)]
+pub enum VictoryButton {
+    NextBoss,
+    Retry,
+    WatchReplay,
+    BackToMenu,
+}
+
+/// Marker component for the replay playback controls
+#[derive(Component)]
+pub struct ReplayUI;
+
+/// Marker component for the replay timeline, clicked to seek
+#[derive(Component)]
+pub struct ReplayTimeline;
+
+/// Component for the played part of the replay timeline
+#[derive(Component)]
+pub struct ReplayTimelineFill;
+
+/// Marker component for the replay time counter
+#[derive(Component)]
+pub struct ReplayTimeLabel;
+
+/// Component to identify different replay control buttons
+#[derive(Component)]
+pub enum ReplayButton {
+    PlayPause,
+    Exit,
+}
@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// Color constants for consistent theming across the application
 pub struct AppColors;
@@ -7,3 +8,53 @@ impl AppColors {
     /// Mid-gray background color used for all screens
     pub const BACKGROUND: Color = Color::srgb(0.1, 0.1, 0.15);
 }
+
+/// Colors that tell the player something about the fight, with variants for colorblind players
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ColorPalette {
+    #[default]
+    Standard,
+    /// Blue, yellow and orange instead of green and red, for deuteranopia and protanopia
+    RedGreenSafe,
+    /// Teal, pink and red instead of green and yellow, for tritanopia
+    BlueYellowSafe,
+}
+
+impl ColorPalette {
+    pub const ALL: [ColorPalette; 3] = [Self::Standard, Self::RedGreenSafe, Self::BlueYellowSafe];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Standard => "Standard",
+            Self::RedGreenSafe => "Deuteranopia / Protanopia",
+            Self::BlueYellowSafe => "Tritanopia",
+        }
+    }
+
+    /// Full player health
+    pub fn healthy(&self) -> Color {
+        match self {
+            Self::Standard => Color::srgb(0.0, 0.8, 0.0),
+            Self::RedGreenSafe => Color::srgb(0.0, 0.45, 0.7),
+            Self::BlueYellowSafe => Color::srgb(0.0, 0.62, 0.45),
+        }
+    }
+
+    /// Middling player health
+    pub fn caution(&self) -> Color {
+        match self {
+            Self::Standard => Color::srgb(0.8, 0.8, 0.2),
+            Self::RedGreenSafe => Color::srgb(0.94, 0.89, 0.26),
+            Self::BlueYellowSafe => Color::srgb(0.8, 0.47, 0.65),
+        }
+    }
+
+    /// Low player health, armed mines, attack telegraphs and boundary warnings
+    pub fn danger(&self) -> Color {
+        match self {
+            Self::Standard => Color::srgb(1.0, 0.2, 0.2),
+            Self::RedGreenSafe => Color::srgb(0.9, 0.45, 0.0),
+            Self::BlueYellowSafe => Color::srgb(0.9, 0.1, 0.1),
+        }
+    }
+}
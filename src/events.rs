@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use crate::collision::CollisionPair;
+
+/// Category of damage, used for resistances and logging
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
+pub enum DamageKind {
+    /// Sustained damage from touching an enemy body
+    Contact,
+    /// Burst damage from a boss dash hit
+    Dash,
+    /// Damage from a projectile
+    Projectile,
+    /// Area damage from an exploding mine or projectile
+    Explosion,
+    /// Damage over time from a burning effect
+    Burn,
+}
+
+/// Event emitted by every hit source; applied by `resolve_damage_events`
+#[derive(Event, Debug, Clone)]
+pub struct DamageEvent {
+    /// Entity that caused the damage (boss, projectile, ...)
+    pub source: Entity,
+    /// Entity receiving the damage
+    pub target: Entity,
+    /// Raw damage before shield, resistances and i-frames
+    pub amount: f32,
+    pub kind: DamageKind,
+    /// World-space direction the attack travels (source -> target), zero if undirected
+    pub direction: Vec2,
+}
+
+/// Event emitted when a boss crosses a health threshold into a new phase
+#[derive(Event, Debug, Clone)]
+pub struct BossPhaseChanged {
+    pub boss: Entity,
+    /// New phase index (0 is the base phase)
+    pub phase: usize,
+}
+
+/// Event emitted when the player perfectly parries an attack, for stagger, refunds and VFX/audio hooks
+#[derive(Event, Debug, Clone)]
+pub struct ParryEvent {
+    pub player: Entity,
+    /// Entity whose attack was parried (usually a boss)
+    pub attacker: Entity,
+    pub kind: DamageKind,
+    /// World position of the parry
+    pub position: Vec2,
+    /// How long the attacker is staggered
+    pub stagger_duration: f32,
+}
+
+/// Event emitted when a boss's health reaches zero, before it is despawned
+#[derive(Event, Debug, Clone)]
+pub struct BossDefeated {
+    pub boss: Entity,
+}
+
+/// Event emitted on the first tick two colliders whose layers collide touch
+#[derive(Event, Debug, Clone)]
+pub struct CollisionStarted {
+    pub pair: CollisionPair,
+    /// Fraction of the tick, from 0.0 to 1.0, at which they first touched
+    pub time_of_impact: f32,
+}
+
+/// Event emitted on every following tick the two colliders still touch
+#[derive(Event, Debug, Clone)]
+pub struct CollisionOngoing {
+    pub pair: CollisionPair,
+}
+
+/// Event emitted on the first tick two colliders stop touching, or one of them is gone
+#[derive(Event, Debug, Clone)]
+pub struct CollisionEnded {
+    pub pair: CollisionPair,
+}
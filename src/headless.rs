@@ -0,0 +1,130 @@
+//! Headless app configuration for automated gameplay tests.
+//!
+//! Runs the game plugins on top of `MinimalPlugins` with a fixed timestep and no window,
+//! renderer or input devices. Tests drive the player by pressing keys and mouse buttons on the
+//! `ButtonInput` resources and by setting `CursorWorldPosition` directly.
+use std::time::Duration;
+use bevy::prelude::*;
+use bevy::input::mouse::{MouseMotion, MouseWheel};
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use crate::plugins::GameAppPlugin;
+use crate::persistence::ConfigStorage;
+use crate::resources::{CursorWorldPosition, SimulationSettings};
+use crate::states::AppState;
+
+/// Simulated time advanced by every `App::update` of a headless app, exactly one simulation tick
+pub const HEADLESS_TIMESTEP: f32 = (1.0 / SimulationSettings::DEFAULT_TICK_RATE) as f32;
+
+/// Build the game without a window or renderer
+pub fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin, AssetPlugin::default(), SimulatedInputPlugin))
+        .init_asset::<Mesh>()
+        .init_asset::<ColorMaterial>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Time::<Fixed>::from_hz(SimulationSettings::DEFAULT_TICK_RATE).timestep()))
+        // Tests always run with the default bindings and never write config files
+        .insert_resource(ConfigStorage::disabled())
+        .add_plugins(GameAppPlugin);
+    app
+}
+
+/// Input resources driven by tests instead of input devices
+pub struct SimulatedInputPlugin;
+
+impl Plugin for SimulatedInputPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<ButtonInput<MouseButton>>()
+            .add_event::<MouseWheel>()
+            .add_event::<MouseMotion>()
+            .add_systems(Last, clear_simulated_input);
+    }
+}
+
+/// System to end the just-pressed/just-released state of simulated presses after one frame,
+/// like `InputPlugin` does for real devices
+fn clear_simulated_input(
+    mut keyboard_input: ResMut<ButtonInput<KeyCode>>,
+    mut mouse_input: ResMut<ButtonInput<MouseButton>>,
+) {
+    keyboard_input.clear();
+    mouse_input.clear();
+}
+
+/// Helpers for driving a headless app from tests
+pub trait HeadlessAppExt {
+    /// Switch to `state` and run the frame that applies the transition
+    fn enter_state(&mut self, state: AppState);
+    /// Run simulated frames covering `seconds`
+    fn run_for(&mut self, seconds: f32);
+    /// Run frames until `condition` holds, returns the simulated seconds it took or `None` on timeout
+    fn run_until(&mut self, max_seconds: f32, condition: impl FnMut(&mut World) -> bool) -> Option<f32>;
+    /// Run frames until `condition` holds, yielding real time between frames so background
+    /// asset loading can finish. Returns false if `timeout` of real time passed first.
+    fn wait_for(&mut self, timeout: Duration, condition: impl FnMut(&mut World) -> bool) -> bool;
+    fn press_key(&mut self, key: KeyCode);
+    fn release_key(&mut self, key: KeyCode);
+    fn press_mouse_button(&mut self, button: MouseButton);
+    fn release_mouse_button(&mut self, button: MouseButton);
+    /// Move the simulated cursor to a world position
+    fn aim_at(&mut self, position: Vec2);
+}
+
+impl HeadlessAppExt for App {
+    fn enter_state(&mut self, state: AppState) {
+        self.world_mut().resource_mut::<NextState<AppState>>().set(state);
+        self.update();
+    }
+
+    fn run_for(&mut self, seconds: f32) {
+        let frames = (seconds / HEADLESS_TIMESTEP).round() as u32;
+        for _ in 0..frames {
+            self.update();
+        }
+    }
+
+    fn run_until(&mut self, max_seconds: f32, mut condition: impl FnMut(&mut World) -> bool) -> Option<f32> {
+        let max_frames = (max_seconds / HEADLESS_TIMESTEP).round() as u32;
+        for frame in 0..max_frames {
+            if condition(self.world_mut()) {
+                return Some(frame as f32 * HEADLESS_TIMESTEP);
+            }
+            self.update();
+        }
+        None
+    }
+
+    fn wait_for(&mut self, timeout: Duration, mut condition: impl FnMut(&mut World) -> bool) -> bool {
+        let start = std::time::Instant::now();
+        while start.elapsed() < timeout {
+            if condition(self.world_mut()) {
+                return true;
+            }
+            self.update();
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        false
+    }
+
+    fn press_key(&mut self, key: KeyCode) {
+        self.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(key);
+    }
+
+    fn release_key(&mut self, key: KeyCode) {
+        self.world_mut().resource_mut::<ButtonInput<KeyCode>>().release(key);
+    }
+
+    fn press_mouse_button(&mut self, button: MouseButton) {
+        self.world_mut().resource_mut::<ButtonInput<MouseButton>>().press(button);
+    }
+
+    fn release_mouse_button(&mut self, button: MouseButton) {
+        self.world_mut().resource_mut::<ButtonInput<MouseButton>>().release(button);
+    }
+
+    fn aim_at(&mut self, position: Vec2) {
+        self.world_mut().resource_mut::<CursorWorldPosition>().position = Some(position);
+    }
+}
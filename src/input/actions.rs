@@ -0,0 +1,212 @@
+use bevy::prelude::*;
+use std::collections::HashSet;
+use std::ops::Deref;
+use crate::input::{TickInput, WeaponRequest};
+
+/// Everything the player can do, independent of the device or key it is bound to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlayerAction {
+    /// Analog movement direction, see `ActionState::move_axis`
+    Move,
+    /// Analog aim target, see `ActionState::aim_direction_from`
+    Aim,
+    Dash,
+    Shield,
+    Fire,
+    Pause,
+    Reload,
+    ToggleTriggerMode,
+}
+
+impl PlayerAction {
+    /// The digital actions, which are pressed and released like buttons
+    pub const BUTTONS: [PlayerAction; 6] = [
+        PlayerAction::Dash,
+        PlayerAction::Shield,
+        PlayerAction::Fire,
+        PlayerAction::Pause,
+        PlayerAction::Reload,
+        PlayerAction::ToggleTriggerMode,
+    ];
+}
+
+/// What the player is aiming with
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AimTarget {
+    /// A world position, from the mouse cursor
+    Position(Vec2),
+    /// A fixed world direction, from a gamepad stick
+    Direction(Vec2),
+}
+
+impl AimTarget {
+    /// Normalized aim direction for something at `origin`
+    pub fn direction_from(&self, origin: Vec2) -> Option<Vec2> {
+        match self {
+            AimTarget::Position(position) => (*position - origin).try_normalize(),
+            AimTarget::Direction(direction) => Some(*direction),
+        }
+    }
+}
+
+/// Resource with the current state of every `PlayerAction`, rebuilt each frame from the `InputBindings`.
+/// Simulation systems in `FixedUpdate` read `FixedActionState` instead.
+#[derive(Resource, Debug, Default)]
+pub struct ActionState {
+    buttons: ButtonInput<PlayerAction>,
+    move_axis: Vec2,
+    aim: Option<AimTarget>,
+    weapon_request: Option<WeaponRequest>,
+}
+
+impl ActionState {
+    pub fn pressed(&self, action: PlayerAction) -> bool {
+        self.buttons.pressed(action)
+    }
+
+    pub fn just_pressed(&self, action: PlayerAction) -> bool {
+        self.buttons.just_pressed(action)
+    }
+
+    pub fn just_released(&self, action: PlayerAction) -> bool {
+        self.buttons.just_released(action)
+    }
+
+    /// Movement direction, zero when not moving, at most length 1
+    pub fn move_axis(&self) -> Vec2 {
+        self.move_axis
+    }
+
+    pub fn aim(&self) -> Option<AimTarget> {
+        self.aim
+    }
+
+    /// Normalized aim direction for something at `origin`
+    pub fn aim_direction_from(&self, origin: Vec2) -> Option<Vec2> {
+        self.aim?.direction_from(origin)
+    }
+
+    /// Weapon change requested this frame, if any
+    pub fn weapon_request(&self) -> Option<WeaponRequest> {
+        self.weapon_request
+    }
+
+    /// Start a new frame, ending the just-pressed/just-released state of the previous one
+    pub fn begin_frame(&mut self) {
+        self.buttons.clear();
+    }
+
+    /// Set whether a digital action is held this frame
+    pub fn set_pressed(&mut self, action: PlayerAction, pressed: bool) {
+        if pressed {
+            self.buttons.press(action);
+        } else {
+            self.buttons.release(action);
+        }
+    }
+
+    pub fn set_move_axis(&mut self, axis: Vec2) {
+        self.move_axis = axis.clamp_length_max(1.0);
+    }
+
+    pub fn set_aim(&mut self, aim: Option<AimTarget>) {
+        self.aim = aim;
+    }
+
+    pub fn set_weapon_request(&mut self, request: Option<WeaponRequest>) {
+        self.weapon_request = request;
+    }
+}
+
+/// Resource with the actions as seen by the simulation, advanced once per `FixedUpdate` tick.
+/// Presses and releases between two ticks are buffered so every tick sees each of them exactly once,
+/// however many frames or ticks run in between.
+#[derive(Resource, Debug, Default)]
+pub struct FixedActionState {
+    state: ActionState,
+    /// Presses (`true`) and releases (`false`) not yet seen by a tick, oldest first
+    pending: Vec<(PlayerAction, bool)>,
+    /// Actions held in the latest frame
+    held: HashSet<PlayerAction>,
+    /// Latest weapon change not yet seen by a tick
+    pending_weapon: Option<WeaponRequest>,
+}
+
+impl Deref for FixedActionState {
+    type Target = ActionState;
+
+    fn deref(&self) -> &ActionState {
+        &self.state
+    }
+}
+
+impl FixedActionState {
+    /// Buffer the presses and releases of a frame until the next tick.
+    /// Presses while the simulation is paused are dropped so menu clicks don't leak into the game.
+    pub fn buffer_frame(&mut self, frame: &ActionState, paused: bool) {
+        self.state.move_axis = frame.move_axis;
+        self.state.aim = frame.aim;
+        self.held = PlayerAction::BUTTONS.into_iter().filter(|action| frame.pressed(*action)).collect();
+
+        if paused {
+            self.pending.clear();
+            self.pending_weapon = None;
+            return;
+        }
+        if frame.weapon_request.is_some() {
+            self.pending_weapon = frame.weapon_request;
+        }
+        for action in PlayerAction::BUTTONS {
+            if frame.just_pressed(action) {
+                self.pending.push((action, true));
+            } else if frame.just_released(action) {
+                self.pending.push((action, false));
+            }
+        }
+    }
+
+    /// Start a tick, applying at most one buffered press or release per action so a quick tap
+    /// is still seen as pressed for one tick, then released on the next
+    pub fn begin_tick(&mut self) {
+        self.state.begin_frame();
+        self.state.weapon_request = self.pending_weapon.take();
+
+        let state = &mut self.state;
+        let mut applied = Vec::new();
+        self.pending.retain(|&(action, pressed)| {
+            if applied.contains(&action) {
+                return true;
+            }
+            applied.push(action);
+            state.set_pressed(action, pressed);
+            false
+        });
+
+        for action in PlayerAction::BUTTONS {
+            if !applied.contains(&action) {
+                self.state.set_pressed(action, self.held.contains(&action));
+            }
+        }
+    }
+
+    /// Reduce this tick's actions to a `TickInput`, aiming from `origin`, and replace them with
+    /// its quantized values so the tick plays out exactly like a replay of it will
+    pub fn resolve(&mut self, origin: Option<Vec2>) -> TickInput {
+        let aim_direction = origin.and_then(|origin| self.state.aim_direction_from(origin));
+        let input = TickInput::new(self.state.move_axis, aim_direction, |action| self.state.pressed(action), self.state.weapon_request);
+        self.state.move_axis = input.move_axis();
+        self.state.aim = input.aim_direction().map(AimTarget::Direction);
+        input
+    }
+
+    /// Start a tick from recorded input instead of the buffered frames
+    pub fn apply_tick_input(&mut self, input: &TickInput) {
+        self.state.begin_frame();
+        for action in PlayerAction::BUTTONS {
+            self.state.set_pressed(action, input.pressed(action));
+        }
+        self.state.move_axis = input.move_axis();
+        self.state.aim = input.aim_direction().map(AimTarget::Direction);
+        self.state.weapon_request = input.weapon;
+    }
+}
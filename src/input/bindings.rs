@@ -0,0 +1,193 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use crate::input::{InputDevices, PlayerAction};
+
+/// Digital inputs that can be rebound, the four movement directions together form `PlayerAction::Move`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum InputSlot {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Dash,
+    Shield,
+    Fire,
+    Pause,
+    Reload,
+    ToggleTriggerMode,
+}
+
+impl InputSlot {
+    pub const ALL: [InputSlot; 10] = [
+        InputSlot::MoveUp,
+        InputSlot::MoveDown,
+        InputSlot::MoveLeft,
+        InputSlot::MoveRight,
+        InputSlot::Dash,
+        InputSlot::Shield,
+        InputSlot::Fire,
+        InputSlot::Pause,
+        InputSlot::Reload,
+        InputSlot::ToggleTriggerMode,
+    ];
+
+    /// Name shown on the controls screen
+    pub fn label(&self) -> &'static str {
+        match self {
+            InputSlot::MoveUp => "Move Up",
+            InputSlot::MoveDown => "Move Down",
+            InputSlot::MoveLeft => "Move Left",
+            InputSlot::MoveRight => "Move Right",
+            InputSlot::Dash => "Dash",
+            InputSlot::Shield => "Shield",
+            InputSlot::Fire => "Fire",
+            InputSlot::Pause => "Pause",
+            InputSlot::Reload => "Reload",
+            InputSlot::ToggleTriggerMode => "Auto/Semi",
+        }
+    }
+
+    /// Digital action driven by this slot, `None` for the movement directions
+    pub fn action(&self) -> Option<PlayerAction> {
+        match self {
+            InputSlot::MoveUp | InputSlot::MoveDown | InputSlot::MoveLeft | InputSlot::MoveRight => None,
+            InputSlot::Dash => Some(PlayerAction::Dash),
+            InputSlot::Shield => Some(PlayerAction::Shield),
+            InputSlot::Fire => Some(PlayerAction::Fire),
+            InputSlot::Pause => Some(PlayerAction::Pause),
+            InputSlot::Reload => Some(PlayerAction::Reload),
+            InputSlot::ToggleTriggerMode => Some(PlayerAction::ToggleTriggerMode),
+        }
+    }
+
+    fn default_bindings(&self) -> Vec<InputBinding> {
+        match self {
+            InputSlot::MoveUp => vec![InputBinding::Key(KeyCode::KeyW), InputBinding::Key(KeyCode::ArrowUp), InputBinding::Gamepad(GamepadButton::DPadUp)],
+            InputSlot::MoveDown => vec![InputBinding::Key(KeyCode::KeyS), InputBinding::Key(KeyCode::ArrowDown), InputBinding::Gamepad(GamepadButton::DPadDown)],
+            InputSlot::MoveLeft => vec![InputBinding::Key(KeyCode::KeyA), InputBinding::Key(KeyCode::ArrowLeft), InputBinding::Gamepad(GamepadButton::DPadLeft)],
+            InputSlot::MoveRight => vec![InputBinding::Key(KeyCode::KeyD), InputBinding::Key(KeyCode::ArrowRight), InputBinding::Gamepad(GamepadButton::DPadRight)],
+            InputSlot::Dash => vec![InputBinding::Key(KeyCode::Space), InputBinding::Gamepad(GamepadButton::South)],
+            InputSlot::Shield => vec![InputBinding::Mouse(MouseButton::Right), InputBinding::Gamepad(GamepadButton::LeftTrigger2)],
+            InputSlot::Fire => vec![InputBinding::Mouse(MouseButton::Left), InputBinding::Gamepad(GamepadButton::RightTrigger2)],
+            InputSlot::Pause => vec![InputBinding::Key(KeyCode::Escape), InputBinding::Gamepad(GamepadButton::Start)],
+            InputSlot::Reload => vec![InputBinding::Key(KeyCode::KeyR), InputBinding::Gamepad(GamepadButton::West)],
+            InputSlot::ToggleTriggerMode => vec![InputBinding::Key(KeyCode::KeyB), InputBinding::Gamepad(GamepadButton::North)],
+        }
+    }
+}
+
+/// A physical key, mouse button or gamepad button
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InputBinding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+    Gamepad(GamepadButton),
+}
+
+impl InputBinding {
+    pub fn is_gamepad(&self) -> bool {
+        matches!(self, InputBinding::Gamepad(_))
+    }
+
+    /// Short name shown on the controls screen
+    pub fn label(&self) -> String {
+        match self {
+            InputBinding::Key(key) => format!("{:?}", key).trim_start_matches("Key").trim_start_matches("Digit").to_string(),
+            InputBinding::Mouse(MouseButton::Left) => "Left Mouse".to_string(),
+            InputBinding::Mouse(MouseButton::Right) => "Right Mouse".to_string(),
+            InputBinding::Mouse(MouseButton::Middle) => "Middle Mouse".to_string(),
+            InputBinding::Mouse(button) => format!("Mouse {:?}", button),
+            InputBinding::Gamepad(button) => format!("Pad {:?}", button),
+        }
+    }
+}
+
+/// Radial dead zones of the gamepad sticks, as a fraction of full deflection
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StickDeadZones {
+    pub move_stick: f32,
+    pub aim_stick: f32,
+}
+
+impl Default for StickDeadZones {
+    fn default() -> Self {
+        Self {
+            move_stick: 0.2,
+            aim_stick: 0.3,
+        }
+    }
+}
+
+impl StickDeadZones {
+    /// Zero inside the dead zone, rescaled so the output still ramps from 0 to 1 outside it
+    pub fn apply(dead_zone: f32, stick: Vec2) -> Vec2 {
+        let length = stick.length();
+        if length <= dead_zone {
+            return Vec2::ZERO;
+        }
+        let scaled = ((length - dead_zone) / (1.0 - dead_zone)).min(1.0);
+        stick / length * scaled
+    }
+}
+
+/// Resource mapping every `InputSlot` to the keys and buttons that trigger it
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InputBindings {
+    pub bindings: BTreeMap<InputSlot, Vec<InputBinding>>,
+    #[serde(default)]
+    pub dead_zones: StickDeadZones,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        Self {
+            bindings: InputSlot::ALL.iter().map(|slot| (*slot, slot.default_bindings())).collect(),
+            dead_zones: StickDeadZones::default(),
+        }
+    }
+}
+
+impl InputBindings {
+    /// File the bindings are persisted to inside the config directory
+    pub const FILE_NAME: &'static str = "input_bindings.ron";
+
+    pub fn get(&self, slot: InputSlot) -> &[InputBinding] {
+        self.bindings.get(&slot).map_or(&[], Vec::as_slice)
+    }
+
+    /// Replace the bindings of `slot` on the same kind of device (keyboard and mouse, or gamepad)
+    /// with a single binding, unbinding it from any other slot
+    pub fn rebind(&mut self, slot: InputSlot, binding: InputBinding) {
+        for bindings in self.bindings.values_mut() {
+            bindings.retain(|existing| *existing != binding);
+        }
+        let bindings = self.bindings.entry(slot).or_default();
+        bindings.retain(|existing| existing.is_gamepad() != binding.is_gamepad());
+        bindings.push(binding);
+        bindings.sort_by_key(InputBinding::is_gamepad);
+    }
+
+    /// Whether any binding of `slot` is held
+    pub fn is_pressed(&self, slot: InputSlot, devices: &InputDevices) -> bool {
+        self.get(slot).iter().any(|binding| devices.pressed(*binding))
+    }
+
+    /// Fill in defaults for slots missing from an older or hand-edited config file
+    pub fn with_missing_defaults(mut self) -> Self {
+        for slot in InputSlot::ALL {
+            self.bindings.entry(slot).or_insert_with(|| slot.default_bindings());
+        }
+        self
+    }
+
+    /// Bindings of `slot` joined for display, e.g. "W / ArrowUp"
+    pub fn describe(&self, slot: InputSlot) -> String {
+        let labels: Vec<String> = self.get(slot).iter().map(InputBinding::label).collect();
+        if labels.is_empty() {
+            "Unbound".to_string()
+        } else {
+            labels.join(" / ")
+        }
+    }
+}
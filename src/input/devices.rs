@@ -0,0 +1,53 @@
+use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
+use crate::input::InputBinding;
+
+/// Keyboard, mouse and connected gamepads, read together by the bindings
+#[derive(SystemParam)]
+pub struct InputDevices<'w, 's> {
+    pub keyboard: Res<'w, ButtonInput<KeyCode>>,
+    pub mouse: Res<'w, ButtonInput<MouseButton>>,
+    pub gamepads: Query<'w, 's, &'static Gamepad>,
+}
+
+impl InputDevices<'_, '_> {
+    pub fn pressed(&self, binding: InputBinding) -> bool {
+        match binding {
+            InputBinding::Key(key) => self.keyboard.pressed(key),
+            InputBinding::Mouse(button) => self.mouse.pressed(button),
+            InputBinding::Gamepad(button) => self.gamepads.iter().any(|gamepad| gamepad.pressed(button)),
+        }
+    }
+
+    /// First key or button pressed this frame on any device
+    pub fn first_just_pressed(&self) -> Option<InputBinding> {
+        self.keyboard
+            .get_just_pressed()
+            .next()
+            .map(|key| InputBinding::Key(*key))
+            .or_else(|| self.mouse.get_just_pressed().next().map(|button| InputBinding::Mouse(*button)))
+            .or_else(|| {
+                self.gamepads
+                    .iter()
+                    .find_map(|gamepad| gamepad.get_just_pressed().next().map(|button| InputBinding::Gamepad(*button)))
+            })
+    }
+
+    /// Left stick of whichever gamepad is pushed furthest
+    pub fn left_stick(&self) -> Vec2 {
+        self.strongest_stick(Gamepad::left_stick)
+    }
+
+    /// Right stick of whichever gamepad is pushed furthest
+    pub fn right_stick(&self) -> Vec2 {
+        self.strongest_stick(Gamepad::right_stick)
+    }
+
+    fn strongest_stick(&self, stick: impl Fn(&Gamepad) -> Vec2) -> Vec2 {
+        self.gamepads
+            .iter()
+            .map(stick)
+            .max_by(|a, b| a.length_squared().total_cmp(&b.length_squared()))
+            .unwrap_or(Vec2::ZERO)
+    }
+}
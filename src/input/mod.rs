@@ -0,0 +1,11 @@
+mod actions;
+mod bindings;
+mod devices;
+mod replay;
+mod tick_input;
+
+pub use actions::*;
+pub use bindings::*;
+pub use devices::*;
+pub use replay::*;
+pub use tick_input::*;
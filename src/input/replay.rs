@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use crate::assets::Loadout;
+use crate::input::TickInput;
+use crate::resources::Difficulty;
+
+/// A recorded fight: the player's input for every simulation tick, run-length encoded.
+/// The simulation has no randomness, so replaying the inputs at the same tick rate, against the same encounter
+/// with the same loadout and difficulty, reproduces the fight exactly.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Replay {
+    /// Format version, replays of other versions are ignored
+    pub version: u32,
+    /// Simulation ticks per second the fight was recorded at
+    pub tick_rate: f64,
+    /// Index of the encounter fought
+    pub encounter: usize,
+    pub loadout: Loadout,
+    /// Replays recorded before difficulties were added were all fought on Normal
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    runs: Vec<ReplayRun>,
+}
+
+/// The same input repeated for consecutive ticks
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ReplayRun {
+    ticks: u32,
+    input: TickInput,
+}
+
+impl Replay {
+    pub const VERSION: u32 = 2;
+    /// Directory under the config directory where replays are saved
+    pub const DIRECTORY: &'static str = "replays";
+
+    pub fn new(tick_rate: f64, encounter: usize, loadout: Loadout, difficulty: Difficulty, inputs: &[TickInput]) -> Self {
+        let mut runs: Vec<ReplayRun> = Vec::new();
+        for input in inputs {
+            match runs.last_mut() {
+                Some(run) if run.input == *input => run.ticks += 1,
+                _ => runs.push(ReplayRun { ticks: 1, input: *input }),
+            }
+        }
+        Self {
+            version: Self::VERSION,
+            tick_rate,
+            encounter,
+            loadout,
+            difficulty,
+            runs,
+        }
+    }
+
+    /// File name for a replay recorded at `timestamp` seconds since the Unix epoch
+    pub fn file_name(timestamp: u64) -> String {
+        format!("{}/replay-{:010}.ron", Self::DIRECTORY, timestamp)
+    }
+
+    /// The input of every tick, in order
+    pub fn inputs(&self) -> Vec<TickInput> {
+        self.runs
+            .iter()
+            .flat_map(|run| std::iter::repeat_n(run.input, run.ticks as usize))
+            .collect()
+    }
+
+    pub fn tick_count(&self) -> usize {
+        self.runs.iter().map(|run| run.ticks as usize).sum()
+    }
+}
@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::TAU;
+use crate::input::PlayerAction;
+
+/// A request to change the selected weapon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WeaponRequest {
+    /// Select the weapon at this index of the registry
+    Select(usize),
+    Next,
+    Previous,
+}
+
+/// Everything the simulation reads from the player in one tick, quantized so it can be stored
+/// compactly in a replay and played back to exactly the same result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct TickInput {
+    /// Movement axis in steps of `1 / MOVE_STEPS`
+    pub move_axis: (i8, i8),
+    /// Aim angle in steps of `TAU / AIM_STEPS`, `None` when there is nothing to aim at
+    pub aim: Option<u16>,
+    /// Bit `i` is set when `PlayerAction::BUTTONS[i]` is held
+    pub buttons: u8,
+    pub weapon: Option<WeaponRequest>,
+}
+
+impl TickInput {
+    const MOVE_STEPS: f32 = i8::MAX as f32;
+    const AIM_STEPS: f32 = 65536.0;
+
+    pub fn new(move_axis: Vec2, aim_direction: Option<Vec2>, pressed: impl Fn(PlayerAction) -> bool, weapon: Option<WeaponRequest>) -> Self {
+        let quantize_axis = |value: f32| (value.clamp(-1.0, 1.0) * Self::MOVE_STEPS).round() as i8;
+        let quantize_angle = |direction: Vec2| {
+            (direction.to_angle().rem_euclid(TAU) / TAU * Self::AIM_STEPS).round() as u32 as u16
+        };
+        let buttons = PlayerAction::BUTTONS
+            .iter()
+            .enumerate()
+            .filter(|(_, action)| pressed(**action))
+            .fold(0, |bits, (index, _)| bits | 1 << index);
+
+        Self {
+            move_axis: (quantize_axis(move_axis.x), quantize_axis(move_axis.y)),
+            aim: aim_direction.map(quantize_angle),
+            buttons,
+            weapon,
+        }
+    }
+
+    pub fn move_axis(&self) -> Vec2 {
+        Vec2::new(self.move_axis.0 as f32, self.move_axis.1 as f32) / Self::MOVE_STEPS
+    }
+
+    /// Normalized aim direction
+    pub fn aim_direction(&self) -> Option<Vec2> {
+        self.aim.map(|steps| Vec2::from_angle(steps as f32 / Self::AIM_STEPS * TAU))
+    }
+
+    pub fn pressed(&self, action: PlayerAction) -> bool {
+        PlayerAction::BUTTONS
+            .iter()
+            .position(|button| *button == action)
+            .is_some_and(|index| self.buttons & 1 << index != 0)
+    }
+}
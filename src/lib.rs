@@ -0,0 +1,15 @@
+// Bevy systems routinely take many parameters and complex query types
+#![allow(clippy::type_complexity, clippy::too_many_arguments)]
+
+pub mod states;
+pub mod plugins;
+pub mod components;
+pub mod systems;
+pub mod resources;
+pub mod constants;
+pub mod events;
+pub mod assets;
+pub mod input;
+pub mod collision;
+pub mod persistence;
+pub mod headless;
@@ -1,16 +1,5 @@
 use bevy::prelude::*;
-use bevy::window::{WindowMode, MonitorSelection};
-
-mod states;
-mod plugins;
-mod components;
-mod systems;
-mod resources;
-mod constants;
-
-use states::AppState;
-use plugins::{MenuPlugin, GamePlugin, DebugPlugin, GameOverPlugin};
-use resources::PauseState;
+use gun_shield_elements::plugins::GameAppPlugin;
 
 fn main() {
     let mut app = App::new();
@@ -26,23 +15,11 @@ fn main() {
         ..default()
     };
     
+    // Window mode, resolution and vsync come from the player's settings once the profile is loaded
     #[cfg(not(target_arch = "wasm32"))]
-    let window_plugin = WindowPlugin {
-        primary_window: Some(Window {
-            mode: WindowMode::BorderlessFullscreen(MonitorSelection::Primary),
-            ..default()
-        }),
-        ..default()
-    };
+    let window_plugin = WindowPlugin::default();
     
     app.add_plugins(DefaultPlugins.set(window_plugin))
-        .init_state::<AppState>()
-        .init_resource::<PauseState>()
-        .add_plugins((
-            MenuPlugin,
-            GamePlugin,
-            DebugPlugin,
-            GameOverPlugin,
-        ))
+        .add_plugins(GameAppPlugin)
         .run();
-}
\ No newline at end of file
+}
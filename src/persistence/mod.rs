@@ -0,0 +1,7 @@
+mod profile;
+mod settings;
+mod storage;
+
+pub use profile::*;
+pub use settings::*;
+pub use storage::*;
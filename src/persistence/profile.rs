@@ -0,0 +1,194 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use crate::persistence::GameSettings;
+use crate::resources::{Difficulty, FightStats};
+
+/// Resource with everything kept about the player between runs: campaign progress, personal bests,
+/// lifetime statistics, the chosen difficulty and settings. Saved as `profile.ron` whenever it changes.
+#[derive(Resource, Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    /// Format version the profile was saved with
+    pub version: u32,
+    pub campaign: CampaignProgress,
+    /// Best results against each encounter, by encounter name and then difficulty
+    pub personal_bests: BTreeMap<String, BTreeMap<Difficulty, PersonalBest>>,
+    pub lifetime: LifetimeStats,
+    /// Difficulty last chosen on the main menu
+    pub difficulty: Difficulty,
+    /// Settings saved from the `GameSettings` resource
+    pub settings: GameSettings,
+    /// Set when the saved profile comes from a newer version of the game, which must not be overwritten
+    #[serde(skip)]
+    pub is_read_only: bool,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self {
+            version: Self::VERSION,
+            campaign: CampaignProgress::default(),
+            personal_bests: BTreeMap::new(),
+            lifetime: LifetimeStats::default(),
+            difficulty: Difficulty::default(),
+            settings: GameSettings::default(),
+            is_read_only: false,
+        }
+    }
+}
+
+/// Just the version of a saved profile, read before the rest to know how to parse it
+#[derive(Deserialize)]
+struct SavedVersion {
+    version: u32,
+}
+
+/// Profile as saved by version 1, which kept a single personal best per encounter
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct ProfileV1 {
+    campaign: CampaignProgress,
+    personal_bests: BTreeMap<String, PersonalBest>,
+    lifetime: LifetimeStats,
+    settings: GameSettings,
+}
+
+impl From<ProfileV1> for Profile {
+    /// Personal bests from before difficulties were added were all set on Normal
+    fn from(saved: ProfileV1) -> Self {
+        Self {
+            campaign: saved.campaign,
+            personal_bests: saved.personal_bests
+                .into_iter()
+                .map(|(encounter, best)| (encounter, BTreeMap::from([(Difficulty::Normal, best)])))
+                .collect(),
+            lifetime: saved.lifetime,
+            settings: saved.settings,
+            ..default()
+        }
+    }
+}
+
+impl Profile {
+    pub const FILE_NAME: &str = "profile.ron";
+    /// Current format version. Added fields are filled with defaults when loading older profiles;
+    /// changes that can't be read that way bump the version and add a migration to `from_ron`.
+    pub const VERSION: u32 = 2;
+
+    /// Parse a saved profile, migrating it from the version it was saved with and replacing unsupported settings
+    pub fn from_ron(contents: &str) -> Result<Self, ProfileError> {
+        let SavedVersion { version } = ron::from_str(contents)?;
+        let mut profile: Self = match version {
+            Self::VERSION => ron::from_str(contents)?,
+            1 => ron::from_str::<ProfileV1>(contents)?.into(),
+            newer if newer > Self::VERSION => return Err(ProfileError::Newer(newer)),
+            older => return Err(ProfileError::Unsupported(older)),
+        };
+        profile.settings.replace_unsupported();
+        Ok(profile)
+    }
+
+    /// Add a finished fight to the lifetime statistics
+    pub fn record_fight(&mut self, stats: &FightStats, won: bool) {
+        self.lifetime.add_fight(stats, won);
+    }
+
+    /// Record a win against `encounter` on `difficulty`, returning whether it set a personal best
+    pub fn record_victory(&mut self, encounter: &str, difficulty: Difficulty, stats: &FightStats) -> bool {
+        self.campaign.beaten.insert(encounter.to_string());
+        let bests = self.personal_bests.entry(encounter.to_string()).or_default();
+        match bests.get_mut(&difficulty) {
+            Some(best) => best.improve(stats),
+            None => {
+                bests.insert(difficulty, PersonalBest::from_fight(stats));
+                true
+            }
+        }
+    }
+
+    /// Best results against `encounter` on `difficulty`, if it was ever won on it
+    pub fn personal_best(&self, encounter: &str, difficulty: Difficulty) -> Option<&PersonalBest> {
+        self.personal_bests.get(encounter)?.get(&difficulty)
+    }
+}
+
+/// Errors produced while reading a saved profile
+#[derive(Debug, thiserror::Error)]
+pub enum ProfileError {
+    #[error("could not parse profile RON: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    #[error("profile version {0} was saved by a newer version of the game")]
+    Newer(u32),
+    #[error("profile version {0} is not supported")]
+    Unsupported(u32),
+}
+
+/// Names of the encounters the player has beaten, which unlock the following ones
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CampaignProgress {
+    pub beaten: BTreeSet<String>,
+}
+
+/// Best results of the winning fights against one encounter, each from whichever fight set it
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PersonalBest {
+    /// Seconds of the fastest win
+    pub fastest_time: f32,
+    pub least_damage_taken: f32,
+}
+
+impl PersonalBest {
+    fn from_fight(stats: &FightStats) -> Self {
+        Self {
+            fastest_time: stats.duration,
+            least_damage_taken: stats.damage_taken,
+        }
+    }
+
+    /// Keep the better of each result, returning whether any improved
+    fn improve(&mut self, stats: &FightStats) -> bool {
+        let previous = *self;
+        self.fastest_time = self.fastest_time.min(stats.duration);
+        self.least_damage_taken = self.least_damage_taken.min(stats.damage_taken);
+        *self != previous
+    }
+}
+
+/// Statistics summed over every fight played
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LifetimeStats {
+    pub fights: u32,
+    pub victories: u32,
+    pub deaths: u32,
+    /// Simulated seconds spent fighting
+    pub time_fighting: f64,
+    pub damage_dealt: f64,
+    pub damage_taken: f64,
+    pub shots_fired: u64,
+    pub shots_hit: u64,
+    pub shield_blocks: u64,
+    pub parries: u64,
+    pub dashes: u64,
+}
+
+impl LifetimeStats {
+    fn add_fight(&mut self, stats: &FightStats, won: bool) {
+        self.fights += 1;
+        if won {
+            self.victories += 1;
+        } else {
+            self.deaths += 1;
+        }
+        self.time_fighting += stats.duration as f64;
+        self.damage_dealt += stats.damage_dealt as f64;
+        self.damage_taken += stats.damage_taken as f64;
+        self.shots_fired += stats.shots_fired as u64;
+        self.shots_hit += stats.shots_hit as u64;
+        self.shield_blocks += stats.shield_blocks as u64;
+        self.parries += stats.parries as u64;
+        self.dashes += stats.dashes as u64;
+    }
+}
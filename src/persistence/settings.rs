@@ -0,0 +1,161 @@
+use bevy::prelude::*;
+use bevy::window::{MonitorSelection, PresentMode, VideoModeSelection, WindowMode};
+use serde::{Deserialize, Serialize};
+use crate::constants::ColorPalette;
+use crate::resources::SimulationSettings;
+
+/// Window resolutions offered on the settings screen, used in windowed mode
+pub const RESOLUTIONS: [(u32, u32); 4] = [(1280, 720), (1600, 900), (1920, 1080), (2560, 1440)];
+
+/// Resource with the player's settings, applied live when changed and stored in the profile
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameSettings {
+    /// Simulation ticks per second
+    pub tick_rate: f64,
+    pub window_mode: WindowModeSetting,
+    /// Window size in windowed mode
+    pub resolution: (u32, u32),
+    pub vsync: bool,
+    /// Volume of all audio, from 0 to 1
+    pub master_volume: f32,
+    /// Strength of the camera shake when the player is hit, from 0 (off) to 1
+    pub screen_shake: f32,
+    pub palette: ColorPalette,
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            tick_rate: SimulationSettings::DEFAULT_TICK_RATE,
+            window_mode: WindowModeSetting::BorderlessFullscreen,
+            resolution: RESOLUTIONS[0],
+            vsync: true,
+            master_volume: 1.0,
+            screen_shake: 1.0,
+            palette: ColorPalette::Standard,
+        }
+    }
+}
+
+impl GameSettings {
+    /// Replace settings the game can't run with, which only an edited profile holds, with their defaults
+    pub fn replace_unsupported(&mut self) {
+        if !SimulationSettings::supports_tick_rate(self.tick_rate) {
+            warn!("Unsupported tick rate {} in the profile, using {} Hz", self.tick_rate, SimulationSettings::DEFAULT_TICK_RATE);
+            self.tick_rate = SimulationSettings::DEFAULT_TICK_RATE;
+        }
+    }
+
+    /// Step a choice setting forwards (`step > 0`) or backwards through its options
+    pub fn cycle(&mut self, setting: CycleSetting, step: isize) {
+        fn next<T: Copy + PartialEq>(options: &[T], current: T, step: isize) -> T {
+            let index = options.iter().position(|option| *option == current).unwrap_or(0) as isize;
+            options[(index + step).rem_euclid(options.len() as isize) as usize]
+        }
+        match setting {
+            CycleSetting::WindowMode => self.window_mode = next(&WindowModeSetting::ALL, self.window_mode, step),
+            CycleSetting::Resolution => self.resolution = next(&RESOLUTIONS, self.resolution, step),
+            CycleSetting::Vsync => self.vsync = !self.vsync,
+            CycleSetting::Palette => self.palette = next(&ColorPalette::ALL, self.palette, step),
+        }
+    }
+
+    /// Current value of a choice setting, as shown on the settings screen
+    pub fn describe(&self, setting: CycleSetting) -> String {
+        match setting {
+            CycleSetting::WindowMode => self.window_mode.label().to_string(),
+            CycleSetting::Resolution => format!("{} x {}", self.resolution.0, self.resolution.1),
+            CycleSetting::Vsync => if self.vsync { "On" } else { "Off" }.to_string(),
+            CycleSetting::Palette => self.palette.label().to_string(),
+        }
+    }
+
+    pub fn slider(&self, setting: SliderSetting) -> f32 {
+        match setting {
+            SliderSetting::MasterVolume => self.master_volume,
+            SliderSetting::ScreenShake => self.screen_shake,
+        }
+    }
+
+    pub fn set_slider(&mut self, setting: SliderSetting, value: f32) {
+        let value = value.clamp(0.0, 1.0);
+        match setting {
+            SliderSetting::MasterVolume => self.master_volume = value,
+            SliderSetting::ScreenShake => self.screen_shake = value,
+        }
+    }
+
+    pub fn window_mode(&self) -> WindowMode {
+        match self.window_mode {
+            WindowModeSetting::Windowed => WindowMode::Windowed,
+            WindowModeSetting::BorderlessFullscreen => WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+            WindowModeSetting::Fullscreen => WindowMode::Fullscreen(MonitorSelection::Current, VideoModeSelection::Current),
+        }
+    }
+
+    pub fn present_mode(&self) -> PresentMode {
+        if self.vsync { PresentMode::AutoVsync } else { PresentMode::AutoNoVsync }
+    }
+}
+
+/// How the game window is shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindowModeSetting {
+    Windowed,
+    BorderlessFullscreen,
+    /// Exclusive fullscreen at the monitor's current video mode
+    Fullscreen,
+}
+
+impl WindowModeSetting {
+    pub const ALL: [WindowModeSetting; 3] = [Self::Windowed, Self::BorderlessFullscreen, Self::Fullscreen];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Windowed => "Windowed",
+            Self::BorderlessFullscreen => "Borderless",
+            Self::Fullscreen => "Fullscreen",
+        }
+    }
+}
+
+/// Settings chosen from a list of options on the settings screen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CycleSetting {
+    WindowMode,
+    Resolution,
+    Vsync,
+    Palette,
+}
+
+impl CycleSetting {
+    pub const ALL: [CycleSetting; 4] = [Self::WindowMode, Self::Resolution, Self::Vsync, Self::Palette];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::WindowMode => "Window Mode",
+            Self::Resolution => "Resolution",
+            Self::Vsync => "VSync",
+            Self::Palette => "Color Palette",
+        }
+    }
+}
+
+/// Settings set with a slider on the settings screen, all ranging from 0 to 1
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliderSetting {
+    MasterVolume,
+    ScreenShake,
+}
+
+impl SliderSetting {
+    pub const ALL: [SliderSetting; 2] = [Self::MasterVolume, Self::ScreenShake];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::MasterVolume => "Master Volume",
+            Self::ScreenShake => "Screen Shake",
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use bevy::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Name of the per-user directory, and prefix of the browser storage keys
+const APP_NAME: &str = "gun_shield_elements";
+
+/// Where player files are kept
+#[derive(Debug, Clone, PartialEq)]
+pub enum StorageLocation {
+    /// Nothing is read or written, as in headless apps
+    Disabled,
+    Directory(PathBuf),
+    /// The browser's `localStorage`, one key per file
+    LocalStorage,
+}
+
+/// Resource with the location player files are read from and written to.
+/// Writes replace files atomically and keep the previous contents as a `.bak` backup,
+/// which loading falls back to when a file is missing or unreadable.
+#[derive(Resource, Debug, Clone)]
+pub struct ConfigStorage {
+    pub location: StorageLocation,
+}
+
+impl Default for ConfigStorage {
+    fn default() -> Self {
+        let location = if cfg!(target_arch = "wasm32") {
+            StorageLocation::LocalStorage
+        } else {
+            StorageLocation::Directory(platform_data_directory())
+        };
+        Self { location }
+    }
+}
+
+impl ConfigStorage {
+    /// Storage that never touches the filesystem
+    pub fn disabled() -> Self {
+        Self { location: StorageLocation::Disabled }
+    }
+
+    /// Directory files are written to, `None` when they are not kept on the filesystem
+    pub fn directory(&self) -> Option<&Path> {
+        match &self.location {
+            StorageLocation::Directory(directory) => Some(directory),
+            _ => None,
+        }
+    }
+
+    /// Read a RON config file, `None` if persistence is disabled or the file is missing or invalid
+    pub fn load<T: DeserializeOwned>(&self, file_name: &str) -> Option<T> {
+        match self.load_with(file_name, |contents| ron::from_str::<T>(contents))? {
+            Ok(value) => Some(value),
+            Err(error) => {
+                warn!("Ignoring invalid config file {}: {}", file_name, error);
+                None
+            }
+        }
+    }
+
+    /// Read a file with `parse`, falling back to its backup when the file is missing or fails to parse.
+    /// `None` if neither exists, otherwise the error of the file itself when both fail.
+    pub fn load_with<T, E>(&self, file_name: &str, parse: impl Fn(&str) -> Result<T, E>) -> Option<Result<T, E>> {
+        let backup = || self.read(&backup_name(file_name)).map(|contents| parse(&contents));
+        let Some(contents) = self.read(file_name) else {
+            return backup();
+        };
+        match parse(&contents) {
+            Ok(value) => Some(Ok(value)),
+            Err(error) => match backup() {
+                Some(Ok(value)) => {
+                    warn!("Config file {} is unreadable, using its backup", file_name);
+                    Some(Ok(value))
+                }
+                _ => Some(Err(error)),
+            },
+        }
+    }
+
+    /// Write a RON config file, creating the config directory if needed
+    pub fn save<T: Serialize>(&self, file_name: &str, value: &T) {
+        self.write(file_name, ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default()));
+    }
+
+    /// Write a RON file on a single line, for large files that are not meant to be edited by hand
+    pub fn save_compact<T: Serialize>(&self, file_name: &str, value: &T) {
+        self.write(file_name, ron::to_string(value));
+    }
+
+    /// Keep a copy of an unreadable file under `<file_name>.corrupt` so saving over it loses nothing
+    pub fn set_aside(&self, file_name: &str) {
+        if let Some(contents) = self.read(file_name) {
+            let corrupt_name = format!("{}.corrupt", file_name);
+            match self.write_contents(&corrupt_name, &contents) {
+                Ok(()) => warn!("Kept a copy of unreadable file {} as {}", file_name, corrupt_name),
+                Err(error) => warn!("Failed to keep a copy of unreadable file {}: {}", file_name, error),
+            }
+        }
+    }
+
+    /// Name of the last file in `subdirectory` in alphabetical order, relative to the config directory
+    pub fn latest_file(&self, subdirectory: &str) -> Option<String> {
+        let entries = std::fs::read_dir(self.directory()?.join(subdirectory)).ok()?;
+        entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter(|name| name.ends_with(".ron"))
+            .max()
+            .map(|name| format!("{}/{}", subdirectory, name))
+    }
+
+    fn write(&self, file_name: &str, contents: Result<String, ron::Error>) {
+        if self.location == StorageLocation::Disabled {
+            return;
+        }
+        let result = contents
+            .map_err(|error| error.to_string())
+            .and_then(|contents| self.write_contents(file_name, &contents));
+        match result {
+            Ok(()) => info!("Saved config file {}", file_name),
+            Err(error) => warn!("Failed to save config file {}: {}", file_name, error),
+        }
+    }
+
+    fn read(&self, file_name: &str) -> Option<String> {
+        match &self.location {
+            StorageLocation::Disabled => None,
+            StorageLocation::Directory(directory) => std::fs::read_to_string(directory.join(file_name)).ok(),
+            StorageLocation::LocalStorage => local_storage::read(file_name),
+        }
+    }
+
+    /// Replace a file, keeping its previous contents as the backup
+    fn write_contents(&self, file_name: &str, contents: &str) -> Result<(), String> {
+        match &self.location {
+            StorageLocation::Disabled => Ok(()),
+            StorageLocation::Directory(directory) => {
+                write_file_atomically(&directory.join(file_name), contents).map_err(|error| error.to_string())
+            }
+            StorageLocation::LocalStorage => {
+                if let Some(previous) = local_storage::read(file_name) {
+                    local_storage::write(&backup_name(file_name), &previous)?;
+                }
+                local_storage::write(file_name, contents)
+            }
+        }
+    }
+}
+
+fn backup_name(file_name: &str) -> String {
+    format!("{}.bak", file_name)
+}
+
+/// Write to a temporary file flushed to disk, then swap it in, so an interrupted write never leaves a truncated file
+fn write_file_atomically(path: &Path, contents: &str) -> std::io::Result<()> {
+    use std::io::Write;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file_name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+    let temporary = path.with_file_name(format!("{}.tmp", file_name));
+    let mut file = std::fs::File::create(&temporary)?;
+    file.write_all(contents.as_bytes())?;
+    file.sync_all()?;
+
+    if path.exists() {
+        std::fs::rename(path, path.with_file_name(backup_name(file_name)))?;
+    }
+    std::fs::rename(&temporary, path)
+}
+
+/// Per-user data directory of the platform, or `config` in the working directory when it cannot be found
+fn platform_data_directory() -> PathBuf {
+    let home = || std::env::var_os("HOME").map(PathBuf::from);
+    let base = if cfg!(target_os = "windows") {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else if cfg!(target_os = "macos") {
+        home().map(|home| home.join("Library/Application Support"))
+    } else {
+        std::env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .filter(|directory| directory.is_absolute())
+            .or_else(|| home().map(|home| home.join(".local/share")))
+    };
+    base.map_or_else(|| PathBuf::from("config"), |base| base.join(APP_NAME))
+}
+
+#[cfg(target_arch = "wasm32")]
+mod local_storage {
+    fn storage() -> Result<web_sys::Storage, String> {
+        web_sys::window()
+            .and_then(|window| window.local_storage().ok().flatten())
+            .ok_or_else(|| "localStorage is not available".to_string())
+    }
+
+    fn key(file_name: &str) -> String {
+        format!("{}/{}", super::APP_NAME, file_name)
+    }
+
+    pub fn read(file_name: &str) -> Option<String> {
+        storage().ok()?.get_item(&key(file_name)).ok().flatten()
+    }
+
+    pub fn write(file_name: &str, contents: &str) -> Result<(), String> {
+        storage()?
+            .set_item(&key(file_name), contents)
+            .map_err(|error| format!("{:?}", error))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod local_storage {
+    pub fn read(_file_name: &str) -> Option<String> {
+        None
+    }
+
+    pub fn write(_file_name: &str, _contents: &str) -> Result<(), String> {
+        Err("localStorage is only available on the web".to_string())
+    }
+}
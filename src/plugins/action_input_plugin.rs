@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+use bevy::input::InputSystem;
+use crate::input::{ActionState, FixedActionState, InputBindings};
+use crate::persistence::ConfigStorage;
+use crate::resources::{CursorWorldPosition, PendingRebind, ReplayPlayback};
+use crate::systems::{load_input_bindings, update_action_state, update_cursor_world_position, buffer_fixed_actions, begin_fixed_action_tick};
+
+/// Input bindings, and the per-frame `ActionState` and per-tick `FixedActionState` that game systems read instead of devices
+pub struct ActionInputPlugin;
+
+impl Plugin for ActionInputPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ConfigStorage>()
+            .init_resource::<InputBindings>()
+            .init_resource::<ActionState>()
+            .init_resource::<FixedActionState>()
+            .init_resource::<CursorWorldPosition>()
+            .init_resource::<PendingRebind>()
+            .add_systems(Startup, load_input_bindings)
+            .add_systems(
+                PreUpdate,
+                (update_cursor_world_position, update_action_state, buffer_fixed_actions).chain().after(InputSystem),
+            )
+            .add_systems(FixedPreUpdate, begin_fixed_action_tick.run_if(not(resource_exists::<ReplayPlayback>)));
+    }
+}
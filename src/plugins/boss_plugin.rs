@@ -0,0 +1,66 @@
+use bevy::prelude::*;
+use crate::states::in_gameplay_state;
+use crate::plugins::DamageSet;
+use crate::assets::{BossDefinition, BossDefinitionLoader};
+use crate::events::BossPhaseChanged;
+use crate::systems::{
+    spawn_pending_boss,
+    mine_boss_ai, update_boss_phase, boss_dash_movement, update_dash_telegraphs, boss_rotation_animation, boss_player_collision, boss_collision_damage, stagger_boss_on_parry,
+    boss_lay_mines, regrow_mine_spikes, arm_mines, mine_proximity_trigger, update_explosion_flashes,
+    boss_fire_bullets,
+    spawn_boss_health_bar, update_boss_health_bar, update_boss_health_bar_color, update_boss_phase_markers, cleanup_boss_health_bar_on_boss_death,
+};
+
+/// Boss AI, collision and boss health bar systems shared by the Game and Debug states.
+/// AI, skills and collisions are simulated in `FixedUpdate`, telegraphs, flashes and the health bar update every frame.
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_asset::<BossDefinition>()
+            .init_asset_loader::<BossDefinitionLoader>()
+            .add_event::<BossPhaseChanged>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    // Spawned by the simulation so the boss appears on the same tick in every run
+                    spawn_pending_boss.before(mine_boss_ai),
+                    // Boss systems
+                    update_boss_phase.after(DamageSet::Resolve),
+                    // The AI starts dashes, movement moves the boss along them and spins it
+                    (mine_boss_ai, boss_dash_movement, boss_rotation_animation).chain(),
+                    boss_player_collision.in_set(DamageSet::Detect),
+                    boss_collision_damage.in_set(DamageSet::Detect),
+                    stagger_boss_on_parry.after(DamageSet::Resolve),
+                ).run_if(in_gameplay_state),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    // Mine and bullet skill systems
+                    // Lays from where the boss and its spikes end the tick
+                    boss_lay_mines.after(boss_rotation_animation),
+                    regrow_mine_spikes,
+                    arm_mines,
+                    mine_proximity_trigger.after(arm_mines).in_set(DamageSet::Detect),
+                    // Bullet pattern skills
+                    boss_fire_bullets.after(mine_boss_ai),
+                ).run_if(in_gameplay_state),
+            )
+            .add_systems(
+                Update,
+                (
+                    // Visual feedback
+                    update_dash_telegraphs,
+                    update_explosion_flashes,
+                    // Boss health bar systems
+                    spawn_boss_health_bar, // Runs in Update to ensure boss is spawned first
+                    update_boss_health_bar,
+                    update_boss_health_bar_color,
+                    update_boss_phase_markers,
+                    cleanup_boss_health_bar_on_boss_death,
+                ).run_if(in_gameplay_state),
+            );
+    }
+}
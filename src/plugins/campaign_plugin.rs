@@ -0,0 +1,166 @@
+use bevy::prelude::*;
+use crate::states::AppState;
+use crate::assets::{EncounterSequence, EncounterSequenceLoader};
+use crate::components::{LevelSelectUI, LevelSelectButton};
+use crate::persistence::Profile;
+use crate::resources::{Difficulty, EncounterAssets, SelectedEncounter};
+use crate::systems::{format_fight_time, load_encounter_sequence, cleanup_ui, create_button_with_component, button_hover_system};
+use crate::constants::AppColors;
+
+/// Encounter sequence, campaign progress and difficulty, and the level select screen listing the encounters the player has reached
+pub struct CampaignPlugin;
+
+impl Plugin for CampaignPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_asset::<EncounterSequence>()
+            .init_asset_loader::<EncounterSequenceLoader>()
+            .init_resource::<SelectedEncounter>()
+            .init_resource::<Difficulty>()
+            .add_systems(Startup, load_encounter_sequence)
+            .add_systems(OnEnter(AppState::LevelSelect), setup_level_select_screen)
+            .add_systems(
+                Update,
+                (
+                    handle_level_select_buttons,
+                    button_hover_system,
+                ).run_if(in_state(AppState::LevelSelect)),
+            )
+            .add_systems(OnExit(AppState::LevelSelect), cleanup_ui::<LevelSelectUI>);
+    }
+}
+
+/// System to setup the level select screen, with a button per unlocked encounter
+fn setup_level_select_screen(
+    mut commands: Commands,
+    encounter_assets: Res<EncounterAssets>,
+    sequences: Res<Assets<EncounterSequence>>,
+    profile: Res<Profile>,
+    difficulty: Res<Difficulty>,
+) {
+    let sequence = sequences.get(&encounter_assets.sequence);
+
+    // Root UI container
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(AppColors::BACKGROUND),
+            LevelSelectUI,
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new("LEVEL SELECT"),
+                TextFont {
+                    font_size: 60.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            // Difficulty the fights start on and personal bests are shown for
+            parent.spawn((
+                Text::new(format!("Difficulty: {}", difficulty.label())),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(30.0)),
+                    ..default()
+                },
+            ));
+
+            // Encounter buttons container
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    let Some(sequence) = sequence else {
+                        parent.spawn((
+                            Text::new("Loading encounters..."),
+                            TextFont {
+                                font_size: 24.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                        ));
+                        return;
+                    };
+
+                    for (index, encounter) in sequence.encounters.iter().enumerate() {
+                        if sequence.is_unlocked(index, &profile.campaign.beaten) {
+                            let best = match profile.personal_best(&encounter.name, *difficulty) {
+                                Some(best) => format!("  (best {})", format_fight_time(best.fastest_time)),
+                                None => String::new(),
+                            };
+                            let label = format!("{}. {}{}", index + 1, encounter.name, best);
+                            create_button_with_component(parent, &label, 24.0, LevelSelectButton::Encounter(index));
+                        } else {
+                            // Locked encounters are listed without revealing their name
+                            parent
+                                .spawn((
+                                    Node {
+                                        width: Val::Px(350.0),
+                                        height: Val::Px(80.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        margin: UiRect::all(Val::Px(15.0)),
+                                        ..default()
+                                    },
+                                    BackgroundColor(Color::srgb(0.12, 0.12, 0.12)),
+                                    BorderRadius::all(Val::Px(5.0)),
+                                ))
+                                .with_children(|parent| {
+                                    parent.spawn((
+                                        Text::new(format!("{}. Locked", index + 1)),
+                                        TextFont {
+                                            font_size: 24.0,
+                                            ..default()
+                                        },
+                                        TextColor(Color::srgb(0.4, 0.4, 0.4)),
+                                    ));
+                                });
+                        }
+                    }
+
+                    // Back button
+                    create_button_with_component(parent, "Back", 32.0, LevelSelectButton::Back);
+                });
+        });
+}
+
+/// System to handle level select button clicks
+fn handle_level_select_buttons(
+    mut interaction_query: Query<
+        (&Interaction, &LevelSelectButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut selected: ResMut<SelectedEncounter>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, button) in &mut interaction_query {
+        if *interaction == Interaction::Pressed {
+            match button {
+                LevelSelectButton::Encounter(index) => {
+                    selected.index = *index;
+                    next_state.set(AppState::Game);
+                }
+                LevelSelectButton::Back => {
+                    next_state.set(AppState::MainMenu);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+use crate::states::{AppState, in_gameplay_state};
+use crate::plugins::DamageSet;
+use crate::collision::{ActiveCollisions, CollisionMatrix, SpatialIndex};
+use crate::events::{CollisionEnded, CollisionOngoing, CollisionStarted};
+use crate::systems::{rebuild_spatial_index, detect_collisions, block_at_obstacles, clear_collisions, enforce_boundaries, projectile_movement_system, boss_dash_movement};
+
+/// Collision detection for the gameplay systems: the `SpatialIndex` is rebuilt every tick after the player,
+/// bosses and projectiles have moved, then colliders whose layers collide that touched anywhere along their
+/// path during the tick are reported as collision events before hits are detected. The player and bosses are
+/// then stopped at the obstacles they ran into.
+pub struct CollisionPlugin;
+
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<SpatialIndex>()
+            .init_resource::<CollisionMatrix>()
+            .init_resource::<ActiveCollisions>()
+            .add_event::<CollisionStarted>()
+            .add_event::<CollisionOngoing>()
+            .add_event::<CollisionEnded>()
+            .add_systems(
+                FixedUpdate,
+                (rebuild_spatial_index, detect_collisions, block_at_obstacles)
+                    .chain()
+                    .after(enforce_boundaries)
+                    .after(projectile_movement_system)
+                    .after(boss_dash_movement)
+                    .before(DamageSet::Detect)
+                    .run_if(in_gameplay_state),
+            )
+            .add_systems(OnExit(AppState::Game), clear_collisions)
+            .add_systems(OnExit(AppState::Debug), clear_collisions);
+    }
+}
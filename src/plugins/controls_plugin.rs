@@ -0,0 +1,204 @@
+use bevy::prelude::*;
+use crate::states::AppState;
+use crate::components::{ControlsMenuUI, ControlsButton, BindingLabel};
+use crate::input::{InputBinding, InputBindings, InputDevices, InputSlot};
+use crate::persistence::ConfigStorage;
+use crate::resources::PendingRebind;
+use crate::systems::{cleanup_ui, create_button_with_component, button_hover_system};
+use crate::constants::AppColors;
+
+/// Controls screen for viewing and rebinding the input bindings
+pub struct ControlsPlugin;
+
+impl Plugin for ControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(OnEnter(AppState::Controls), setup_controls_screen)
+            .add_systems(
+                Update,
+                (
+                    // Capture before handling clicks so the click that starts a rebind isn't captured
+                    capture_rebind,
+                    handle_controls_buttons,
+                    update_binding_labels
+                        .run_if(resource_changed::<InputBindings>.or(resource_changed::<PendingRebind>)),
+                    button_hover_system,
+                ).chain().run_if(in_state(AppState::Controls)),
+            )
+            .add_systems(OnExit(AppState::Controls), (cleanup_ui::<ControlsMenuUI>, cancel_rebind));
+    }
+}
+
+/// System to setup the controls screen UI
+fn setup_controls_screen(mut commands: Commands, bindings: Res<InputBindings>) {
+    // Root UI container
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(AppColors::BACKGROUND),
+            ControlsMenuUI,
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new("Controls"),
+                TextFont {
+                    font_size: 60.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            parent.spawn((
+                Text::new("Click an action, then press a key, mouse button or gamepad button to bind it"),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                Node {
+                    margin: UiRect::bottom(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+
+            // One row per bindable slot, clicking the row starts rebinding it
+            for slot in InputSlot::ALL {
+                parent
+                    .spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(600.0),
+                            height: Val::Px(40.0),
+                            justify_content: JustifyContent::SpaceBetween,
+                            align_items: AlignItems::Center,
+                            padding: UiRect::horizontal(Val::Px(20.0)),
+                            margin: UiRect::all(Val::Px(4.0)),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+                        BorderRadius::all(Val::Px(5.0)),
+                        ControlsButton::Rebind(slot),
+                    ))
+                    .with_children(|parent| {
+                        parent.spawn((
+                            Text::new(slot.label()),
+                            TextFont {
+                                font_size: 22.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                        ));
+                        parent.spawn((
+                            Text::new(bindings.describe(slot)),
+                            TextFont {
+                                font_size: 22.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.8, 0.8, 0.4)),
+                            BindingLabel(slot),
+                        ));
+                    });
+            }
+
+            // Bottom buttons
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    create_button_with_component(parent, "Reset to Defaults", 28.0, ControlsButton::ResetDefaults);
+                    create_button_with_component(parent, "Back", 28.0, ControlsButton::Back);
+                });
+        });
+}
+
+/// System to handle controls screen button clicks
+fn handle_controls_buttons(
+    mut interaction_query: Query<
+        (&Interaction, &ControlsButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut pending: ResMut<PendingRebind>,
+    mut bindings: ResMut<InputBindings>,
+    storage: Res<ConfigStorage>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, button) in &mut interaction_query {
+        if *interaction == Interaction::Pressed {
+            match button {
+                ControlsButton::Rebind(slot) => {
+                    pending.slot = Some(*slot);
+                }
+                ControlsButton::ResetDefaults => {
+                    pending.slot = None;
+                    *bindings = InputBindings::default();
+                    storage.save(InputBindings::FILE_NAME, &*bindings);
+                    info!("Input bindings reset to defaults");
+                }
+                ControlsButton::Back => {
+                    next_state.set(AppState::MainMenu);
+                }
+            }
+        }
+    }
+}
+
+/// System to bind the next key, mouse button or gamepad button press to the slot waiting for a rebind.
+/// Escape cancels, unless it is the pause slot being rebound.
+fn capture_rebind(
+    mut pending: ResMut<PendingRebind>,
+    mut bindings: ResMut<InputBindings>,
+    storage: Res<ConfigStorage>,
+    devices: InputDevices,
+) {
+    let Some(slot) = pending.slot else {
+        return;
+    };
+
+    let Some(binding) = devices.first_just_pressed() else {
+        return;
+    };
+
+    pending.slot = None;
+    if binding == InputBinding::Key(KeyCode::Escape) && slot != InputSlot::Pause {
+        return;
+    }
+
+    bindings.rebind(slot, binding);
+    storage.save(InputBindings::FILE_NAME, &*bindings);
+    info!("Bound {} to {}", slot.label(), binding.label());
+}
+
+/// System to refresh the binding labels after a rebind
+fn update_binding_labels(
+    mut label_query: Query<(&BindingLabel, &mut Text)>,
+    bindings: Res<InputBindings>,
+    pending: Res<PendingRebind>,
+) {
+    for (label, mut text) in &mut label_query {
+        text.0 = if pending.slot == Some(label.0) {
+            "Press a key...".to_string()
+        } else {
+            bindings.describe(label.0)
+        };
+    }
+}
+
+/// System to drop an unfinished rebind when leaving the controls screen
+fn cancel_rebind(mut pending: ResMut<PendingRebind>) {
+    pending.slot = None;
+}
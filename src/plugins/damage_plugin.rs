@@ -0,0 +1,32 @@
+use bevy::prelude::*;
+use crate::states::in_gameplay_state;
+use crate::events::{DamageEvent, ParryEvent, BossDefeated};
+use crate::systems::{resolve_damage_events, update_burning};
+
+/// System sets ordering hit detection before damage resolution
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum DamageSet {
+    /// Systems that detect hits and emit `DamageEvent`s
+    Detect,
+    /// The single system that applies `DamageEvent`s
+    Resolve,
+}
+
+pub struct DamagePlugin;
+
+impl Plugin for DamagePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<DamageEvent>()
+            .add_event::<ParryEvent>()
+            .add_event::<BossDefeated>()
+            .configure_sets(FixedUpdate, (DamageSet::Detect, DamageSet::Resolve).chain())
+            .add_systems(
+                FixedUpdate,
+                (
+                    update_burning.in_set(DamageSet::Detect),
+                    resolve_damage_events.in_set(DamageSet::Resolve),
+                ).run_if(in_gameplay_state),
+            );
+    }
+}
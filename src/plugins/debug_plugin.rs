@@ -1,26 +1,30 @@
 use bevy::prelude::*;
 use crate::states::AppState;
-use crate::components::{DebugUI, PauseOverlayUI, Player, MineBoss, BossSkills, HealthBarUI, EnergyBarUI};
+use crate::plugins::DamageSet;
+use crate::components::{DebugUI, PauseOverlayUI, Player, Weapon, MineBoss, BossSkills, BossPhase, AttackStage, HealthBarUI, EnergyBarUI, AmmoDisplayUI};
+use crate::assets::WeaponRegistry;
+use crate::resources::WeaponAssets;
 use crate::systems::{
     cleanup_ui, handle_pause_input, update_pause_timer,
     spawn_pause_overlay, despawn_pause_overlay, handle_pause_buttons, button_hover_system,
-    reset_pause_state, spawn_player, player_movement, player_face_mouse, camera_follow_player, cleanup_player, cleanup_debug_entities,
-    handle_shield_input, animate_shield, update_shield_mesh, spawn_mine_boss, cleanup_boss_entities,
-    mine_boss_ai, boss_dash_movement, boss_rotation_animation, boss_player_collision, boss_collision_damage,
+    reset_pause_state, spawn_player, cleanup_player, cleanup_debug_entities,
+    begin_encounter, cleanup_boss_entities,
     spawn_health_bar, update_health_bar, update_health_bar_color, check_player_death,
-    spawn_energy_bar, update_energy_bar, update_energy_bar_color, manage_player_invulnerability,
-    spawn_boundary_visuals, enforce_boundaries, cleanup_boundary_visuals,
+    spawn_energy_bar, update_energy_bar, update_energy_bar_color,
+    spawn_ammo_display, update_ammo_display,
+    spawn_boundary_visuals, cleanup_boundary_visuals, spawn_arena_obstacles, cleanup_obstacles,
     spawn_edge_warnings, update_edge_warnings, cleanup_edge_warnings,
-    weapon_firing_system, projectile_movement_system, projectile_lifetime_system, projectile_boss_collision_system, cleanup_projectiles,
-    spawn_boss_health_bar, update_boss_health_bar, update_boss_health_bar_color, cleanup_boss_health_bar_on_boss_death, cleanup_boss_health_bar
+    cleanup_projectiles, cleanup_mines, cleanup_boss_health_bar
 };
 
 pub struct DebugPlugin;
 
 impl Plugin for DebugPlugin {
     fn build(&self, app: &mut App) {
+        // Player controls, weapons and boss systems are shared with Game mode
+        // through PlayerControlsPlugin, WeaponPlugin and BossPlugin
         app
-            .add_systems(OnEnter(AppState::Debug), (setup_debug_screen, spawn_boundary_visuals, spawn_edge_warnings, spawn_player, spawn_mine_boss, spawn_health_bar, spawn_energy_bar))
+            .add_systems(OnEnter(AppState::Debug), (begin_encounter, (setup_debug_screen, spawn_boundary_visuals, spawn_arena_obstacles, spawn_edge_warnings, spawn_player, spawn_health_bar, spawn_energy_bar, spawn_ammo_display)).chain())
             .add_systems(
                 Update,
                 (
@@ -33,70 +37,35 @@ impl Plugin for DebugPlugin {
                     button_hover_system,
                 ).run_if(in_state(AppState::Debug)),
             )
-            .add_systems(
-                Update,
-                (
-                    // Player systems - use chain to ensure proper ordering
-                    player_movement,
-                    manage_player_invulnerability,
-                    player_face_mouse,
-                    handle_shield_input,
-                    animate_shield,
-                    update_shield_mesh.after(animate_shield),
-                    enforce_boundaries, // Apply boundary constraints after movement
-                    camera_follow_player,
-                ).chain().run_if(in_state(AppState::Debug)),
-            )
-            .add_systems(
-                Update,
-                (
-                    // Weapon systems
-                    weapon_firing_system,
-                    projectile_movement_system,
-                    projectile_lifetime_system,
-                    projectile_boss_collision_system,
-                ).run_if(in_state(AppState::Debug)),
-            )
-            .add_systems(
-                Update,
-                (
-                    // Boss systems
-                    mine_boss_ai,
-                    boss_dash_movement,
-                    boss_rotation_animation,
-                    boss_player_collision,
-                    boss_collision_damage,
-                ).run_if(in_state(AppState::Debug)),
-            )
             .add_systems(
                 Update,
                 (
                     // Health and game state systems
-                    spawn_boss_health_bar, // Moved to Update to ensure boss is spawned first
                     update_health_bar,
                     update_health_bar_color,
                     update_energy_bar,
                     update_energy_bar_color,
-                    update_boss_health_bar,
-                    update_boss_health_bar_color,
-                    cleanup_boss_health_bar_on_boss_death,
+                    update_ammo_display,
                     update_edge_warnings, // Update edge warning intensity
-                    check_player_death,
                     update_debug_info,
                 ).run_if(in_state(AppState::Debug)),
             )
+            .add_systems(FixedUpdate, check_player_death.after(DamageSet::Resolve).run_if(in_state(AppState::Debug)))
             .add_systems(OnExit(AppState::Debug), (
                 cleanup_ui::<DebugUI>,
                 cleanup_ui::<PauseOverlayUI>,
                 cleanup_ui::<HealthBarUI>,
                 cleanup_ui::<EnergyBarUI>,
+                cleanup_ui::<AmmoDisplayUI>,
                 cleanup_boss_health_bar,
                 cleanup_player,
                 cleanup_boss_entities,
                 cleanup_debug_entities,
                 cleanup_boundary_visuals,
+                cleanup_obstacles,
                 cleanup_edge_warnings,
                 cleanup_projectiles,
+                cleanup_mines,
                 reset_pause_state,
             ));
     }
@@ -122,7 +91,7 @@ fn setup_debug_screen(mut commands: Commands) {
 
     // Debug info display
     commands.spawn((
-        Text::new("Debug Mode\nUse WASD to move\nMove mouse to aim\nRight click to activate shield\nSpace to dash in WASD direction (first 30% has i-frames)\nESC to pause\nPlayer: White circle (rotates to face mouse)\nDirection indicator: Small white circle (hidden when shield active)\nShield: White arc that grows from indicator\nWeapon: Automatically fires white projectiles towards mouse\nMine Boss: Orange circle with 8 brown squares (dashes at player)"),
+        Text::new("Debug Mode\nUse WASD to move\nMove mouse to aim\nRight click to activate shield\nSpace to dash in WASD direction (first 30% has i-frames)\nESC to pause\nGamepad: left stick move, right stick aim, LT shield, RT fire, A dash\nPlayer: White circle (rotates to face mouse)\nDirection indicator: Small white circle (hidden when shield active)\nShield: White arc that grows from indicator\nLeft click to fire (B: auto/semi, R: reload, 1-9 or scroll to switch)\nMine Boss: Orange circle with 8 brown squares (dashes at player)"),
         TextFont {
             font_size: 20.0,
             ..default()
@@ -141,10 +110,14 @@ fn setup_debug_screen(mut commands: Commands) {
 }
 
 /// System to update debug information
+#[allow(clippy::collapsible_if)]
 fn update_debug_info(
     player_query: Query<(&Transform, &crate::components::Energy, &crate::components::PlayerDash, &crate::components::Invulnerability), With<Player>>,
     shield_query: Query<&crate::components::Shield>,
-    boss_query: Query<(&Transform, &BossSkills), With<MineBoss>>,
+    boss_query: Query<(&Transform, &BossSkills, Option<&BossPhase>), With<MineBoss>>,
+    weapon_query: Query<&Weapon>,
+    weapon_assets: Res<WeaponAssets>,
+    registries: Res<Assets<WeaponRegistry>>,
     mut debug_text_query: Query<&mut Text, With<DebugInfoText>>,
 ) {
     if let Ok((player_transform, player_energy, player_dash, player_invulnerability)) = player_query.single() {
@@ -167,12 +140,27 @@ fn update_debug_info(
                 if player_dash.is_dashing { "Active" } else { "Ready" },
                 if player_invulnerability.is_active() { " (I-FRAMES)" } else { "" });
             
-            let boss_info = if let Ok((boss_transform, boss_skills)) = boss_query.single() {
+            let weapon_info = match (weapon_query.single(), registries.get(&weapon_assets.registry)) {
+                (Ok(weapon), Some(registry)) => registry.weapons.get(weapon.current).map_or_else(
+                    || "Weapon: Unknown".to_string(),
+                    |definition| format!("Weapon: {} [{}/{}]", definition.name, weapon.current + 1, registry.weapons.len()),
+                ),
+                _ => "Weapon: Loading".to_string(),
+            };
+            
+            let boss_info = if let Ok((boss_transform, boss_skills, boss_phase)) = boss_query.single() {
                 let boss_pos = boss_transform.translation;
                 let distance_to_boss = pos.distance(boss_pos);
-                format!("Boss Position: ({:.1}, {:.1})\nDistance to Boss: {:.1}\nBoss Status: {}\nDash Cooldown: {:.1}s",
+                format!("Boss Position: ({:.1}, {:.1})\nDistance to Boss: {:.1}\nBoss Status: {}\nBoss Phase: {}\nDash Cooldown: {:.1}s",
                     boss_pos.x, boss_pos.y, distance_to_boss,
-                    if boss_skills.is_dashing { "Dashing" } else { "Idle" },
+                    match boss_skills.dash_attack.stage {
+                        AttackStage::Ready => "Idle",
+                        AttackStage::Windup => "Winding up",
+                        AttackStage::Active => "Dashing",
+                        AttackStage::Recovery => "Recovering",
+                        AttackStage::Staggered => "Staggered",
+                    },
+                    boss_phase.map_or(1, |phase| phase.current + 1),
                     boss_skills.dash_cooldown.remaining_secs()
                 )
             } else {
@@ -180,8 +168,8 @@ fn update_debug_info(
             };
             
             **text = format!(
-                "Debug Mode\nUse WASD to move\nMove mouse to aim\nRight click to activate shield\nSpace to dash in WASD direction (first 30% has i-frames)\nESC to pause\nWeapon: Automatically fires white projectiles towards mouse\n{}\n{}\n{}\n{}",
-                shield_info, energy_info, dash_info, boss_info
+                "Debug Mode\nUse WASD to move\nMove mouse to aim\nRight click to activate shield\nSpace to dash in WASD direction (first 30% has i-frames)\nESC to pause\nGamepad: left stick move, right stick aim, LT shield, RT fire, A dash\nLeft click to fire (B: auto/semi, R: reload, 1-9 or scroll to switch)\n{}\n{}\n{}\n{}\n{}",
+                shield_info, energy_info, dash_info, weapon_info, boss_info
             );
         }
     }
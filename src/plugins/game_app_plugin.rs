@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+use crate::states::AppState;
+use crate::resources::PauseState;
+use crate::plugins::{MenuPlugin, GamePlugin, DebugPlugin, GameOverPlugin, VictoryPlugin, DamagePlugin, CollisionPlugin, PlayerControlsPlugin, WeaponPlugin, BossPlugin, ActionInputPlugin, ControlsPlugin, SimulationPlugin, ReplayPlugin, CampaignPlugin, ProfilePlugin, SettingsPlugin};
+
+/// All game states, resources and plugins, on top of either `DefaultPlugins` or the headless setup
+pub struct GameAppPlugin;
+
+impl Plugin for GameAppPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_state::<AppState>()
+            .init_resource::<PauseState>()
+            // Simulation, input and player data
+            .add_plugins((
+                SimulationPlugin,
+                ActionInputPlugin,
+                ReplayPlugin,
+                ProfilePlugin,
+                SettingsPlugin,
+                CampaignPlugin,
+            ))
+            // Screens and gameplay
+            .add_plugins((
+                MenuPlugin,
+                ControlsPlugin,
+                GamePlugin,
+                DebugPlugin,
+                GameOverPlugin,
+                VictoryPlugin,
+                DamagePlugin,
+                CollisionPlugin,
+                PlayerControlsPlugin,
+                WeaponPlugin,
+                BossPlugin,
+            ));
+    }
+}
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 use crate::states::AppState;
 use crate::components::{GameOverUI, GameOverButton};
-use crate::systems::{cleanup_ui, create_button_with_component, button_hover_system};
+use crate::resources::LastReplay;
+use crate::systems::{cleanup_ui, create_button_with_component, button_hover_system, watch_replay};
 use crate::constants::AppColors;
 
 pub struct GameOverPlugin;
@@ -63,6 +64,9 @@ fn setup_game_over_screen(mut commands: Commands) {
                     // Restart Game button
                     create_button_with_component(parent, "Restart Game", 32.0, GameOverButton::RestartGame);
 
+                    // Watch Replay button
+                    create_button_with_component(parent, "Watch Replay", 32.0, GameOverButton::WatchReplay);
+
                     // Back to Menu button
                     create_button_with_component(parent, "Back to Menu", 32.0, GameOverButton::BackToMenu);
 
@@ -80,6 +84,8 @@ fn handle_game_over_buttons(
     >,
     mut next_state: ResMut<NextState<AppState>>,
     mut exit: EventWriter<AppExit>,
+    mut commands: Commands,
+    last_replay: Res<LastReplay>,
 ) {
     for (interaction, button) in &mut interaction_query {
         if *interaction == Interaction::Pressed {
@@ -88,6 +94,11 @@ fn handle_game_over_buttons(
                     info!("Restarting game");
                     next_state.set(AppState::Game);
                 }
+                GameOverButton::WatchReplay => {
+                    if let Some(replay) = &last_replay.replay {
+                        watch_replay(&mut commands, &mut next_state, replay);
+                    }
+                }
                 GameOverButton::BackToMenu => {
                     info!("Returning to main menu");
                     next_state.set(AppState::MainMenu);
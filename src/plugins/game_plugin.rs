@@ -1,19 +1,20 @@
 use bevy::prelude::*;
 use crate::states::AppState;
-use crate::components::{GameUI, PauseOverlayUI, HealthBarUI, EnergyBarUI};
+use crate::plugins::DamageSet;
+use crate::resources::FightStats;
+use crate::components::{GameUI, PauseOverlayUI, HealthBarUI, EnergyBarUI, AmmoDisplayUI};
 use crate::systems::{
     cleanup_ui, handle_pause_input, update_pause_timer,
     spawn_pause_overlay, despawn_pause_overlay, handle_pause_buttons, button_hover_system,
     reset_pause_state, spawn_health_bar, update_health_bar, update_health_bar_color,
     spawn_energy_bar, update_energy_bar, update_energy_bar_color,
+    spawn_ammo_display, update_ammo_display,
     check_player_death, spawn_player, cleanup_player, cleanup_debug_entities,
-    player_movement, player_face_mouse, camera_follow_player, manage_player_invulnerability,
-
-    spawn_mine_boss, cleanup_boss_entities,
-    mine_boss_ai, boss_dash_movement, boss_rotation_animation, boss_player_collision, boss_collision_damage,
-    spawn_boundary_visuals, enforce_boundaries, cleanup_boundary_visuals,
+    begin_encounter, cleanup_boss_entities, cleanup_boss_health_bar,
+    spawn_boundary_visuals, cleanup_boundary_visuals, spawn_arena_obstacles, cleanup_obstacles,
     spawn_edge_warnings, update_edge_warnings, cleanup_edge_warnings,
-    weapon_firing_system, projectile_movement_system, projectile_lifetime_system, projectile_boss_collision_system, cleanup_projectiles
+    cleanup_projectiles, cleanup_mines,
+    reset_fight_stats, tick_fight_clock, count_parries, check_boss_defeat,
 };
 
 
@@ -21,8 +22,15 @@ pub struct GamePlugin;
 
 impl Plugin for GamePlugin {
     fn build(&self, app: &mut App) {
+        // Player controls, weapons and boss systems are shared with Debug mode
+        // through PlayerControlsPlugin, WeaponPlugin and BossPlugin
         app
-            .add_systems(OnEnter(AppState::Game), (setup_game_screen, spawn_boundary_visuals, spawn_edge_warnings, spawn_health_bar, spawn_energy_bar, spawn_player, spawn_mine_boss))
+            .init_resource::<FightStats>()
+            .add_systems(
+                OnEnter(AppState::Game),
+                // The encounter decides the boss, arena and loadout the rest is set up with
+                (begin_encounter, (reset_fight_stats, setup_game_screen, spawn_boundary_visuals, spawn_arena_obstacles, spawn_edge_warnings, spawn_health_bar, spawn_energy_bar, spawn_ammo_display, spawn_player)).chain(),
+            )
             .add_systems(
                 Update,
                 (
@@ -35,38 +43,6 @@ impl Plugin for GamePlugin {
                     button_hover_system,
                 ).run_if(in_state(AppState::Game)),
             )
-            .add_systems(
-                Update,
-                (
-                    // Player systems - use chain for proper ordering
-                    player_movement,
-                    manage_player_invulnerability,
-                    player_face_mouse,
-                    enforce_boundaries, // Apply boundary constraints after movement
-                    camera_follow_player,
-                ).chain().run_if(in_state(AppState::Game)),
-            )
-            .add_systems(
-                Update,
-                (
-                    // Weapon systems
-                    weapon_firing_system,
-                    projectile_movement_system,
-                    projectile_lifetime_system,
-                    projectile_boss_collision_system,
-                ).run_if(in_state(AppState::Game)),
-            )
-            .add_systems(
-                Update,
-                (
-                    // Boss systems
-                    mine_boss_ai,
-                    boss_dash_movement,
-                    boss_rotation_animation,
-                    boss_player_collision,
-                    boss_collision_damage,
-                ).run_if(in_state(AppState::Game)),
-            )
             .add_systems(
                 Update,
                 (
@@ -75,8 +51,17 @@ impl Plugin for GamePlugin {
                     update_health_bar_color,
                     update_energy_bar,
                     update_energy_bar_color,
+                    update_ammo_display,
                     update_edge_warnings, // Update edge warning intensity
-                    check_player_death,
+                ).run_if(in_state(AppState::Game)),
+            )
+            .add_systems(
+                FixedUpdate,
+                (
+                    tick_fight_clock,
+                    count_parries.after(DamageSet::Resolve),
+                    // A player killed on the same tick as the last boss still loses
+                    (check_boss_defeat, check_player_death).chain().after(DamageSet::Resolve),
                 ).run_if(in_state(AppState::Game)),
             )
             .add_systems(OnExit(AppState::Game), (
@@ -84,12 +69,16 @@ impl Plugin for GamePlugin {
                 cleanup_ui::<PauseOverlayUI>,
                 cleanup_ui::<HealthBarUI>,
                 cleanup_ui::<EnergyBarUI>,
+                cleanup_ui::<AmmoDisplayUI>,
+                cleanup_boss_health_bar,
                 cleanup_player,
                 cleanup_boss_entities,
                 cleanup_debug_entities,
                 cleanup_boundary_visuals,
+                cleanup_obstacles,
                 cleanup_edge_warnings,
                 cleanup_projectiles,
+                cleanup_mines,
                 reset_pause_state,
             ));
     }
@@ -1,7 +1,8 @@
 use bevy::prelude::*;
 use crate::states::AppState;
 use crate::components::{MainMenuUI, MenuButton};
-use crate::systems::{handle_menu_buttons, button_hover_system, cleanup_ui, create_button_with_component};
+use crate::resources::Difficulty;
+use crate::systems::{handle_menu_buttons, update_difficulty_button, difficulty_button_label, button_hover_system, cleanup_ui, create_button_with_component};
 use crate::constants::AppColors;
 
 pub struct MenuPlugin;
@@ -13,7 +14,11 @@ impl Plugin for MenuPlugin {
             .add_systems(OnEnter(AppState::MainMenu), setup_main_menu)
             .add_systems(
                 Update,
-                (handle_menu_buttons, button_hover_system).run_if(in_state(AppState::MainMenu)),
+                (
+                    handle_menu_buttons,
+                    update_difficulty_button.run_if(resource_changed::<Difficulty>),
+                    button_hover_system,
+                ).chain().run_if(in_state(AppState::MainMenu)),
             )
             .add_systems(OnExit(AppState::MainMenu), cleanup_ui::<MainMenuUI>);
     }
@@ -25,7 +30,7 @@ fn setup_camera(mut commands: Commands) {
 }
 
 /// System to setup the main menu UI
-fn setup_main_menu(mut commands: Commands) {
+fn setup_main_menu(mut commands: Commands, difficulty: Res<Difficulty>) {
     // Root UI container
     commands
         .spawn((
@@ -66,9 +71,24 @@ fn setup_main_menu(mut commands: Commands) {
                     // Start Game button
                     create_button_with_component(parent, "Start Game", 32.0, MenuButton::StartGame);
 
+                    // Difficulty button
+                    create_button_with_component(parent, &difficulty_button_label(*difficulty), 32.0, MenuButton::Difficulty);
+
+                    // Level Select button
+                    create_button_with_component(parent, "Level Select", 32.0, MenuButton::LevelSelect);
+
                     // Debug Mode button
                     create_button_with_component(parent, "Debug Mode", 32.0, MenuButton::DebugMode);
 
+                    // Controls button
+                    create_button_with_component(parent, "Controls", 32.0, MenuButton::Controls);
+
+                    // Settings button
+                    create_button_with_component(parent, "Settings", 32.0, MenuButton::Settings);
+
+                    // Watch Last Replay button
+                    create_button_with_component(parent, "Watch Last Replay", 32.0, MenuButton::WatchReplay);
+
                     // Exit to Desktop button
                     create_button_with_component(parent, "Exit to Desktop", 32.0, MenuButton::ExitToDesktop);
                 });
@@ -2,8 +2,36 @@ mod menu_plugin;
 mod game_plugin;
 mod debug_plugin;
 mod game_over_plugin;
+mod victory_plugin;
+mod damage_plugin;
+mod collision_plugin;
+mod player_controls_plugin;
+mod boss_plugin;
+mod weapon_plugin;
+mod action_input_plugin;
+mod controls_plugin;
+mod simulation_plugin;
+mod replay_plugin;
+mod campaign_plugin;
+mod profile_plugin;
+mod settings_plugin;
+mod game_app_plugin;
 
 pub use menu_plugin::MenuPlugin;
 pub use game_plugin::GamePlugin;
 pub use debug_plugin::DebugPlugin;
 pub use game_over_plugin::GameOverPlugin;
+pub use victory_plugin::VictoryPlugin;
+pub use damage_plugin::{DamagePlugin, DamageSet};
+pub use collision_plugin::CollisionPlugin;
+pub use player_controls_plugin::PlayerControlsPlugin;
+pub use boss_plugin::BossPlugin;
+pub use weapon_plugin::WeaponPlugin;
+pub use action_input_plugin::ActionInputPlugin;
+pub use controls_plugin::ControlsPlugin;
+pub use simulation_plugin::SimulationPlugin;
+pub use replay_plugin::ReplayPlugin;
+pub use campaign_plugin::CampaignPlugin;
+pub use profile_plugin::ProfilePlugin;
+pub use settings_plugin::SettingsPlugin;
+pub use game_app_plugin::GameAppPlugin;
@@ -0,0 +1,41 @@
+use bevy::prelude::*;
+use crate::states::in_gameplay_state;
+use crate::plugins::DamageSet;
+use crate::systems::{
+    player_movement, manage_player_invulnerability, player_face_aim,
+    handle_shield_input, animate_shield, update_shield_collider, update_shield_mesh, refund_parry_energy, spawn_parry_flash,
+    enforce_boundaries, camera_follow_player, add_screen_shake_on_damage, update_screen_shake,
+};
+use crate::resources::ScreenShake;
+
+/// Player movement and shield systems shared by the Game and Debug states.
+/// Movement and shield logic is simulated in `FixedUpdate`, the shield mesh, camera follow and screen shake every frame.
+pub struct PlayerControlsPlugin;
+
+impl Plugin for PlayerControlsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ScreenShake>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    // Player systems - use chain to ensure proper ordering
+                    player_movement,
+                    manage_player_invulnerability,
+                    player_face_aim,
+                    handle_shield_input,
+                    animate_shield,
+                    update_shield_collider,
+                    enforce_boundaries, // Apply boundary constraints after movement
+                ).chain().run_if(in_gameplay_state),
+            )
+            .add_systems(FixedUpdate, (refund_parry_energy, spawn_parry_flash).after(DamageSet::Resolve).run_if(in_gameplay_state))
+            .add_systems(
+                Update,
+                (
+                    update_shield_mesh,
+                    (add_screen_shake_on_damage, update_screen_shake, camera_follow_player).chain(),
+                ).run_if(in_gameplay_state),
+            );
+    }
+}
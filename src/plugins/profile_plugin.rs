@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+use crate::states::AppState;
+use crate::persistence::Profile;
+use crate::systems::{load_profile, save_profile, record_fight_in_profile};
+
+/// The player's profile: loaded at startup, updated when fights end and saved whenever it changes
+pub struct ProfilePlugin;
+
+impl Plugin for ProfilePlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<Profile>()
+            .add_systems(Startup, load_profile)
+            .add_systems(OnEnter(AppState::GameOver), record_fight_in_profile)
+            .add_systems(OnEnter(AppState::Victory), record_fight_in_profile)
+            .add_systems(Last, save_profile.run_if(resource_changed::<Profile>.and(not(resource_added::<Profile>))));
+    }
+}
@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+use crate::states::AppState;
+use crate::components::ReplayUI;
+use crate::resources::{LastReplay, ReplayPlayback, ReplayRecorder};
+use crate::systems::{
+    begin_fixed_action_tick, resolve_tick_input, play_back_tick_input,
+    begin_fight_input, stop_fight_recording, save_fight_replay, stop_replay_playback, update_replay_seek,
+    cleanup_ui, spawn_replay_controls, update_replay_controls, handle_replay_timeline, handle_replay_buttons,
+};
+
+/// Recording of every fight's tick inputs into a replay, and playback of replays with a seekable timeline.
+/// During playback the recorded `TickInput` drives `FixedActionState` instead of the input devices.
+pub struct ReplayPlugin;
+
+impl Plugin for ReplayPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<ReplayRecorder>()
+            .init_resource::<LastReplay>()
+            .add_systems(
+                FixedPreUpdate,
+                (
+                    resolve_tick_input.run_if(not(resource_exists::<ReplayPlayback>)),
+                    play_back_tick_input.run_if(resource_exists::<ReplayPlayback>.and(in_state(AppState::Game))),
+                ).after(begin_fixed_action_tick),
+            )
+            .add_systems(OnEnter(AppState::Game), (begin_fight_input, spawn_replay_controls.run_if(resource_exists::<ReplayPlayback>)))
+            .add_systems(
+                Update,
+                (
+                    update_replay_seek,
+                    update_replay_controls,
+                    handle_replay_timeline,
+                    handle_replay_buttons,
+                ).run_if(in_state(AppState::Game).and(resource_exists::<ReplayPlayback>)),
+            )
+            .add_systems(OnExit(AppState::Game), (stop_fight_recording, cleanup_ui::<ReplayUI>))
+            .add_systems(OnEnter(AppState::GameOver), (save_fight_replay, stop_replay_playback))
+            .add_systems(OnEnter(AppState::Victory), (save_fight_replay, stop_replay_playback))
+            .add_systems(OnEnter(AppState::MainMenu), stop_replay_playback);
+    }
+}
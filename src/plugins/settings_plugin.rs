@@ -0,0 +1,236 @@
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use crate::states::AppState;
+use crate::components::{SettingsUI, SettingsButton, SettingValueLabel, SettingSlider, SettingSliderFill};
+use crate::persistence::{CycleSetting, GameSettings, SliderSetting};
+use crate::systems::{
+    apply_video_settings, apply_audio_settings, store_settings_in_profile,
+    cleanup_ui, create_button_with_component, button_hover_system,
+};
+use crate::constants::AppColors;
+
+/// Video, audio, gameplay and accessibility settings: applied live whenever they change, and edited
+/// on the settings screen, which stores them in the profile when left
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<GameSettings>()
+            .add_systems(Update, (apply_video_settings, apply_audio_settings).run_if(resource_changed::<GameSettings>))
+            .add_systems(OnEnter(AppState::Settings), setup_settings_screen)
+            .add_systems(
+                Update,
+                (
+                    handle_settings_buttons,
+                    handle_setting_sliders,
+                    update_setting_values.run_if(resource_changed::<GameSettings>),
+                    button_hover_system,
+                ).chain().run_if(in_state(AppState::Settings)),
+            )
+            .add_systems(OnExit(AppState::Settings), (cleanup_ui::<SettingsUI>, store_settings_in_profile));
+    }
+}
+
+/// System to setup the settings screen UI, with arrows for choice settings and sliders for the rest
+fn setup_settings_screen(mut commands: Commands, settings: Res<GameSettings>) {
+    // Root UI container
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(AppColors::BACKGROUND),
+            SettingsUI,
+        ))
+        .with_children(|parent| {
+            // Title
+            parent.spawn((
+                Text::new("Settings"),
+                TextFont {
+                    font_size: 60.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                Node {
+                    margin: UiRect::bottom(Val::Px(20.0)),
+                    ..default()
+                },
+            ));
+
+            // Choice settings: label, previous arrow, value, next arrow
+            for setting in CycleSetting::ALL {
+                spawn_setting_row(parent, setting.label()).with_children(|parent| {
+                    parent
+                        .spawn(Node {
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(10.0),
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            spawn_arrow_button(parent, "<", SettingsButton::Previous(setting));
+                            parent.spawn((
+                                Text::new(settings.describe(setting)),
+                                TextFont {
+                                    font_size: 22.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.8, 0.8, 0.4)),
+                                Node {
+                                    width: Val::Px(260.0),
+                                    justify_content: JustifyContent::Center,
+                                    ..default()
+                                },
+                                TextLayout::new_with_justify(JustifyText::Center),
+                                SettingValueLabel(setting),
+                            ));
+                            spawn_arrow_button(parent, ">", SettingsButton::Next(setting));
+                        });
+                });
+            }
+
+            // Slider settings: label and a bar filled up to the value
+            for setting in SliderSetting::ALL {
+                spawn_setting_row(parent, setting.label()).with_children(|parent| {
+                    parent
+                        .spawn((
+                            Node {
+                                width: Val::Px(340.0),
+                                height: Val::Px(16.0),
+                                border: UiRect::all(Val::Px(2.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.3, 0.3, 0.3)), // Dark gray background
+                            BorderColor(Color::WHITE),
+                            BorderRadius::all(Val::Px(4.0)),
+                            Interaction::default(),
+                            RelativeCursorPosition::default(),
+                            SettingSlider(setting),
+                        ))
+                        .with_children(|parent| {
+                            parent.spawn((
+                                Node {
+                                    width: Val::Percent(settings.slider(setting) * 100.0),
+                                    height: Val::Percent(100.0),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgb(0.2, 0.6, 0.9)), // Blue fill
+                                BorderRadius::all(Val::Px(2.0)),
+                                SettingSliderFill(setting),
+                            ));
+                        });
+                });
+            }
+
+            // Back button
+            create_button_with_component(parent, "Back", 32.0, SettingsButton::Back);
+        });
+}
+
+/// Spawn a settings row with its label on the left, returning it to add the control on the right
+fn spawn_setting_row<'a>(parent: &'a mut ChildSpawnerCommands, label: &str) -> EntityCommands<'a> {
+    let mut row = parent.spawn(Node {
+        width: Val::Px(640.0),
+        height: Val::Px(44.0),
+        justify_content: JustifyContent::SpaceBetween,
+        align_items: AlignItems::Center,
+        margin: UiRect::all(Val::Px(2.0)),
+        ..default()
+    });
+    row.with_children(|parent| {
+        parent.spawn((
+            Text::new(label),
+            TextFont {
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+    });
+    row
+}
+
+/// Spawn a small arrow button to step through a choice setting
+fn spawn_arrow_button(parent: &mut ChildSpawnerCommands, text: &str, button: SettingsButton) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(36.0),
+                height: Val::Px(36.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+            BorderRadius::all(Val::Px(5.0)),
+            button,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(text),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// System to handle settings screen button clicks
+fn handle_settings_buttons(
+    mut interaction_query: Query<
+        (&Interaction, &SettingsButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut settings: ResMut<GameSettings>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, button) in &mut interaction_query {
+        if *interaction == Interaction::Pressed {
+            match button {
+                SettingsButton::Previous(setting) => settings.cycle(*setting, -1),
+                SettingsButton::Next(setting) => settings.cycle(*setting, 1),
+                SettingsButton::Back => {
+                    next_state.set(AppState::MainMenu);
+                }
+            }
+        }
+    }
+}
+
+/// System to set a slider's value from the cursor while it is pressed, so it can be clicked or dragged
+fn handle_setting_sliders(
+    slider_query: Query<(&Interaction, &RelativeCursorPosition, &SettingSlider)>,
+    mut settings: ResMut<GameSettings>,
+) {
+    for (interaction, cursor, slider) in &slider_query {
+        let (Interaction::Pressed, Some(position)) = (interaction, cursor.normalized) else {
+            continue;
+        };
+        let value = position.x.clamp(0.0, 1.0);
+        if settings.slider(slider.0) != value {
+            settings.set_slider(slider.0, value);
+        }
+    }
+}
+
+/// System to refresh the shown values and slider fills after a setting changed
+fn update_setting_values(
+    settings: Res<GameSettings>,
+    mut label_query: Query<(&SettingValueLabel, &mut Text)>,
+    mut fill_query: Query<(&SettingSliderFill, &mut Node)>,
+) {
+    for (label, mut text) in &mut label_query {
+        text.0 = settings.describe(label.0);
+    }
+    for (fill, mut node) in &mut fill_query {
+        node.width = Val::Percent(settings.slider(fill.0) * 100.0);
+    }
+}
@@ -0,0 +1,27 @@
+use bevy::prelude::*;
+use bevy::app::RunFixedMainLoopSystem;
+use crate::resources::SimulationSettings;
+use crate::systems::{
+    apply_tick_rate, restore_simulated_transforms, record_previous_transforms, record_current_transforms, interpolate_transforms,
+};
+
+/// Fixed-timestep clock for the gameplay simulation, and `Transform` interpolation between its ticks.
+/// Gameplay systems run in `FixedUpdate` and read `Res<Time>`, which is the fixed clock there.
+pub struct SimulationPlugin;
+
+impl Plugin for SimulationPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_resource::<SimulationSettings>()
+            .add_systems(PreUpdate, apply_tick_rate.run_if(resource_changed::<SimulationSettings>))
+            .add_systems(
+                RunFixedMainLoop,
+                (
+                    restore_simulated_transforms.in_set(RunFixedMainLoopSystem::BeforeFixedMainLoop),
+                    interpolate_transforms.in_set(RunFixedMainLoopSystem::AfterFixedMainLoop),
+                ),
+            )
+            .add_systems(FixedFirst, record_previous_transforms)
+            .add_systems(FixedLast, record_current_transforms);
+    }
+}
@@ -0,0 +1,232 @@
+use bevy::prelude::*;
+use crate::states::AppState;
+use crate::components::{VictoryUI, VictoryButton};
+use crate::assets::EncounterSequence;
+use crate::persistence::Profile;
+use crate::resources::{ActiveEncounter, EncounterAssets, FightStats, LastReplay, SelectedEncounter};
+use crate::systems::{format_fight_time, cleanup_ui, create_button_with_component, button_hover_system, watch_replay, record_encounter_victory};
+use crate::constants::AppColors;
+
+/// Results screen shown when every boss of the fight has been defeated
+pub struct VictoryPlugin;
+
+impl Plugin for VictoryPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_systems(OnEnter(AppState::Victory), (record_encounter_victory, setup_victory_screen).chain())
+            .add_systems(
+                Update,
+                (
+                    handle_victory_buttons,
+                    button_hover_system,
+                ).run_if(in_state(AppState::Victory)),
+            )
+            .add_systems(OnExit(AppState::Victory), cleanup_ui::<VictoryUI>);
+    }
+}
+
+/// System to setup the victory screen UI with the statistics of the fight and the rewards of the encounter
+fn setup_victory_screen(
+    mut commands: Commands,
+    stats: Res<FightStats>,
+    active: Option<Res<ActiveEncounter>>,
+    encounter_assets: Res<EncounterAssets>,
+    sequences: Res<Assets<EncounterSequence>>,
+    profile: Res<Profile>,
+) {
+    let sequence = sequences.get(&encounter_assets.sequence);
+    let encounter = active
+        .as_ref()
+        .zip(sequence)
+        .and_then(|(active, sequence)| sequence.encounters.get(active.index));
+    // Offer the next boss only once it is unlocked, and not at the end of a replay
+    let has_next_boss = active
+        .as_ref()
+        .filter(|active| active.is_counted)
+        .zip(sequence)
+        .is_some_and(|(active, sequence)| sequence.is_unlocked(active.index + 1, &profile.campaign.beaten));
+    let rewards = encounter
+        .filter(|_| active.as_ref().is_some_and(|active| active.is_counted))
+        .map(|encounter| encounter.unlocks.iter().map(|unlock| unlock.describe()).collect::<Vec<_>>())
+        .unwrap_or_default();
+
+    let personal_best = active
+        .as_ref()
+        .zip(encounter)
+        .and_then(|(active, encounter)| profile.personal_best(&encounter.name, active.difficulty));
+
+    let mut results = vec![
+        ("Fight duration", format_fight_time(stats.duration)),
+        ("Damage dealt", format!("{:.0}", stats.damage_dealt)),
+        ("Damage taken", format!("{:.0}", stats.damage_taken)),
+        ("Shots hit", format!("{} / {} ({:.0}%)", stats.shots_hit, stats.shots_fired, stats.accuracy() * 100.0)),
+        ("Shield blocks", stats.shield_blocks.to_string()),
+        ("Perfect parries", stats.parries.to_string()),
+        ("Dashes used", stats.dashes.to_string()),
+    ];
+    if let Some(active) = &active {
+        results.insert(0, ("Difficulty", active.difficulty.label().to_string()));
+    }
+    if let Some(best) = personal_best {
+        results.push(("Best time", format_fight_time(best.fastest_time)));
+    }
+
+    // Root UI container
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(AppColors::BACKGROUND),
+            VictoryUI,
+        ))
+        .with_children(|parent| {
+            // Victory Title
+            parent.spawn((
+                Text::new("VICTORY"),
+                TextFont {
+                    font_size: 80.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.9, 0.8, 0.2)), // Gold color for the win
+                Node {
+                    margin: UiRect::bottom(Val::Px(10.0)),
+                    ..default()
+                },
+            ));
+
+            // Name of the encounter won
+            if let Some(encounter) = encounter {
+                parent.spawn((
+                    Text::new(encounter.name.clone()),
+                    TextFont {
+                        font_size: 32.0,
+                        ..default()
+                    },
+                    TextColor(Color::WHITE),
+                    Node {
+                        margin: UiRect::bottom(Val::Px(20.0)),
+                        ..default()
+                    },
+                ));
+            }
+
+            // Fight results, one row per statistic
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(6.0),
+                    margin: UiRect::bottom(Val::Px(30.0)),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    for (label, value) in results {
+                        parent
+                            .spawn(Node {
+                                width: Val::Px(420.0),
+                                justify_content: JustifyContent::SpaceBetween,
+                                ..default()
+                            })
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    Text::new(label),
+                                    TextFont {
+                                        font_size: 24.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                                ));
+                                parent.spawn((
+                                    Text::new(value),
+                                    TextFont {
+                                        font_size: 24.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::WHITE),
+                                ));
+                            });
+                    }
+                });
+
+            // Rewards unlocked by the encounter
+            for reward in rewards {
+                parent.spawn((
+                    Text::new(reward),
+                    TextFont {
+                        font_size: 22.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.9, 0.8, 0.2)),
+                ));
+            }
+
+            // Menu buttons container
+            parent
+                .spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|parent| {
+                    // Next Boss button
+                    if has_next_boss {
+                        create_button_with_component(parent, "Next Boss", 32.0, VictoryButton::NextBoss);
+                    }
+
+                    // Retry button
+                    create_button_with_component(parent, "Retry", 32.0, VictoryButton::Retry);
+
+                    // Watch Replay button
+                    create_button_with_component(parent, "Watch Replay", 32.0, VictoryButton::WatchReplay);
+
+                    // Back to Menu button
+                    create_button_with_component(parent, "Back to Menu", 32.0, VictoryButton::BackToMenu);
+                });
+        });
+}
+
+/// System to handle victory screen button clicks
+fn handle_victory_buttons(
+    mut interaction_query: Query<
+        (&Interaction, &VictoryButton),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut commands: Commands,
+    last_replay: Res<LastReplay>,
+    active: Option<Res<ActiveEncounter>>,
+    mut selected: ResMut<SelectedEncounter>,
+) {
+    let active_index = active.map(|active| active.index);
+    for (interaction, button) in &mut interaction_query {
+        if *interaction == Interaction::Pressed {
+            match button {
+                VictoryButton::NextBoss => {
+                    selected.index = active_index.map_or(0, |index| index + 1);
+                    next_state.set(AppState::Game);
+                }
+                VictoryButton::Retry => {
+                    info!("Retrying fight");
+                    if let Some(index) = active_index {
+                        selected.index = index;
+                    }
+                    next_state.set(AppState::Game);
+                }
+                VictoryButton::WatchReplay => {
+                    if let Some(replay) = &last_replay.replay {
+                        watch_replay(&mut commands, &mut next_state, replay);
+                    }
+                }
+                VictoryButton::BackToMenu => {
+                    info!("Returning to main menu");
+                    next_state.set(AppState::MainMenu);
+                }
+            }
+        }
+    }
+}
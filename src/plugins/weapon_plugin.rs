@@ -0,0 +1,42 @@
+use bevy::prelude::*;
+use crate::states::in_gameplay_state;
+use crate::plugins::DamageSet;
+use crate::assets::{WeaponRegistry, WeaponRegistryLoader};
+use crate::systems::{
+    load_weapon_registry,
+    weapon_input_system, weapon_switch_system, weapon_firing_system, homing_projectile_system, projectile_movement_system, projectile_lifetime_system,
+    projectile_boss_collision_system, projectile_player_collision_system, projectile_obstacle_collision_system,
+    rebuild_spatial_index, enforce_boundaries,
+};
+
+/// Weapon registry, weapon switching/firing and projectile systems shared by the Game and Debug states.
+/// Weapon switching, firing and projectiles are simulated in `FixedUpdate`.
+pub struct WeaponPlugin;
+
+impl Plugin for WeaponPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .init_asset::<WeaponRegistry>()
+            .init_asset_loader::<WeaponRegistryLoader>()
+            .add_systems(Startup, load_weapon_registry)
+            .add_systems(
+                FixedUpdate,
+                (
+                    // Weapon systems
+                    weapon_switch_system.before(weapon_input_system),
+                    weapon_input_system.before(weapon_firing_system),
+                    // Fires from where the player ends the tick
+                    weapon_firing_system.after(enforce_boundaries),
+                    // Steers by the spatial index of this tick's positions, the new heading is flown next tick
+                    homing_projectile_system.after(rebuild_spatial_index).before(DamageSet::Detect),
+                    projectile_movement_system,
+                    projectile_lifetime_system,
+                    projectile_boss_collision_system.after(projectile_movement_system).in_set(DamageSet::Detect),
+                    // Hostile projectiles are reflected before player projectiles are checked against bosses
+                    projectile_player_collision_system.after(projectile_movement_system).before(projectile_boss_collision_system).in_set(DamageSet::Detect),
+                    // Obstacles stop whatever projectiles are left after their earlier hits
+                    projectile_obstacle_collision_system.after(projectile_boss_collision_system).in_set(DamageSet::Detect),
+                ).run_if(in_gameplay_state),
+            );
+    }
+}
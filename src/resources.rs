@@ -1,4 +1,8 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
+use crate::assets::{BossDefinition, EncounterSequence, Loadout, WeaponRegistry};
+use crate::input::{InputSlot, Replay, TickInput};
 
 /// Resource to track pause state and timer
 #[derive(Resource, Default)]
@@ -25,3 +29,261 @@ impl PauseState {
         }
     }
 }
+
+/// Resource with the rate the gameplay simulation ticks at in `FixedUpdate`, independent of the frame rate
+#[derive(Resource, Debug, Clone)]
+pub struct SimulationSettings {
+    /// Ticks per second
+    pub tick_rate: f64,
+}
+
+impl SimulationSettings {
+    pub const DEFAULT_TICK_RATE: f64 = 60.0;
+    /// Tick rates the simulation can run at, from profiles and replays alike
+    pub const TICK_RATES: RangeInclusive<f64> = 10.0..=240.0;
+
+    pub fn supports_tick_rate(tick_rate: f64) -> bool {
+        Self::TICK_RATES.contains(&tick_rate)
+    }
+}
+
+impl Default for SimulationSettings {
+    fn default() -> Self {
+        Self {
+            tick_rate: Self::DEFAULT_TICK_RATE,
+        }
+    }
+}
+
+/// Resource with the difficulty chosen on the main menu, which scales the boss and the player when a fight starts
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Difficulty {
+    Story,
+    #[default]
+    Normal,
+    Hard,
+    Nightmare,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 4] = [Self::Story, Self::Normal, Self::Hard, Self::Nightmare];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Story => "Story",
+            Self::Normal => "Normal",
+            Self::Hard => "Hard",
+            Self::Nightmare => "Nightmare",
+        }
+    }
+
+    /// The next harder difficulty, wrapping around to the easiest
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|difficulty| difficulty == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    pub fn multipliers(&self) -> DifficultyMultipliers {
+        match self {
+            Self::Story => DifficultyMultipliers {
+                boss_health: 0.6,
+                boss_damage: 0.5,
+                dash_cooldown: 1.5,
+                telegraph: 1.5,
+                energy_recharge: 1.5,
+            },
+            Self::Normal => DifficultyMultipliers::default(),
+            Self::Hard => DifficultyMultipliers {
+                boss_health: 1.4,
+                boss_damage: 1.3,
+                dash_cooldown: 0.8,
+                telegraph: 0.8,
+                energy_recharge: 0.85,
+            },
+            Self::Nightmare => DifficultyMultipliers {
+                boss_health: 1.8,
+                boss_damage: 1.6,
+                dash_cooldown: 0.6,
+                telegraph: 0.65,
+                energy_recharge: 0.7,
+            },
+        }
+    }
+}
+
+/// Factors a difficulty applies to the values of boss definitions and the player
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DifficultyMultipliers {
+    pub boss_health: f32,
+    /// Applies to every source of boss damage: contact, dashes, mines and projectiles
+    pub boss_damage: f32,
+    /// Time between boss dashes
+    pub dash_cooldown: f32,
+    /// Windup before boss dashes
+    pub telegraph: f32,
+    pub energy_recharge: f32,
+}
+
+impl Default for DifficultyMultipliers {
+    fn default() -> Self {
+        Self {
+            boss_health: 1.0,
+            boss_damage: 1.0,
+            dash_cooldown: 1.0,
+            telegraph: 1.0,
+            energy_recharge: 1.0,
+        }
+    }
+}
+
+/// Resource with the world position the player is aiming at.
+/// Updated from the window cursor, or injected directly in headless apps.
+#[derive(Resource, Default)]
+pub struct CursorWorldPosition {
+    pub position: Option<Vec2>,
+}
+
+/// Resource holding the handle to the campaign's encounter sequence asset, which also loads its bosses
+#[derive(Resource)]
+pub struct EncounterAssets {
+    pub sequence: Handle<EncounterSequence>,
+}
+
+/// Resource with the index of the encounter that Start Game, Retry and Next Boss start
+#[derive(Resource, Debug, Clone, Default)]
+pub struct SelectedEncounter {
+    pub index: usize,
+}
+
+/// Resource with the encounter being fought and the loadout it is fought with, set when a fight starts
+#[derive(Resource, Debug, Clone)]
+pub struct ActiveEncounter {
+    /// Index in the `EncounterSequence`
+    pub index: usize,
+    pub loadout: Loadout,
+    pub difficulty: Difficulty,
+    /// Whether the fight counts towards the profile's progress and statistics, unlike replays and debug fights
+    pub is_counted: bool,
+}
+
+/// Resource describing a boss waiting for its definition to finish loading
+#[derive(Resource)]
+pub struct PendingBossSpawn {
+    pub definition: Handle<BossDefinition>,
+    pub position: Vec3,
+    /// Difficulty the boss is scaled to when spawned
+    pub difficulty: Difficulty,
+}
+
+/// Resource holding the handle to the player weapon registry asset
+#[derive(Resource)]
+pub struct WeaponAssets {
+    pub registry: Handle<WeaponRegistry>,
+}
+
+/// Resource with the input slot waiting for a key or button press on the controls screen
+#[derive(Resource, Default)]
+pub struct PendingRebind {
+    pub slot: Option<InputSlot>,
+}
+
+/// Resource collecting the input of every tick of the current fight
+#[derive(Resource, Default)]
+pub struct ReplayRecorder {
+    pub is_recording: bool,
+    pub inputs: Vec<TickInput>,
+}
+
+/// Resource with the replay of the latest fight, recorded or loaded
+#[derive(Resource, Default)]
+pub struct LastReplay {
+    pub replay: Option<Replay>,
+}
+
+/// Resource present while a replay is played back, feeding its input to the simulation instead of the devices
+#[derive(Resource)]
+pub struct ReplayPlayback {
+    inputs: Vec<TickInput>,
+    pub tick_rate: f64,
+    /// Encounter, loadout and difficulty the replay was recorded with
+    pub encounter: usize,
+    pub loadout: Loadout,
+    pub difficulty: Difficulty,
+    /// Next tick to play
+    pub tick: usize,
+    /// Tick being fast-forwarded to after seeking
+    pub seek_target: Option<usize>,
+}
+
+impl ReplayPlayback {
+    pub fn new(replay: &Replay) -> Self {
+        Self {
+            inputs: replay.inputs(),
+            tick_rate: replay.tick_rate,
+            encounter: replay.encounter,
+            loadout: replay.loadout.clone(),
+            difficulty: replay.difficulty,
+            tick: 0,
+            seek_target: None,
+        }
+    }
+
+    pub fn tick_count(&self) -> usize {
+        self.inputs.len()
+    }
+
+    /// Input for the next tick, no input once the recording has ended
+    pub fn next_input(&mut self) -> TickInput {
+        let input = self.inputs.get(self.tick).copied().unwrap_or_default();
+        self.tick += 1;
+        input
+    }
+
+    /// Fast-forward to `tick`. Returns true if it is behind the current tick, in which case
+    /// playback starts over and the fight has to be restarted.
+    pub fn seek(&mut self, tick: usize) -> bool {
+        let tick = tick.min(self.tick_count());
+        let restart = tick < self.tick;
+        if restart {
+            self.tick = 0;
+        }
+        self.seek_target = Some(tick);
+        restart
+    }
+}
+
+/// Resource with the statistics of the current or latest fight, shown on the victory screen
+#[derive(Resource, Debug, Clone, Default)]
+pub struct FightStats {
+    /// Simulated seconds since the fight started
+    pub duration: f32,
+    pub damage_dealt: f32,
+    pub damage_taken: f32,
+    /// Player projectiles fired, counting every projectile of a volley
+    pub shots_fired: u32,
+    /// Player projectiles that hit a boss at least once
+    pub shots_hit: u32,
+    /// Attacks whose damage was reduced by the shield
+    pub shield_blocks: u32,
+    pub parries: u32,
+    pub dashes: u32,
+}
+
+impl FightStats {
+    /// Fraction of fired shots that hit, 0 when nothing was fired
+    pub fn accuracy(&self) -> f32 {
+        if self.shots_fired == 0 {
+            return 0.0;
+        }
+        (self.shots_hit as f32 / self.shots_fired as f32).min(1.0)
+    }
+}
+
+/// Resource with the camera shake from hits on the player, decaying over time
+#[derive(Resource, Default)]
+pub struct ScreenShake {
+    /// Shake strength from 0 to 1
+    pub trauma: f32,
+    /// Camera offset for the current frame
+    pub offset: Vec2,
+}
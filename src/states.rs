@@ -5,7 +5,23 @@ use bevy::prelude::*;
 pub enum AppState {
     #[default]
     MainMenu,
+    Controls,
+    Settings,
+    LevelSelect,
     Game,
     Debug,
     GameOver,
+    Victory,
+}
+
+impl AppState {
+    /// Whether this state runs the boss fight simulation (normal game or debug mode)
+    pub fn is_gameplay(&self) -> bool {
+        matches!(self, AppState::Game | AppState::Debug)
+    }
+}
+
+/// Run condition for systems shared by the Game and Debug states
+pub fn in_gameplay_state(state: Option<Res<State<AppState>>>) -> bool {
+    state.is_some_and(|state| state.get().is_gameplay())
 }
@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use crate::components::{Boss, BossSkills, BossPhase, BulletSkills, Player, Projectile, ProjectileBundle, Faction, Collider};
+
+/// System to fire boss bullet patterns (rings, spirals, aimed bursts)
+pub fn boss_fire_bullets(
+    mut commands: Commands,
+    mut boss_query: Query<(Entity, &Transform, &Collider, &mut BulletSkills, Option<&BossSkills>, Option<&BossPhase>), With<Boss>>,
+    player_query: Query<&Transform, (With<Player>, Without<Boss>)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    time: Res<Time>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+
+    for (boss_entity, boss_transform, boss_collider, mut bullet_skills, boss_skills, boss_phase) in boss_query.iter_mut() {
+        // Only start volleys when the boss is not in the middle of a dash
+        let dash_ready = boss_skills.is_none_or(|skills| skills.dash_attack.is_ready());
+        let current_phase = boss_phase.map_or(0, |phase| phase.current);
+        let aim = (player_transform.translation - boss_transform.translation).truncate().normalize_or(Vec2::X);
+
+        for skill in bullet_skills.skills.iter_mut() {
+            if current_phase < skill.min_phase {
+                continue;
+            }
+
+            if skill.is_firing() {
+                skill.shot_timer.tick(time.delta());
+                if !skill.shot_timer.just_finished() {
+                    continue;
+                }
+            } else {
+                skill.cooldown.tick(time.delta());
+                if !skill.cooldown.finished() || !dash_ready {
+                    continue;
+                }
+                skill.start_volley();
+            }
+
+            let projectile_mesh = meshes.add(Circle::new(skill.projectile.radius));
+            let projectile_material = materials.add(ColorMaterial::from(skill.projectile.color()));
+
+            for direction in skill.next_round(aim) {
+                // Spawn at the edge of the boss body so bullets don't start inside it
+                let spawn_position = boss_transform.translation.truncate() + direction * boss_collider.bounding_radius();
+                commands.spawn((
+                    ProjectileBundle::new(
+                        Projectile::new(skill.projectile.damage, direction.extend(0.0), skill.projectile.lifetime, Faction::Enemy, boss_entity),
+                        skill.projectile.speed,
+                        Collider::circle(skill.projectile.radius),
+                        spawn_position.extend(0.9), // Below boss and player
+                        projectile_mesh.clone(),
+                        projectile_material.clone(),
+                    ),
+                    skill.projectile.on_block,
+                ));
+            }
+        }
+    }
+}
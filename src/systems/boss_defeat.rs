@@ -0,0 +1,23 @@
+use bevy::prelude::*;
+use crate::components::{Boss, Health};
+use crate::events::BossDefeated;
+use crate::resources::PendingBossSpawn;
+use crate::states::AppState;
+
+/// System to transition to the victory screen once every boss of the fight has been defeated
+pub fn check_boss_defeat(
+    mut defeated_events: EventReader<BossDefeated>,
+    boss_query: Query<&Health, With<Boss>>,
+    pending: Option<Res<PendingBossSpawn>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if defeated_events.read().count() == 0 {
+        return;
+    }
+
+    // Defeated bosses are despawned at the end of the tick
+    if pending.is_none() && boss_query.iter().all(|health| !health.is_alive()) {
+        info!("All bosses defeated! Transitioning to Victory screen...");
+        next_state.set(AppState::Victory);
+    }
+}
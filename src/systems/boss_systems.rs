@@ -1,29 +1,58 @@
 use bevy::prelude::*;
-use crate::components::{Boss, MineBoss, BossSkills, RotationAnimation, Player, Health, CollisionDamage, Collider, Invulnerability, Shield, DirectionIndicator, Speed};
+use crate::assets::BossMovementPattern;
+use crate::collision::{BossHitboxes, TouchingPairs};
+use crate::components::{Boss, MineBoss, BossSkills, BossPhase, AttackStage, RotationAnimation, Player, Health, CollisionDamage, Speed};
+use crate::events::{DamageEvent, DamageKind, BossPhaseChanged, ParryEvent};
+
+/// System to stagger bosses whose attack was parried, interrupting their dash
+pub fn stagger_boss_on_parry(
+    mut parry_events: EventReader<ParryEvent>,
+    mut boss_query: Query<&mut BossSkills, With<Boss>>,
+) {
+    for event in parry_events.read() {
+        if let Ok(mut skills) = boss_query.get_mut(event.attacker) {
+            skills.stagger(event.stagger_duration);
+            info!("Boss {:?} staggered by parry for {:.1}s", event.attacker, event.stagger_duration);
+        }
+    }
+}
 
 /// System to handle Mine boss AI and skill usage
 pub fn mine_boss_ai(
     mut boss_query: Query<(&mut BossSkills, &Transform), (With<MineBoss>, Without<Player>)>,
     player_query: Query<&Transform, (With<Player>, Without<MineBoss>)>,
-    time: Res<Time<Virtual>>,
+    time: Res<Time>,
 ) {
     if let Ok(player_transform) = player_query.single() {
         for (mut skills, boss_transform) in boss_query.iter_mut() {
             // Update skill cooldowns
             skills.dash_cooldown.tick(time.delta());
+            skills.chain_delay.tick(time.delta());
+            
+            // Advance the telegraphed dash lifecycle
+            match skills.dash_attack.tick(time.delta()) {
+                Some(AttackStage::Active) => {
+                    skills.start_dash(boss_transform.translation);
+                    info!("Mine boss windup finished, dashing");
+                }
+                Some(AttackStage::Ready) => {
+                    info!("Mine boss recovered from dash");
+                }
+                _ => {}
+            }
             
             // Check if boss should dash towards player
             if skills.can_dash() {
                 let distance_to_player = boss_transform.translation.distance(player_transform.translation);
                 
                 // Dash if player is not too close (avoid dash when already very close)
-                if distance_to_player > 100.0 {
-                    // Calculate direction to player
+                if distance_to_player > skills.dash_min_range {
+                    // Lock the dash direction now so the telegraph shows where the boss will go
                     let direction = (player_transform.translation - boss_transform.translation).normalize_or_zero();
                     let dash_target = boss_transform.translation + direction * skills.dash_distance;
                     
-                    skills.start_dash(dash_target, boss_transform.translation);
-                    info!("Mine boss starting dash towards player at distance: {:.1}, dash distance: {:.1}", distance_to_player, skills.dash_distance);
+                    skills.begin_dash_windup(dash_target);
+                    info!("Mine boss winding up dash towards player at distance: {:.1}, dash distance: {:.1}", distance_to_player, skills.dash_distance);
                 }
             }
         }
@@ -32,12 +61,12 @@ pub fn mine_boss_ai(
 
 /// System to handle boss dash movement and animation
 pub fn boss_dash_movement(
-    mut boss_query: Query<(&mut Transform, &mut BossSkills, &mut RotationAnimation, &Speed), With<MineBoss>>,
+    mut boss_query: Query<(&mut Transform, &mut BossSkills, &mut RotationAnimation, &Speed, Option<&BossPhase>), With<MineBoss>>,
     player_query: Query<&Transform, (With<Player>, Without<MineBoss>)>,
-    time: Res<Time<Virtual>>,
+    time: Res<Time>,
 ) {
     if let Ok(player_transform) = player_query.single() {
-        for (mut transform, mut skills, mut rotation, speed) in boss_query.iter_mut() {
+        for (mut transform, mut skills, mut rotation, speed, phase) in boss_query.iter_mut() {
             let delta = time.delta_secs();
             
             if skills.is_dashing {
@@ -46,9 +75,10 @@ pub fn boss_dash_movement(
                     rotation.start();
                 }
                 
-                // Calculate direction and move towards target
-                let direction = (skills.dash_target - transform.translation).normalize_or_zero();
-                let move_distance = skills.dash_speed * delta;
+                // Calculate direction and move towards target, without overshooting it on long ticks
+                let to_target = skills.dash_target - transform.translation;
+                let direction = to_target.normalize_or_zero();
+                let move_distance = (skills.dash_speed * delta).min(to_target.length());
                 
                 // Move towards target
                 transform.translation += direction * move_distance;
@@ -58,12 +88,26 @@ pub fn boss_dash_movement(
                     info!("Mine boss dash completed");
                     rotation.stop();
                 }
+            } else if !skills.dash_attack.is_ready() {
+                // Stand still during windup and recovery so the telegraph stays readable
+                rotation.stop();
             } else {
-                // Constant slow movement toward player when not dashing
-                let direction = (player_transform.translation - transform.translation).normalize_or_zero();
+                // Constant slow movement when not dashing, pattern depends on the boss phase
+                let to_player = player_transform.translation - transform.translation;
+                let movement = phase.map_or(BossMovementPattern::Chase, |phase| phase.movement);
+                let direction = match movement {
+                    BossMovementPattern::Chase => to_player.normalize_or_zero(),
+                    BossMovementPattern::Orbit { radius } => {
+                        // Circle around the player while correcting distance towards the orbit radius
+                        let radial = -to_player.normalize_or_zero();
+                        let tangent = Vec3::new(-radial.y, radial.x, 0.0);
+                        let radial_correction = ((radius - to_player.length()) / radius).clamp(-1.0, 1.0);
+                        (tangent + radial * radial_correction).normalize_or_zero()
+                    }
+                };
                 let move_distance = speed.value * delta;
                 
-                // Move towards player
+                // Move according to the movement pattern
                 transform.translation += direction * move_distance;
                 
                 // Stop rotation when not dashing
@@ -73,10 +117,37 @@ pub fn boss_dash_movement(
     }
 }
 
+/// System to advance the boss phase state machine when health crosses a threshold
+pub fn update_boss_phase(
+    mut boss_query: Query<(Entity, &Health, &mut BossPhase, Option<&mut BossSkills>, &mut Speed), With<Boss>>,
+    mut phase_events: EventWriter<BossPhaseChanged>,
+) {
+    for (entity, health, mut phase, skills, mut speed) in boss_query.iter_mut() {
+        let target_phase = phase.phase_for_health(health.percentage());
+        
+        // Phases only advance, health never goes back up
+        if target_phase > phase.current {
+            let definition = phase.phases[target_phase - 1].clone();
+            if let Some(mut skills) = skills {
+                skills.apply_phase(&definition);
+            }
+            speed.value = definition.move_speed;
+            phase.movement = definition.movement;
+            phase.current = target_phase;
+            
+            info!("Boss entered phase {} at {:.0}% health", target_phase + 1, health.percentage() * 100.0);
+            phase_events.write(BossPhaseChanged {
+                boss: entity,
+                phase: target_phase,
+            });
+        }
+    }
+}
+
 /// System to handle rotation animation
 pub fn boss_rotation_animation(
     mut boss_query: Query<(&mut Transform, &RotationAnimation), With<Boss>>,
-    time: Res<Time<Virtual>>,
+    time: Res<Time>,
 ) {
     for (mut transform, rotation) in boss_query.iter_mut() {
         if rotation.enabled {
@@ -86,136 +157,66 @@ pub fn boss_rotation_animation(
     }
 }
 
-/// System to handle collision between boss and player
+/// System to detect dash hits between boss and player
 pub fn boss_player_collision(
-    mut boss_query: Query<(&Transform, &mut BossSkills, &Collider), (With<MineBoss>, Without<Player>)>,
-    mut player_query: Query<(&Transform, &mut Health, &Collider, &Invulnerability), (With<Player>, Without<MineBoss>)>,
-    shield_query: Query<&Shield>,
-    indicator_query: Query<&Transform, (With<DirectionIndicator>, Without<Player>, Without<MineBoss>)>,
+    mut boss_query: Query<(Entity, &Transform, &mut BossSkills), (With<MineBoss>, Without<Player>)>,
+    player_query: Query<(Entity, &Transform), (With<Player>, Without<MineBoss>)>,
+    mut touching: TouchingPairs,
+    hitboxes: BossHitboxes,
+    mut damage_events: EventWriter<DamageEvent>,
 ) {
-    if let Ok((player_transform, mut player_health, player_collider, player_invulnerability)) = player_query.single_mut() {
-        for (boss_transform, mut skills, boss_collider) in boss_query.iter_mut() {
-            if skills.can_hit_player() {
-                let distance = boss_transform.translation.distance(player_transform.translation);
-                // Accurate circle-to-circle collision: sum of both radii
-                let collision_radius = player_collider.radius + boss_collider.radius;
+    if let Ok((player_entity, player_transform)) = player_query.single() {
+        let in_contact = bosses_touching_player(&mut touching, &hitboxes, player_entity);
+        for (boss_entity, boss_transform, mut skills) in boss_query.iter_mut() {
+            if skills.can_hit_player() && in_contact.contains(&boss_entity) {
+                damage_events.write(DamageEvent {
+                    source: boss_entity,
+                    target: player_entity,
+                    amount: skills.dash_damage,
+                    kind: DamageKind::Dash,
+                    direction: (player_transform.translation - boss_transform.translation).truncate().normalize_or_zero(),
+                });
                 
-                if distance < collision_radius {
-                    // Check if player is invulnerable
-                    if !player_invulnerability.is_active() {
-                        let mut damage = skills.dash_damage;
-                        let mut blocked_by_shield = false;
-
-                        // Check if shield can block this attack
-                        if let Ok(shield) = shield_query.single() {
-                            if let Ok(indicator_transform) = indicator_query.single() {
-                                if shield.is_active && shield.length > 0.0 {
-                                    // Calculate attack angle from player center to boss
-                                    let attack_direction = (boss_transform.translation - player_transform.translation).truncate();
-                                    let attack_angle = attack_direction.y.atan2(attack_direction.x);
-
-                                    // Calculate shield center angle from indicator position
-                                    let indicator_local_pos = indicator_transform.translation.truncate();
-                                    let shield_center_angle = indicator_local_pos.y.atan2(indicator_local_pos.x);
-
-                                    // Check if shield can block this attack
-                                    if shield.can_block_attack(attack_angle, shield_center_angle) {
-                                        damage *= 1.0 - shield.damage_reduction; // Apply damage reduction
-                                        blocked_by_shield = true;
-                                    }
-                                }
-                            }
-                        }
-
-                        // Apply damage to player
-                        player_health.take_damage(damage);
-                        
-                        if blocked_by_shield {
-                            info!("Shield blocked attack! Reduced damage: {:.1} (was {:.1}). Player health: {:.1}/{:.1}", 
-                                damage, skills.dash_damage, player_health.current, player_health.max);
-                        } else {
-                            info!("Mine boss hit player for {} damage! Player health: {:.1}/{:.1}", 
-                                damage, player_health.current, player_health.max);
-                        }
-                        
-                        if !player_health.is_alive() {
-                            info!("Player has died!");
-                            // TODO: Handle player death (restart, game over screen, etc.)
-                        }
-                    } else {
-                        info!("Player avoided damage due to invulnerability frames!");
-                    }
-                    
-                    // Mark that we've hit the player this dash (regardless of invulnerability)
-                    skills.mark_player_hit();
-                }
+                // Mark that we've hit the player this dash (regardless of invulnerability)
+                skills.mark_player_hit();
             }
         }
     }
 }
 
-/// System to handle collision damage between boss and player during constant movement
+/// System to detect contact damage between boss and player during constant movement
 pub fn boss_collision_damage(
-    mut boss_query: Query<(&Transform, &BossSkills, &mut CollisionDamage, &Collider), (With<MineBoss>, Without<Player>)>,
-    mut player_query: Query<(&Transform, &mut Health, &Collider, &Invulnerability), (With<Player>, Without<MineBoss>)>,
-    shield_query: Query<&Shield>,
-    indicator_query: Query<&Transform, (With<DirectionIndicator>, Without<Player>, Without<MineBoss>)>,
-    time: Res<Time<Virtual>>,
+    mut boss_query: Query<(Entity, &Transform, &BossSkills, &mut CollisionDamage), (With<MineBoss>, Without<Player>)>,
+    player_query: Query<(Entity, &Transform), (With<Player>, Without<MineBoss>)>,
+    mut touching: TouchingPairs,
+    hitboxes: BossHitboxes,
+    mut damage_events: EventWriter<DamageEvent>,
+    time: Res<Time>,
 ) {
-    if let Ok((player_transform, mut player_health, player_collider, player_invulnerability)) = player_query.single_mut() {
-        for (boss_transform, skills, mut collision_damage, boss_collider) in boss_query.iter_mut() {
-            // Only apply collision damage when NOT dashing (constant movement only)
-            if !skills.is_dashing {
-                let distance = boss_transform.translation.distance(player_transform.translation);
-                // Accurate circle-to-circle collision: sum of both radii
-                let collision_radius = player_collider.radius + boss_collider.radius;
-                
-                if distance < collision_radius {
-                    // Check if player is invulnerable
-                    if !player_invulnerability.is_active() {
-                        let current_time = time.elapsed_secs();
-                        if collision_damage.can_damage(current_time) {
-                            let mut damage = collision_damage.apply_damage(current_time);
-                            let mut blocked_by_shield = false;
+    if let Ok((player_entity, player_transform)) = player_query.single() {
+        let in_contact = bosses_touching_player(&mut touching, &hitboxes, player_entity);
+        for (boss_entity, boss_transform, skills, mut collision_damage) in boss_query.iter_mut() {
+            collision_damage.tick(time.delta_secs());
 
-                            // Check if shield can block this attack
-                            if let Ok(shield) = shield_query.single() {
-                                if let Ok(indicator_transform) = indicator_query.single() {
-                                    if shield.is_active && shield.length > 0.0 {
-                                        // Calculate attack angle from player center to boss
-                                        let attack_direction = (boss_transform.translation - player_transform.translation).truncate();
-                                        let attack_angle = attack_direction.y.atan2(attack_direction.x);
-
-                                        // Calculate shield center angle from indicator position
-                                        let indicator_local_pos = indicator_transform.translation.truncate();
-                                        let shield_center_angle = indicator_local_pos.y.atan2(indicator_local_pos.x);
-
-                                        // Check if shield can block this attack
-                                        if shield.can_block_attack(attack_angle, shield_center_angle) {
-                                            damage *= 1.0 - shield.damage_reduction; // Apply damage reduction
-                                            blocked_by_shield = true;
-                                        }
-                                    }
-                                }
-                            }
-
-                            player_health.take_damage(damage);
-                            
-                            if blocked_by_shield {
-                                info!("Shield blocked collision damage! Reduced damage: {:.1}. Player health: {:.1}/{:.1}", 
-                                    damage, player_health.current, player_health.max);
-                            } else {
-                                info!("Boss collision damage: {} damage! Player health: {:.1}/{:.1}", 
-                                    damage, player_health.current, player_health.max);
-                            }
-                            
-                            if !player_health.is_alive() {
-                                info!("Player has died from collision damage!");
-                            }
-                        }
-                    }
-                }
+            // Only apply collision damage when NOT dashing (constant movement only)
+            if !skills.is_dashing && in_contact.contains(&boss_entity) && collision_damage.can_damage() {
+                damage_events.write(DamageEvent {
+                    source: boss_entity,
+                    target: player_entity,
+                    amount: collision_damage.apply_damage(),
+                    kind: DamageKind::Contact,
+                    direction: (player_transform.translation - boss_transform.translation).truncate().normalize_or_zero(),
+                });
             }
         }
     }
-} 
\ No newline at end of file
+}
+
+/// Bosses whose body or parts touch the player this tick
+fn bosses_touching_player(touching: &mut TouchingPairs, hitboxes: &BossHitboxes, player_entity: Entity) -> Vec<Entity> {
+    touching
+        .read()
+        .filter_map(|pair| pair.matching(|entity| entity != player_entity, |entity| entity == player_entity))
+        .filter_map(|(entity, _)| hitboxes.boss_of(entity))
+        .collect()
+}
@@ -0,0 +1,100 @@
+use bevy::prelude::*;
+use crate::components::{Boss, BossSkills, AttackStage, DashTelegraph, Collider, DebugEntity};
+use crate::constants::ColorPalette;
+use crate::persistence::GameSettings;
+
+/// System to draw a telegraph lane towards the dash target while a boss winds up its dash
+pub fn update_dash_telegraphs(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    boss_query: Query<(Entity, &Transform, &BossSkills, &Collider), With<Boss>>,
+    telegraph_query: Query<(Entity, &DashTelegraph, &Mesh2d), Without<Boss>>,
+    settings: Res<GameSettings>,
+) {
+    // Update existing telegraphs, removing them once the windup is over
+    for (telegraph_entity, telegraph, mesh_handle) in telegraph_query.iter() {
+        match boss_query.get(telegraph.boss) {
+            Ok((_, boss_transform, skills, collider)) if skills.dash_attack.stage == AttackStage::Windup => {
+                let mesh = create_dash_telegraph_mesh(boss_transform.translation, skills, collider.bounding_radius(), settings.palette);
+                meshes.insert(&mesh_handle.0, mesh);
+            }
+            _ => {
+                commands.entity(telegraph_entity).despawn();
+            }
+        }
+    }
+
+    // Spawn telegraphs for bosses that just started winding up
+    for (boss_entity, boss_transform, skills, collider) in boss_query.iter() {
+        let has_telegraph = telegraph_query.iter().any(|(_, telegraph, _)| telegraph.boss == boss_entity);
+        if skills.dash_attack.stage == AttackStage::Windup && !has_telegraph {
+            let mesh = meshes.add(create_dash_telegraph_mesh(boss_transform.translation, skills, collider.bounding_radius(), settings.palette));
+            // White base color so the per-vertex colors define the telegraph look
+            let material = materials.add(ColorMaterial {
+                color: Color::WHITE,
+                alpha_mode: bevy::sprite::AlphaMode2d::Blend,
+                ..default()
+            });
+
+            commands.spawn((
+                DashTelegraph { boss: boss_entity },
+                Transform::from_translation(Vec3::new(boss_transform.translation.x, boss_transform.translation.y, 0.5)), // Below boss and player
+                Mesh2d(mesh),
+                MeshMaterial2d(material),
+                DebugEntity, // Mark as debug entity for cleanup
+            ));
+        }
+    }
+}
+
+/// Create a lane mesh from the boss towards its dash target.
+/// A faint lane shows the full dash path and a brighter fill grows with windup progress.
+fn create_dash_telegraph_mesh(boss_position: Vec3, skills: &BossSkills, half_width: f32, palette: ColorPalette) -> Mesh {
+    let to_target = (skills.dash_target - boss_position).truncate();
+    let direction = to_target.normalize_or_zero();
+    let length = to_target.length();
+    let side = direction.perp() * half_width;
+    let progress = skills.dash_attack.windup_progress();
+
+    let mut vertices = Vec::new();
+    let mut colors = Vec::new();
+    let mut indices = Vec::new();
+    let mut normals = Vec::new();
+    let mut uvs = Vec::new();
+
+    // Full lane first, then the progress fill drawn on top of it
+    let danger = palette.danger().to_srgba();
+    let lanes = [
+        (length, danger.with_alpha(0.15).to_f32_array()),
+        (length * progress, danger.with_alpha(0.45).to_f32_array()),
+    ];
+
+    for (lane_length, color) in lanes {
+        let base = vertices.len() as u32;
+        let end = direction * lane_length;
+
+        for (point, uv) in [(-side, [0.0, 0.0]), (side, [1.0, 0.0]), (end - side, [0.0, 1.0]), (end + side, [1.0, 1.0])] {
+            vertices.push([point.x, point.y, 0.0]);
+            colors.push(color);
+            normals.push([0.0, 0.0, 1.0]);
+            uvs.push(uv);
+        }
+
+        // Two counter-clockwise triangles per lane
+        indices.extend_from_slice(&[base, base + 2, base + 1, base + 1, base + 2, base + 3]);
+    }
+
+    let mut mesh = Mesh::new(
+        bevy::render::render_resource::PrimitiveTopology::TriangleList,
+        bevy::render::render_asset::RenderAssetUsages::MAIN_WORLD | bevy::render::render_asset::RenderAssetUsages::RENDER_WORLD,
+    );
+
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, vertices);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    mesh.insert_indices(bevy::render::mesh::Indices::U32(indices));
+
+    mesh
+}
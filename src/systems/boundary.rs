@@ -1,22 +1,29 @@
 use bevy::prelude::*;
-use crate::components::{BoundedMovement, BoundaryVisual, EdgeWarning, EdgeType, Player, PlayerDash};
+use crate::components::{BoundedMovement, BoundaryVisual, BossSkills, EdgeWarning, EdgeType, Player, PlayerDash};
 use crate::constants::{GameBoundaries, AppColors};
+use crate::assets::EncounterSequence;
+use crate::persistence::GameSettings;
+use crate::resources::{ActiveEncounter, EncounterAssets};
 
 /// System to enforce boundary constraints on all bounded entities
+#[allow(clippy::collapsible_if)]
 pub fn enforce_boundaries(
-    mut bounded_query: Query<(&mut Transform, Option<&mut PlayerDash>), With<BoundedMovement>>,
+    mut bounded_query: Query<(&mut Transform, Option<&mut PlayerDash>, Option<&mut BossSkills>), With<BoundedMovement>>,
 ) {
-    for (mut transform, dash_opt) in bounded_query.iter_mut() {
+    for (mut transform, dash_opt, skills_opt) in bounded_query.iter_mut() {
         let original_pos = transform.translation;
         let clamped_pos = GameBoundaries::clamp_position(original_pos);
         
-        // If position was clamped and this is a player with dash, reset the dash to prevent getting stuck
+        // If position was clamped, end any dash so it doesn't keep pushing against the edge
         if original_pos != clamped_pos {
             if let Some(mut dash) = dash_opt {
                 if dash.is_dashing {
                     dash.reset_dash(); // Properly reset all dash state when hitting boundary
                 }
             }
+            if let Some(mut skills) = skills_opt && skills.is_dashing {
+                skills.end_dash();
+            }
             transform.translation = clamped_pos;
         }
     }
@@ -28,6 +35,9 @@ pub fn spawn_boundary_visuals(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     existing_boundaries: Query<Entity, With<BoundaryVisual>>,
+    encounter: Option<Res<ActiveEncounter>>,
+    encounter_assets: Option<Res<EncounterAssets>>,
+    sequences: Res<Assets<EncounterSequence>>,
 ) {
     // Only spawn if boundaries don't exist yet
     if !existing_boundaries.is_empty() {
@@ -42,15 +52,19 @@ pub fn spawn_boundary_visuals(
     // Black material for out-of-bounds areas
     let black_material = materials.add(Color::BLACK);
     
-    // Gray material for the game area background
-    let gray_material = materials.add(AppColors::BACKGROUND);
+    // Floor material for the game area background, from the encounter's arena
+    let floor_color = encounter
+        .zip(encounter_assets)
+        .and_then(|(encounter, assets)| sequences.get(&assets.sequence)?.encounters.get(encounter.index).map(|encounter| encounter.arena.floor_color()))
+        .unwrap_or(AppColors::BACKGROUND);
+    let floor_material = materials.add(floor_color);
     
     // Create the game area background first (behind everything)
     let game_area_mesh = meshes.add(Rectangle::new(world_width, world_height));
     commands.spawn((
         Transform::from_translation(Vec3::new(0.0, 0.0, -2.0)), // Behind boundaries
         Mesh2d(game_area_mesh),
-        MeshMaterial2d(gray_material),
+        MeshMaterial2d(floor_material),
         BoundaryVisual,
     ));
     
@@ -199,6 +213,7 @@ pub fn update_edge_warnings(
     player_query: Query<&Transform, With<Player>>,
     mut warning_query: Query<(&EdgeWarning, &mut BackgroundColor)>,
     windows: Query<&Window>,
+    settings: Res<GameSettings>,
 ) {
     if let Ok(player_transform) = player_query.single() {
         let player_pos = player_transform.translation;
@@ -228,9 +243,9 @@ pub fn update_edge_warnings(
                 0.0
             };
             
-            // Apply intensity to alpha channel with a subtle red bloom
+            // Apply intensity to alpha channel with a subtle bloom of the danger color
             let alpha = (warning_intensity * 0.3).min(0.3); // Cap at 30% opacity for subtlety
-            *background_color = BackgroundColor(Color::srgba(1.0, 0.0, 0.0, alpha));
+            *background_color = BackgroundColor(settings.palette.danger().with_alpha(alpha));
         }
     }
 }
@@ -1,12 +1,15 @@
 use bevy::prelude::*;
 use crate::components::{Player};
 use crate::constants::GameBoundaries;
+use crate::resources::ScreenShake;
 
-/// System to make camera follow the player while respecting boundaries
+/// System to make camera follow the player while respecting boundaries, offset by the screen shake
+#[allow(clippy::collapsible_if)]
 pub fn camera_follow_player(
     player_query: Query<&Transform, (With<Player>, Without<Camera2d>)>,
     mut camera_query: Query<&mut Transform, (With<Camera2d>, Without<Player>)>,
     windows: Query<&Window>,
+    shake: Res<ScreenShake>,
 ) {
     if let Ok(player_transform) = player_query.single() {
         if let Ok(mut camera_transform) = camera_query.single_mut() {
@@ -35,10 +38,10 @@ pub fn camera_follow_player(
                 );
                 
                 // Set camera position directly to target for exact following
-                camera_transform.translation = clamped_target;
+                camera_transform.translation = clamped_target + shake.offset.extend(0.0);
             } else {
                 // Fallback: set camera position directly to player position
-                camera_transform.translation = target_position;
+                camera_transform.translation = target_position + shake.offset.extend(0.0);
             }
         }
     }
@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+use crate::resources::CursorWorldPosition;
+
+/// System to convert the window cursor into a world position for aiming
+pub fn update_cursor_world_position(
+    mut cursor: ResMut<CursorWorldPosition>,
+    window_query: Query<&Window>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+) {
+    // Headless apps have no window, their cursor position is injected directly
+    let (Ok(window), Ok((camera, camera_transform))) = (window_query.single(), camera_query.single()) else {
+        return;
+    };
+
+    cursor.position = window
+        .cursor_position()
+        .and_then(|cursor_position| camera.viewport_to_world_2d(camera_transform, cursor_position).ok());
+}
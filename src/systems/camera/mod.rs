@@ -1,3 +1,7 @@
 mod camera_follow;
+mod cursor;
+mod screen_shake;
 
 pub use camera_follow::*;
+pub use cursor::*;
+pub use screen_shake::*;
@@ -0,0 +1,43 @@
+use bevy::prelude::*;
+use crate::components::Player;
+use crate::events::DamageEvent;
+use crate::persistence::GameSettings;
+use crate::resources::ScreenShake;
+
+/// Camera offset in pixels at full trauma and full screen shake setting
+const MAX_SHAKE_OFFSET: f32 = 18.0;
+/// Trauma lost per second
+const TRAUMA_DECAY: f32 = 1.5;
+/// Damage that gives full trauma
+const FULL_TRAUMA_DAMAGE: f32 = 40.0;
+
+/// System to add trauma when the player is hit, in proportion to the damage
+pub fn add_screen_shake_on_damage(
+    mut damage_events: EventReader<DamageEvent>,
+    player_query: Query<(), With<Player>>,
+    mut shake: ResMut<ScreenShake>,
+) {
+    for event in damage_events.read() {
+        if player_query.contains(event.target) {
+            shake.trauma = (shake.trauma + event.amount / FULL_TRAUMA_DAMAGE).min(1.0);
+        }
+    }
+}
+
+/// System to decay trauma and compute this frame's camera offset, scaled by the screen shake setting
+pub fn update_screen_shake(
+    mut shake: ResMut<ScreenShake>,
+    settings: Res<GameSettings>,
+    time: Res<Time>,
+) {
+    shake.trauma = (shake.trauma - TRAUMA_DECAY * time.delta_secs()).max(0.0);
+
+    // Squared trauma so small hits barely shake, summed sines as a cheap smooth noise
+    let strength = shake.trauma * shake.trauma * settings.screen_shake * MAX_SHAKE_OFFSET;
+    let t = time.elapsed_secs();
+    let noise = Vec2::new(
+        (t * 47.0).sin() + 0.5 * (t * 29.0).sin(),
+        (t * 53.0).cos() + 0.5 * (t * 31.0).cos(),
+    ) / 1.5;
+    shake.offset = noise * strength;
+}
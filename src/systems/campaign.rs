@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use crate::assets::{EncounterSequence, Loadout};
+use crate::persistence::{CampaignProgress, Profile};
+use crate::resources::{ActiveEncounter, Difficulty, EncounterAssets, FightStats, PendingBossSpawn, ReplayPlayback, SelectedEncounter};
+use crate::states::AppState;
+
+/// System to start loading the campaign's encounter sequence, and with it every boss definition
+pub fn load_encounter_sequence(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(EncounterAssets {
+        sequence: asset_server.load("encounters/campaign.encounters.ron"),
+    });
+}
+
+/// System to start the selected encounter, or the one being replayed: decides the loadout and difficulty and queues the boss.
+/// Debug mode fights the selected encounter with every weapon and no upgrades.
+pub fn begin_encounter(
+    mut commands: Commands,
+    encounter_assets: Res<EncounterAssets>,
+    sequences: Res<Assets<EncounterSequence>>,
+    selected: Res<SelectedEncounter>,
+    profile: Res<Profile>,
+    difficulty: Res<Difficulty>,
+    playback: Option<Res<ReplayPlayback>>,
+    state: Res<State<AppState>>,
+) {
+    let sequence = sequences.get(&encounter_assets.sequence);
+    let active = match playback {
+        Some(playback) => ActiveEncounter {
+            index: playback.encounter,
+            loadout: playback.loadout.clone(),
+            difficulty: playback.difficulty,
+            is_counted: false,
+        },
+        None => ActiveEncounter {
+            index: selected.index,
+            loadout: match state.get() {
+                AppState::Debug => Loadout::default(),
+                _ => sequence.map(|sequence| sequence.loadout(&profile.campaign.beaten)).unwrap_or_default(),
+            },
+            difficulty: *difficulty,
+            is_counted: *state.get() == AppState::Game,
+        },
+    };
+
+    match sequence.and_then(|sequence| sequence.encounters.get(active.index)) {
+        Some(encounter) => {
+            info!("Starting encounter '{}' on {}", encounter.name, active.difficulty.label());
+            commands.insert_resource(PendingBossSpawn {
+                definition: encounter.boss_definition.clone(),
+                position: Vec3::new(encounter.boss_position.0, encounter.boss_position.1, 1.0),
+                difficulty: active.difficulty,
+            });
+        }
+        None => error!("Encounter {} is not loaded, starting without a boss", active.index),
+    }
+    commands.insert_resource(active);
+}
+
+/// System to mark the encounter just won as beaten, unlocking the next one and its rewards, and keep its personal best
+/// at the difficulty it was fought on
+pub fn record_encounter_victory(
+    active: Option<Res<ActiveEncounter>>,
+    encounter_assets: Res<EncounterAssets>,
+    sequences: Res<Assets<EncounterSequence>>,
+    stats: Res<FightStats>,
+    mut profile: ResMut<Profile>,
+) {
+    let Some(active) = active.filter(|active| active.is_counted) else {
+        return;
+    };
+    let Some(encounter) = sequences
+        .get(&encounter_assets.sequence)
+        .and_then(|sequence| sequence.encounters.get(active.index))
+    else {
+        return;
+    };
+    if !profile.campaign.beaten.contains(&encounter.name) {
+        info!("Encounter '{}' beaten for the first time", encounter.name);
+    }
+    if profile.record_victory(&encounter.name, active.difficulty, &stats) {
+        info!("New personal best against '{}' on {}", encounter.name, active.difficulty.label());
+    }
+}
+
+/// Index of the first encounter not beaten yet, to continue the campaign from; the last one once all are beaten
+pub fn next_campaign_encounter(sequence: &EncounterSequence, progress: &CampaignProgress) -> usize {
+    sequence.encounters
+        .iter()
+        .position(|encounter| !progress.beaten.contains(&encounter.name))
+        .unwrap_or(sequence.encounters.len().saturating_sub(1))
+}
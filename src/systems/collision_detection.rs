@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use bevy::ecs::entity::EntityHashMap;
+use std::collections::BTreeMap;
+use crate::collision::{ActiveCollisions, CollisionLayers, CollisionMatrix, CollisionPair, SpatialIndex, WorldCollider, WorldColliders};
+use crate::events::{CollisionEnded, CollisionOngoing, CollisionStarted};
+
+/// System to find every pair of colliders whose layers collide that touched at some point during the tick,
+/// following fast movers along their path so they can't pass through each other between ticks, and report
+/// which pairs started touching, still touch and stopped touching since the previous tick
+pub fn detect_collisions(
+    spatial_index: Res<SpatialIndex>,
+    matrix: Res<CollisionMatrix>,
+    colliders: WorldColliders,
+    layers_query: Query<&CollisionLayers>,
+    mut active: ResMut<ActiveCollisions>,
+    mut started_events: EventWriter<CollisionStarted>,
+    mut ongoing_events: EventWriter<CollisionOngoing>,
+    mut ended_events: EventWriter<CollisionEnded>,
+) {
+    // Colliders on layers that collide with anything, placed in the world
+    let placed: EntityHashMap<(WorldCollider, CollisionLayers)> = colliders
+        .iter()
+        .filter_map(|collider| {
+            let layers = *layers_query.get(collider.entity).ok()?;
+            matrix.collides_with_any(layers).then_some((collider.entity, (collider, layers)))
+        })
+        .collect();
+    let farthest_move = placed.values().map(|(collider, _)| collider.displacement.length()).fold(0.0, f32::max);
+
+    // Touching pairs with the fraction of the tick at which they first touched
+    let mut touching = BTreeMap::new();
+    for (&entity, (collider, layers)) in &placed {
+        // The index holds where colliders ended the tick, so look as far as either could have come from
+        let reach = collider.shape.bounding_radius() + collider.displacement.length() + farthest_move;
+        for other in spatial_index.query_circle(collider.shape.center(), reach) {
+            // Each pair is found from its lower entity, which also skips the collider itself
+            if other.entity <= entity {
+                continue;
+            }
+            let Some((other_collider, other_layers)) = placed.get(&other.entity) else {
+                continue;
+            };
+            if !matrix.collides(*layers, *other_layers) {
+                continue;
+            }
+            let relative_displacement = collider.displacement - other_collider.displacement;
+            if let Some(time_of_impact) = collider.shape.time_of_impact(relative_displacement, &other_collider.shape) {
+                touching.insert(CollisionPair::new(entity, other.entity), time_of_impact);
+            }
+        }
+    }
+
+    for (&pair, &time_of_impact) in &touching {
+        if active.contains(&pair) {
+            ongoing_events.write(CollisionOngoing { pair });
+        } else {
+            started_events.write(CollisionStarted { pair, time_of_impact });
+        }
+    }
+    let previous = active.replace(touching.into_keys().collect());
+    for pair in previous {
+        if !active.contains(&pair) {
+            ended_events.write(CollisionEnded { pair });
+        }
+    }
+}
+
+/// System to forget the collisions of a fight that ended
+pub fn clear_collisions(mut active: ResMut<ActiveCollisions>, mut spatial_index: ResMut<SpatialIndex>) {
+    active.clear();
+    spatial_index.clear();
+}
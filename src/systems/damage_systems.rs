@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+use crate::components::{Player, Boss, Health, Invulnerability, Resistances, Burning, Shield, DirectionIndicator};
+use crate::events::{DamageEvent, DamageKind, ParryEvent, BossDefeated};
+use crate::resources::FightStats;
+
+/// System to resolve all damage events in one place (i-frames, shield, resistances, death)
+pub fn resolve_damage_events(
+    mut commands: Commands,
+    mut damage_events: EventReader<DamageEvent>,
+    mut parry_events: EventWriter<ParryEvent>,
+    mut defeated_events: EventWriter<BossDefeated>,
+    mut target_query: Query<(&Transform, &mut Health, Option<&Invulnerability>, Option<&Resistances>, Option<&Children>, Has<Player>, Has<Boss>)>,
+    shield_query: Query<&Shield>,
+    indicator_query: Query<&Transform, (With<DirectionIndicator>, Without<Health>)>,
+    mut stats: ResMut<FightStats>,
+) {
+    for event in damage_events.read() {
+        let Ok((target_transform, mut health, invulnerability, resistances, children, is_player, is_boss)) = target_query.get_mut(event.target) else {
+            continue;
+        };
+
+        // Already dead targets are awaiting despawn
+        if !health.is_alive() {
+            continue;
+        }
+
+        // Check if target is invulnerable
+        if invulnerability.is_some_and(|invulnerability| invulnerability.is_active()) {
+            info!("{:?} damage from {:?} avoided due to invulnerability frames!", event.kind, event.source);
+            continue;
+        }
+
+        let mut damage = event.amount;
+        let mut blocked_by_shield = false;
+
+        // Check if a child shield can parry or block this attack
+        if let Some(children) = children {
+            let shield = children.iter().find_map(|child| shield_query.get(child).ok());
+            let indicator = children.iter().find_map(|child| indicator_query.get(child).ok());
+
+            if let (Some(shield), Some(indicator_transform)) = (shield, indicator) {
+                // Attack comes from the opposite of its travel direction
+                if shield.parries_from_direction(-event.direction, target_transform.rotation, indicator_transform.translation) {
+                    // Perfect parry fully negates the hit
+                    parry_events.write(ParryEvent {
+                        player: event.target,
+                        attacker: event.source,
+                        kind: event.kind,
+                        position: target_transform.translation.truncate(),
+                        stagger_duration: shield.parry_stagger_duration,
+                    });
+                    info!("Parried {:?} attack from {:?}!", event.kind, event.source);
+                    continue;
+                }
+
+                if shield.blocks_from_direction(-event.direction, target_transform.rotation, indicator_transform.translation) {
+                    damage *= 1.0 - shield.damage_reduction; // Apply damage reduction
+                    blocked_by_shield = true;
+                    stats.shield_blocks += 1;
+                }
+            }
+        }
+
+        // Apply resistances
+        if let Some(resistances) = resistances {
+            damage *= resistances.multiplier(event.kind);
+        }
+
+        let health_before = health.current;
+        health.take_damage(damage);
+        if is_player {
+            stats.damage_taken += health_before - health.current;
+        } else if is_boss {
+            stats.damage_dealt += health_before - health.current;
+        }
+
+        if blocked_by_shield {
+            info!("Shield blocked {:?} attack from {:?}! Reduced damage: {:.1} (was {:.1}). Health: {:.1}/{:.1}",
+                event.kind, event.source, damage, event.amount, health.current, health.max);
+        } else {
+            info!("{:?} from {:?} hit {:?} for {:.1} damage! Health: {:.1}/{:.1}",
+                event.kind, event.source, event.target, damage, health.current, health.max);
+        }
+
+        if !health.is_alive() {
+            if is_player {
+                // Player death is handled by check_player_death
+                info!("Player has died!");
+            } else {
+                info!("Target has been defeated! Despawning entity.");
+                if is_boss {
+                    defeated_events.write(BossDefeated { boss: event.target });
+                }
+                commands.entity(event.target).despawn();
+            }
+        }
+    }
+}
+
+/// System to apply damage over time from burning effects
+pub fn update_burning(
+    mut commands: Commands,
+    mut burning_query: Query<(Entity, &mut Burning)>,
+    mut damage_events: EventWriter<DamageEvent>,
+    time: Res<Time>,
+) {
+    for (entity, mut burning) in burning_query.iter_mut() {
+        if let Some(amount) = burning.update(time.delta()) {
+            damage_events.write(DamageEvent {
+                source: burning.source,
+                target: entity,
+                amount,
+                kind: DamageKind::Burn,
+                direction: Vec2::ZERO,
+            });
+        }
+
+        if burning.is_finished() {
+            commands.entity(entity).remove::<Burning>();
+        }
+    }
+}
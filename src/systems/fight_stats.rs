@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+use crate::events::ParryEvent;
+use crate::resources::FightStats;
+
+/// System to start a fight with empty statistics
+pub fn reset_fight_stats(mut stats: ResMut<FightStats>) {
+    *stats = FightStats::default();
+}
+
+/// System to advance the fight duration by one tick
+pub fn tick_fight_clock(mut stats: ResMut<FightStats>, time: Res<Time>) {
+    stats.duration += time.delta_secs();
+}
+
+/// System to count the player's perfect parries
+pub fn count_parries(mut stats: ResMut<FightStats>, mut parry_events: EventReader<ParryEvent>) {
+    stats.parries += parry_events.read().count() as u32;
+}
@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use bevy::input::mouse::{MouseMotion, MouseWheel};
+use crate::input::{ActionState, FixedActionState, AimTarget, InputBindings, InputDevices, InputSlot, StickDeadZones, WeaponRequest};
+use crate::persistence::ConfigStorage;
+use crate::resources::CursorWorldPosition;
+
+/// System to load the saved input bindings, keeping the defaults if there is no config file
+pub fn load_input_bindings(
+    mut bindings: ResMut<InputBindings>,
+    storage: Res<ConfigStorage>,
+) {
+    if let Some(saved) = storage.load::<InputBindings>(InputBindings::FILE_NAME) {
+        *bindings = saved.with_missing_defaults();
+        info!("Loaded input bindings");
+    }
+}
+
+/// System to translate keyboard, mouse, gamepad and cursor state into `ActionState` through the bindings.
+/// Aim follows the right stick while it is pushed and switches back to the cursor when the mouse moves.
+/// The number keys select a weapon and the mouse wheel cycles through them.
+pub fn update_action_state(
+    mut action_state: ResMut<ActionState>,
+    bindings: Res<InputBindings>,
+    devices: InputDevices,
+    cursor: Res<CursorWorldPosition>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+) {
+    const NUMBER_KEYS: [KeyCode; 9] = [
+        KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3,
+        KeyCode::Digit4, KeyCode::Digit5, KeyCode::Digit6,
+        KeyCode::Digit7, KeyCode::Digit8, KeyCode::Digit9,
+    ];
+
+    action_state.begin_frame();
+
+    let pressed = |slot| bindings.is_pressed(slot, &devices);
+
+    for slot in InputSlot::ALL {
+        if let Some(action) = slot.action() {
+            action_state.set_pressed(action, pressed(slot));
+        }
+    }
+
+    let mut move_axis = StickDeadZones::apply(bindings.dead_zones.move_stick, devices.left_stick());
+    if pressed(InputSlot::MoveUp) {
+        move_axis += Vec2::Y;
+    }
+    if pressed(InputSlot::MoveDown) {
+        move_axis -= Vec2::Y;
+    }
+    if pressed(InputSlot::MoveLeft) {
+        move_axis -= Vec2::X;
+    }
+    if pressed(InputSlot::MoveRight) {
+        move_axis += Vec2::X;
+    }
+    action_state.set_move_axis(move_axis);
+
+    let mouse_moved = mouse_motion.read().count() > 0;
+    let stick_aim = StickDeadZones::apply(bindings.dead_zones.aim_stick, devices.right_stick()).try_normalize();
+    if let Some(direction) = stick_aim {
+        action_state.set_aim(Some(AimTarget::Direction(direction)));
+    } else if mouse_moved || !matches!(action_state.aim(), Some(AimTarget::Direction(_))) {
+        // Keep facing the last stick direction until the mouse takes over
+        action_state.set_aim(cursor.position.map(AimTarget::Position));
+    }
+
+    let scroll: f32 = mouse_wheel.read().map(|event| event.y).sum();
+    let scroll_request = match scroll {
+        scroll if scroll > 0.0 => Some(WeaponRequest::Next),
+        scroll if scroll < 0.0 => Some(WeaponRequest::Previous),
+        _ => None,
+    };
+    let weapon_request = NUMBER_KEYS
+        .iter()
+        .position(|key| devices.keyboard.just_pressed(*key))
+        .map(WeaponRequest::Select)
+        .or(scroll_request);
+    action_state.set_weapon_request(weapon_request);
+}
+
+/// System to hand this frame's actions to the simulation, which reads them on its next tick
+pub fn buffer_fixed_actions(
+    action_state: Res<ActionState>,
+    mut fixed_action_state: ResMut<FixedActionState>,
+    time: Res<Time<Virtual>>,
+) {
+    fixed_action_state.buffer_frame(&action_state, time.is_paused());
+}
+
+/// System to advance the simulation's view of the actions at the start of each tick
+pub fn begin_fixed_action_tick(mut fixed_action_state: ResMut<FixedActionState>) {
+    fixed_action_state.begin_tick();
+}
@@ -1,6 +1,10 @@
 use bevy::prelude::*;
 use crate::components::{MenuButton};
+use crate::assets::EncounterSequence;
+use crate::persistence::{ConfigStorage, Profile};
+use crate::resources::{Difficulty, EncounterAssets, LastReplay, SelectedEncounter};
 use crate::states::AppState;
+use crate::systems::{find_last_replay, next_campaign_encounter, watch_replay};
 
 /// System to handle main menu button interactions
 pub fn handle_menu_buttons(
@@ -10,20 +14,74 @@ pub fn handle_menu_buttons(
     >,
     mut next_state: ResMut<NextState<AppState>>,
     mut exit: EventWriter<AppExit>,
+    mut commands: Commands,
+    mut last_replay: ResMut<LastReplay>,
+    storage: Res<ConfigStorage>,
+    encounter_assets: Res<EncounterAssets>,
+    sequences: Res<Assets<EncounterSequence>>,
+    mut profile: ResMut<Profile>,
+    mut difficulty: ResMut<Difficulty>,
+    mut selected: ResMut<SelectedEncounter>,
 ) {
     for (interaction, menu_button) in &mut interaction_query {
         if *interaction == Interaction::Pressed {
             match menu_button {
                 MenuButton::StartGame => {
+                    // Continue the campaign from the first encounter not beaten yet
+                    if let Some(sequence) = sequences.get(&encounter_assets.sequence) {
+                        selected.index = next_campaign_encounter(sequence, &profile.campaign);
+                    }
                     next_state.set(AppState::Game);
                 }
+                MenuButton::Difficulty => {
+                    // Remember the choice in the profile for the next session
+                    *difficulty = difficulty.next();
+                    profile.difficulty = *difficulty;
+                }
+                MenuButton::LevelSelect => {
+                    next_state.set(AppState::LevelSelect);
+                }
                 MenuButton::DebugMode => {
                     next_state.set(AppState::Debug);
                 }
+                MenuButton::Controls => {
+                    next_state.set(AppState::Controls);
+                }
+                MenuButton::Settings => {
+                    next_state.set(AppState::Settings);
+                }
+                MenuButton::WatchReplay => {
+                    match find_last_replay(&mut last_replay, &storage, sequences.get(&encounter_assets.sequence)) {
+                        Some(replay) => watch_replay(&mut commands, &mut next_state, &replay),
+                        None => info!("No replay to watch yet"),
+                    }
+                }
                 MenuButton::ExitToDesktop => {
                     exit.write(AppExit::Success);
                 }
             }
         }
     }
-}
\ No newline at end of file
+}
+
+/// Text of the main menu's difficulty button
+pub fn difficulty_button_label(difficulty: Difficulty) -> String {
+    format!("Difficulty: {}", difficulty.label())
+}
+
+/// System to show the current difficulty on the main menu's difficulty button
+pub fn update_difficulty_button(
+    difficulty: Res<Difficulty>,
+    button_query: Query<(&MenuButton, &Children)>,
+    mut text_query: Query<&mut Text>,
+) {
+    for (button, children) in &button_query {
+        if matches!(button, MenuButton::Difficulty) {
+            for child in children.iter() {
+                if let Ok(mut text) = text_query.get_mut(child) {
+                    **text = difficulty_button_label(*difficulty);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use bevy::prelude::*;
+use crate::components::{Boss, BossSkills, MineSkill, MineSpike, Mine, MineBundle, ExplosionFlash, Player, Collider};
+use crate::collision::{CollisionLayer, CollisionLayers, TouchingPairs};
+use crate::events::{DamageEvent, DamageKind};
+use crate::persistence::GameSettings;
+
+/// System to detach spikes from the boss and drop them as mines
+pub fn boss_lay_mines(
+    mut commands: Commands,
+    mut boss_query: Query<(Entity, &Transform, &mut MineSkill, Option<&BossSkills>, &Children), With<Boss>>,
+    mut spike_query: Query<(&Transform, &Mesh2d, &mut MineSpike, &mut Visibility, &mut CollisionLayers)>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    time: Res<Time>,
+) {
+    for (boss_entity, boss_transform, mut skill, boss_skills, children) in boss_query.iter_mut() {
+        skill.cooldown.tick(time.delta());
+        
+        // Only lay mines when the boss is not in the middle of a dash
+        let dash_ready = boss_skills.is_none_or(|skills| skills.dash_attack.is_ready());
+        if !skill.cooldown.finished() || !dash_ready {
+            continue;
+        }
+        
+        let attached: Vec<Entity> = children
+            .iter()
+            .filter(|child| spike_query.get(*child).is_ok_and(|(_, _, spike, ..)| spike.attached))
+            .collect();
+        if attached.is_empty() {
+            continue;
+        }
+        
+        // Spread the detached spikes evenly around the boss
+        let mines_to_lay = (skill.mines_per_cast as usize).min(attached.len());
+        let stride = (attached.len() / mines_to_lay.max(1)).max(1);
+        
+        for spike_entity in attached.into_iter().step_by(stride).take(mines_to_lay) {
+            if let Ok((spike_transform, spike_mesh, mut spike, mut visibility, mut layers)) = spike_query.get_mut(spike_entity) {
+                // Launch the mine outward along the spike direction, the spike moving with the simulated boss
+                let spike_position = boss_transform.mul_transform(*spike_transform).translation;
+                let outward = (spike_position - boss_transform.translation).truncate().normalize_or_zero();
+                let position = boss_transform.translation.truncate() + outward * skill.scatter_distance;
+                
+                // Each mine has its own material so it can change color when armed
+                let mine_material = materials.add(Color::srgb(0.4, 0.25, 0.15));
+                commands.spawn((
+                    MineBundle::new(
+                        boss_entity,
+                        &skill,
+                        position.extend(0.8), // Below boss and player
+                        spike_mesh.0.clone(),
+                        mine_material,
+                    ),
+                ));
+                
+                spike.detach(skill.regrow_time);
+                *visibility = Visibility::Hidden;
+                *layers = CollisionLayers::NONE;
+            }
+        }
+        
+        skill.cooldown.reset();
+        info!("Boss laid {} mines", mines_to_lay);
+    }
+}
+
+/// System to regrow detached spikes on the boss
+pub fn regrow_mine_spikes(
+    mut spike_query: Query<(&mut MineSpike, &mut Visibility, &mut CollisionLayers)>,
+    time: Res<Time>,
+) {
+    for (mut spike, mut visibility, mut layers) in spike_query.iter_mut() {
+        if spike.update_regrowth(time.delta()) {
+            *visibility = Visibility::Inherited;
+            *layers = CollisionLayers::new(CollisionLayer::Boss);
+        }
+    }
+}
+
+/// System to arm mines and show their armed state
+pub fn arm_mines(
+    mut mine_query: Query<(&mut Mine, &MeshMaterial2d<ColorMaterial>)>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    time: Res<Time>,
+    settings: Res<GameSettings>,
+) {
+    for (mut mine, material_handle) in mine_query.iter_mut() {
+        if mine.update_arming(time.delta()) && let Some(material) = materials.get_mut(&material_handle.0) {
+            material.color = settings.palette.danger(); // Danger color when armed
+        }
+    }
+}
+
+/// System to explode armed mines when the player comes within trigger radius
+pub fn mine_proximity_trigger(
+    mut commands: Commands,
+    mine_query: Query<(&Transform, &Mine)>,
+    player_query: Query<(Entity, &Transform, &Collider), With<Player>>,
+    mut touching: TouchingPairs,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    if let Ok((player_entity, player_transform, player_collider)) = player_query.single() {
+        // Mines whose trigger radius the player is inside
+        for pair in touching.read() {
+            let Some((mine_entity, _)) = pair.matching(|entity| mine_query.contains(entity), |entity| entity == player_entity) else {
+                continue;
+            };
+            let Ok((mine_transform, mine)) = mine_query.get(mine_entity) else {
+                continue;
+            };
+            if !mine.is_armed() {
+                continue;
+            }
+            
+            let offset = (player_transform.translation - mine_transform.translation).truncate();
+            let distance = offset.length();
+            
+            // Explosion damages the player if they are inside the blast radius
+            if distance < mine.explosion_radius + player_collider.bounding_radius() {
+                damage_events.write(DamageEvent {
+                    source: mine.owner,
+                    target: player_entity,
+                    amount: mine.damage,
+                    kind: DamageKind::Explosion,
+                    direction: offset.normalize_or_zero(),
+                });
+            }
+            
+            // Short explosion flash
+            commands.spawn((
+                ExplosionFlash {
+                    timer: Timer::from_seconds(0.25, TimerMode::Once),
+                },
+                Transform::from_translation(mine_transform.translation),
+                Mesh2d(meshes.add(Circle::new(mine.explosion_radius))),
+                MeshMaterial2d(materials.add(Color::srgba(1.0, 0.5, 0.1, 0.5))),
+            ));
+            
+            commands.entity(mine_entity).despawn();
+        }
+    }
+}
+
+/// System to fade out and remove explosion flashes
+pub fn update_explosion_flashes(
+    mut commands: Commands,
+    mut flash_query: Query<(Entity, &mut ExplosionFlash, &MeshMaterial2d<ColorMaterial>)>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    time: Res<Time>,
+) {
+    for (entity, mut flash, material_handle) in flash_query.iter_mut() {
+        if flash.timer.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        } else if let Some(material) = materials.get_mut(&material_handle.0) {
+            material.color.set_alpha(0.5 * (1.0 - flash.timer.fraction()));
+        }
+    }
+}
+
+/// System to clean up mines and explosion flashes
+pub fn cleanup_mines(
+    mut commands: Commands,
+    mine_query: Query<Entity, Or<(With<Mine>, With<ExplosionFlash>)>>,
+) {
+    for entity in mine_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -5,10 +5,25 @@ pub mod movement;
 pub mod camera;
 pub mod spawning;
 pub mod boss_systems;
+pub mod boss_telegraph;
+pub mod mine_systems;
+pub mod boss_bullets;
 pub mod ui;
 pub mod player_death;
+pub mod boss_defeat;
+pub mod fight_stats;
+pub mod campaign;
+pub mod profile_systems;
+pub mod settings_systems;
 pub mod boundary;
 pub mod weapon_systems;
+pub mod damage_systems;
+pub mod input_systems;
+pub mod simulation;
+pub mod replay_systems;
+pub mod spatial_index;
+pub mod collision_detection;
+pub mod obstacle_systems;
 
 pub use menu_systems::*;
 pub use ui_systems::*;
@@ -17,7 +32,22 @@ pub use movement::*;
 pub use camera::*;
 pub use spawning::*;
 pub use boss_systems::*;
+pub use boss_telegraph::*;
+pub use mine_systems::*;
+pub use boss_bullets::*;
 pub use ui::*;
 pub use player_death::*;
+pub use boss_defeat::*;
+pub use fight_stats::*;
+pub use campaign::*;
+pub use profile_systems::*;
+pub use settings_systems::*;
 pub use boundary::*;
 pub use weapon_systems::*;
+pub use damage_systems::*;
+pub use input_systems::*;
+pub use simulation::*;
+pub use replay_systems::*;
+pub use spatial_index::*;
+pub use collision_detection::*;
+pub use obstacle_systems::*;
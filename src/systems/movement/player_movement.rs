@@ -1,12 +1,15 @@
 use bevy::prelude::*;
 use crate::components::{Player, DirectionIndicator, PlayerDash, Energy, Invulnerability, Shield};
+use crate::input::{FixedActionState, PlayerAction};
+use crate::resources::FightStats;
 
 /// System to handle player movement and dash input
 pub fn player_movement(
     mut player_query: Query<(&mut Transform, &mut PlayerDash, &mut Energy), (With<Player>, Without<DirectionIndicator>)>,
     shield_query: Query<&Shield>,
-    input: Res<ButtonInput<KeyCode>>,
-    time: Res<Time<Virtual>>,
+    action_state: Res<FixedActionState>,
+    time: Res<Time>,
+    mut stats: ResMut<FightStats>,
 ) {
     for (mut player_transform, mut dash, mut energy) in &mut player_query {
         let delta = time.delta_secs();
@@ -20,28 +23,16 @@ pub fn player_movement(
             energy.recharge(delta);
         }
         
-        // Handle dash input (Space key)
-        if input.just_pressed(KeyCode::Space) && dash.can_dash(&energy) {
-            // Get current WASD direction for dash
-            let mut dash_direction = Vec3::ZERO;
-            
-            if input.pressed(KeyCode::KeyW) || input.pressed(KeyCode::ArrowUp) {
-                dash_direction += Vec3::Y;
-            }
-            if input.pressed(KeyCode::KeyS) || input.pressed(KeyCode::ArrowDown) {
-                dash_direction -= Vec3::Y;
-            }
-            if input.pressed(KeyCode::KeyA) || input.pressed(KeyCode::ArrowLeft) {
-                dash_direction -= Vec3::X;
-            }
-            if input.pressed(KeyCode::KeyD) || input.pressed(KeyCode::ArrowRight) {
-                dash_direction += Vec3::X;
-            }
+        // Handle dash input
+        if action_state.just_pressed(PlayerAction::Dash) && dash.can_dash(&energy) {
+            // Dash in the current movement direction
+            let dash_direction = action_state.move_axis().extend(0.0);
             
             // Only dash if there's a direction
             if dash_direction != Vec3::ZERO {
                 energy.consume(dash.energy_cost);
                 dash.start_dash(dash_direction, player_transform.translation);
+                stats.dashes += 1;
                 info!("Player dash started! Energy: {:.1}/{:.1}", energy.current, energy.max);
             }
         }
@@ -49,7 +40,8 @@ pub fn player_movement(
         // Handle movement - either dash or regular movement
         if dash.is_dashing {
             // Dash movement
-            let direction = (dash.dash_target - player_transform.translation).normalize_or_zero();
+            let to_target = dash.dash_target - player_transform.translation;
+            let direction = to_target.normalize_or_zero();
             
             // Safety check: if direction is zero or dash target is invalid, reset dash
             if direction == Vec3::ZERO || dash.dash_target == Vec3::ZERO {
@@ -57,7 +49,8 @@ pub fn player_movement(
                 continue;
             }
             
-            let move_distance = dash.dash_speed * delta;
+            // Never overshoot the target, however long the tick
+            let move_distance = (dash.dash_speed * delta).min(to_target.length());
             player_transform.translation += direction * move_distance;
             
             // Check if dash is complete
@@ -65,26 +58,12 @@ pub fn player_movement(
                 info!("Player dash completed");
             }
         } else {
-            // Regular movement with WASD
-            let mut movement = Vec3::ZERO;
+            // Regular movement
             let base_speed = 300.0; // Base movement speed
-            
-            if input.pressed(KeyCode::KeyW) || input.pressed(KeyCode::ArrowUp) {
-                movement += Vec3::Y;
-            }
-            if input.pressed(KeyCode::KeyS) || input.pressed(KeyCode::ArrowDown) {
-                movement -= Vec3::Y;
-            }
-            if input.pressed(KeyCode::KeyA) || input.pressed(KeyCode::ArrowLeft) {
-                movement -= Vec3::X;
-            }
-            if input.pressed(KeyCode::KeyD) || input.pressed(KeyCode::ArrowRight) {
-                movement += Vec3::X;
-            }
+            let movement = action_state.move_axis().extend(0.0);
             
             if movement != Vec3::ZERO {
-                movement = movement.normalize() * base_speed * delta;
-                player_transform.translation += movement;
+                player_transform.translation += movement * base_speed * delta;
             }
         }
     }
@@ -103,47 +82,41 @@ pub fn manage_player_invulnerability(
     }
 }
 
-/// System to handle player face mouse direction (only when not dashing)
-pub fn player_face_mouse(
+/// System to make the player face the mouse cursor or gamepad aim direction (only when not dashing)
+pub fn player_face_aim(
     mut player_query: Query<(&mut Transform, &PlayerDash), (With<Player>, Without<DirectionIndicator>)>,
     mut indicator_query: Query<&mut Transform, (With<DirectionIndicator>, Without<Player>)>,
-    window_query: Query<&Window>,
-    camera_query: Query<(&Camera, &GlobalTransform)>,
+    action_state: Res<FixedActionState>,
 ) {
-    if let Ok(window) = window_query.single() {
-        if let Ok((camera, camera_transform)) = camera_query.single() {
-            if let Some(cursor_position) = window.cursor_position() {
-                // Convert screen coordinates to world coordinates
-                if let Ok(world_position) = camera.viewport_to_world_2d(camera_transform, cursor_position) {
-                    for (mut player_transform, dash) in &mut player_query {
-                        // Only rotate to face mouse when not dashing
-                        if !dash.is_dashing {
-                            let direction = (world_position - player_transform.translation.truncate()).normalize();
-                            let angle = direction.y.atan2(direction.x);
-                            player_transform.rotation = Quat::from_rotation_z(angle);
-                            
-                            // Update direction indicator position (if exists)
-                            if let Ok(mut indicator_transform) = indicator_query.single_mut() {
-                                // Position the indicator at a constant distance from player edge
-                                let player_radius = 25.0;
-                                let gap_from_edge = 7.0;
-                                let distance_from_center = player_radius + gap_from_edge;
-                                
-                                // Since the player rotates, but we want the indicator to point in world direction,
-                                // we need to counter-rotate the local position by the player's rotation
-                                let inverse_rotation = player_transform.rotation.inverse();
-                                let world_direction = Vec3::new(direction.x, direction.y, 0.0);
-                                let local_direction = inverse_rotation * world_direction;
-                                
-                                // Set local position relative to parent (player)
-                                indicator_transform.translation = Vec3::new(
-                                    local_direction.x * distance_from_center,
-                                    local_direction.y * distance_from_center,
-                                    0.1 // Slightly above parent
-                                );
-                            }
-                        }
-                    }
+    if action_state.aim().is_some() {
+        for (mut player_transform, dash) in &mut player_query {
+            // Only rotate to face the aim when not dashing
+            if !dash.is_dashing {
+                let Some(direction) = action_state.aim_direction_from(player_transform.translation.truncate()) else {
+                    continue;
+                };
+                let angle = direction.y.atan2(direction.x);
+                player_transform.rotation = Quat::from_rotation_z(angle);
+                
+                // Update direction indicator position (if exists)
+                if let Ok(mut indicator_transform) = indicator_query.single_mut() {
+                    // Position the indicator at a constant distance from player edge
+                    let player_radius = 25.0;
+                    let gap_from_edge = 7.0;
+                    let distance_from_center = player_radius + gap_from_edge;
+                    
+                    // Since the player rotates, but we want the indicator to point in world direction,
+                    // we need to counter-rotate the local position by the player's rotation
+                    let inverse_rotation = player_transform.rotation.inverse();
+                    let world_direction = Vec3::new(direction.x, direction.y, 0.0);
+                    let local_direction = inverse_rotation * world_direction;
+                    
+                    // Set local position relative to parent (player)
+                    indicator_transform.translation = Vec3::new(
+                        local_direction.x * distance_from_center,
+                        local_direction.y * distance_from_center,
+                        0.1 // Slightly above parent
+                    );
                 }
             }
         }
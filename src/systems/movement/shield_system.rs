@@ -1,18 +1,22 @@
 use bevy::prelude::*;
 use std::f32::consts::PI;
-use crate::components::{Player, Shield, DirectionIndicator};
-use crate::components::attributes::Energy;
+use crate::components::{Player, Shield, DirectionIndicator, ExplosionFlash};
+use crate::components::attributes::{Collider, Energy};
+use crate::collision::{CollisionLayer, CollisionLayers};
+use crate::events::ParryEvent;
+use crate::input::{FixedActionState, PlayerAction};
 
-/// System to handle shield input (right click)
+/// System to handle shield input
+#[allow(clippy::collapsible_if)]
 pub fn handle_shield_input(
-    mouse_input: Res<ButtonInput<MouseButton>>,
+    action_state: Res<FixedActionState>,
     mut shield_query: Query<&mut Shield>,
     mut energy_query: Query<&mut Energy, With<Player>>,
     mut indicator_query: Query<&mut Visibility, With<DirectionIndicator>>,
 ) {
     if let Ok(mut shield) = shield_query.single_mut() {
         if let Ok(mut energy) = energy_query.single_mut() {
-            if mouse_input.just_pressed(MouseButton::Right) {
+            if action_state.just_pressed(PlayerAction::Shield) {
                 // Check if we have enough energy to activate the shield
                 if shield.can_activate(&energy) {
                     // Consume the activation energy cost
@@ -24,7 +28,7 @@ pub fn handle_shield_input(
                         *visibility = Visibility::Hidden;
                     }
                 }
-            } else if mouse_input.just_released(MouseButton::Right) {
+            } else if action_state.just_released(PlayerAction::Shield) {
                 shield.deactivate();
                 
                 // Show direction indicator when shield is deactivated
@@ -38,12 +42,14 @@ pub fn handle_shield_input(
 
 /// System to animate shield growth/shrinkage and handle energy consumption
 pub fn animate_shield(
-    time: Res<Time<Virtual>>,
+    time: Res<Time>,
     mut shield_query: Query<&mut Shield>,
     mut energy_query: Query<&mut Energy, With<Player>>,
     mut indicator_query: Query<&mut Visibility, With<DirectionIndicator>>,
 ) {
     for mut shield in &mut shield_query {
+        shield.parry_window.tick(time.delta());
+
         if let Ok(mut energy) = energy_query.single_mut() {
             // If shield is active, consume energy
             if shield.is_active && shield.length > 0.0 {
@@ -81,7 +87,58 @@ pub fn animate_shield(
     }
 }
 
+/// System to fit the shield's arc collider to what it blocks, so it only collides while raised
+pub fn update_shield_collider(
+    mut shield_query: Query<(&Shield, &mut Collider, &mut CollisionLayers)>,
+    indicator_query: Query<&Transform, With<DirectionIndicator>>,
+) {
+    let Ok(indicator_transform) = indicator_query.single() else {
+        return;
+    };
+    for (shield, mut collider, mut layers) in &mut shield_query {
+        *collider = shield.collider(indicator_transform.translation);
+        *layers = if shield.is_active { CollisionLayers::new(CollisionLayer::Shield) } else { CollisionLayers::NONE };
+    }
+}
+
+/// System to refund the shield activation cost on a perfect parry
+pub fn refund_parry_energy(
+    mut parry_events: EventReader<ParryEvent>,
+    mut shield_query: Query<&mut Shield>,
+    mut energy_query: Query<&mut Energy, With<Player>>,
+) {
+    for event in parry_events.read() {
+        if let (Ok(mut shield), Ok(mut energy)) = (shield_query.single_mut(), energy_query.get_mut(event.player)) {
+            let refund = shield.take_parry_refund();
+            if refund > 0.0 {
+                energy.restore(refund);
+                info!("Parry of {:?} attack refunded {:.1} energy", event.kind, refund);
+            }
+        }
+    }
+}
+
+/// System to show a short flash where an attack was parried
+pub fn spawn_parry_flash(
+    mut commands: Commands,
+    mut parry_events: EventReader<ParryEvent>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    for event in parry_events.read() {
+        commands.spawn((
+            ExplosionFlash {
+                timer: Timer::from_seconds(0.25, TimerMode::Once),
+            },
+            Transform::from_translation(event.position.extend(1.5)), // Above the player
+            Mesh2d(meshes.add(Circle::new(Shield::OUTER_RADIUS + 10.0))),
+            MeshMaterial2d(materials.add(Color::srgba(0.6, 0.9, 1.0, 0.5))),
+        ));
+    }
+}
+
 /// System to update shield mesh based on current length
+#[allow(clippy::collapsible_if)]
 pub fn update_shield_mesh(
     mut meshes: ResMut<Assets<Mesh>>,
     shield_query: Query<(&Shield, &Mesh2d), Changed<Shield>>,
@@ -111,10 +168,8 @@ pub fn update_shield_mesh(
 
 /// Create a mesh for the shield arc centered on the direction indicator
 fn create_shield_arc_mesh(length: f32, center_angle: f32) -> Mesh {
-    let player_radius = 25.0;
-    let shield_thickness = 4.0; // Reduced back to 4.0
-    let gap_from_edge = 7.0; // Same as direction indicator positioning
-    let shield_radius = player_radius + gap_from_edge; // 32.0 - same as direction indicator distance
+    let shield_thickness = Shield::THICKNESS;
+    let shield_radius = Shield::ARC_RADIUS; // Same as direction indicator distance
     
     // Calculate arc parameters - arc grows equally in both directions from center
     let total_arc_length = length * 2.0 * PI; // Full circle when length = 1.0
@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use bevy::ecs::entity::EntityHashMap;
+use crate::collision::WorldShape;
+use crate::components::{BossSkills, BoundedMovement, Collider, Health, InterpolatedTransform, Obstacle, PlayerDash, Projectile};
+use crate::events::{CollisionOngoing, CollisionStarted, DamageEvent, DamageKind};
+
+/// System to stop the player and bosses at the obstacles they ran into, pushing them back out.
+/// A dash into an obstacle ends there, and stuns the boss that made it.
+pub fn block_at_obstacles(
+    mut started: EventReader<CollisionStarted>,
+    mut ongoing: EventReader<CollisionOngoing>,
+    obstacle_query: Query<(&Transform, &Collider), (With<Obstacle>, Without<BoundedMovement>)>,
+    mut mover_query: Query<(&mut Transform, &Collider, &InterpolatedTransform, Option<&mut PlayerDash>, Option<&mut BossSkills>), With<BoundedMovement>>,
+) {
+    let contacts = started
+        .read()
+        .map(|collision| (collision.pair, Some(collision.time_of_impact)))
+        .chain(ongoing.read().map(|collision| (collision.pair, None)));
+    for (pair, time_of_impact) in contacts {
+        let Some((mover_entity, obstacle_entity)) = pair.matching(|entity| mover_query.contains(entity), |entity| obstacle_query.contains(entity)) else {
+            continue;
+        };
+        let (Ok((mut transform, collider, interpolated, dash, skills)), Ok((obstacle_transform, obstacle_collider))) =
+            (mover_query.get_mut(mover_entity), obstacle_query.get(obstacle_entity))
+        else {
+            continue;
+        };
+
+        // Back to where it first touched the obstacle, so fast movers don't end up inside or past it
+        if let Some(time_of_impact) = time_of_impact {
+            transform.translation = interpolated.translation_at(&transform, time_of_impact);
+        }
+        let obstacle_shape = WorldShape::of(obstacle_collider, obstacle_transform);
+        if let Some(push) = WorldShape::of(collider, &transform).push_out(&obstacle_shape) {
+            transform.translation += push.extend(0.0);
+        }
+
+        if let Some(mut dash) = dash.filter(|dash| dash.is_dashing) {
+            dash.reset_dash();
+        }
+        if let Some(mut skills) = skills.filter(|skills| skills.is_dashing) {
+            skills.stagger(BossSkills::OBSTACLE_STUN_DURATION);
+            info!("Boss {:?} dashed into an obstacle and is stunned for {:.1}s", mover_entity, BossSkills::OBSTACLE_STUN_DURATION);
+        }
+    }
+}
+
+/// The first obstacle each projectile hit this tick, with when it did. Whatever a projectile reached later in
+/// the tick was behind the obstacle.
+pub fn first_obstacle_hits<'a>(
+    started: impl IntoIterator<Item = &'a CollisionStarted>,
+    is_projectile: impl Fn(Entity) -> bool,
+    is_obstacle: impl Fn(Entity) -> bool,
+) -> EntityHashMap<(Entity, f32)> {
+    let mut hits = EntityHashMap::default();
+    for collision in started {
+        let Some((projectile_entity, obstacle_entity)) = collision.pair.matching(&is_projectile, &is_obstacle) else {
+            continue;
+        };
+        let earlier = hits.get(&projectile_entity).is_some_and(|&(_, time_of_impact)| time_of_impact <= collision.time_of_impact);
+        if !earlier {
+            hits.insert(projectile_entity, (obstacle_entity, collision.time_of_impact));
+        }
+    }
+    hits
+}
+
+/// System to stop projectiles at the first obstacle they hit, damaging destructible ones. Runs after the other
+/// projectile hits, which ignore whatever a projectile reached after its obstacle.
+pub fn projectile_obstacle_collision_system(
+    mut commands: Commands,
+    projectile_query: Query<&Projectile>,
+    obstacle_query: Query<Has<Health>, With<Obstacle>>,
+    mut collisions: EventReader<CollisionStarted>,
+    mut damage_events: EventWriter<DamageEvent>,
+) {
+    let hits = first_obstacle_hits(collisions.read(), |entity| projectile_query.contains(entity), |entity| obstacle_query.contains(entity));
+    for (projectile_entity, (obstacle_entity, _)) in hits {
+        let (Ok(projectile), Ok(destructible)) = (projectile_query.get(projectile_entity), obstacle_query.get(obstacle_entity)) else {
+            continue;
+        };
+        if destructible {
+            damage_events.write(DamageEvent {
+                source: projectile_entity,
+                target: obstacle_entity,
+                amount: projectile.damage,
+                kind: DamageKind::Projectile,
+                direction: projectile.direction.truncate(),
+            });
+        }
+        commands.entity(projectile_entity).despawn();
+    }
+}
@@ -2,19 +2,20 @@ use bevy::prelude::*;
 use crate::components::{PauseOverlayUI, PauseButton};
 use crate::resources::PauseState;
 use crate::states::AppState;
+use crate::input::{ActionState, PlayerAction};
 use crate::systems::create_button_with_component;
 
 
-/// System to handle pause input (ESC key)
+/// System to handle pause input
 pub fn handle_pause_input(
     mut pause_state: ResMut<PauseState>,
     mut virtual_time: ResMut<Time<Virtual>>,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    action_state: Res<ActionState>,
     current_state: Res<State<AppState>>,
 ) {
     // Allow pausing in both Game and Debug states
-    let can_pause = matches!(*current_state.get(), AppState::Game | AppState::Debug);
-    if can_pause && keyboard_input.just_pressed(KeyCode::Escape) {
+    let can_pause = current_state.get().is_gameplay();
+    if can_pause && action_state.just_pressed(PlayerAction::Pause) {
         pause_state.toggle();
         
         // Pause or unpause virtual time based on pause state
@@ -3,13 +3,14 @@ use crate::components::{Player, Health};
 use crate::states::AppState;
 
 /// System to check if player has died and transition to game over state
+#[allow(clippy::collapsible_if)]
 pub fn check_player_death(
     player_query: Query<&Health, With<Player>>,
     mut next_state: ResMut<NextState<AppState>>,
     current_state: Res<State<AppState>>,
 ) {
     // Only check for death in Game and Debug states
-    if !matches!(current_state.get(), AppState::Game | AppState::Debug) {
+    if !current_state.get().is_gameplay() {
         return;
     }
     
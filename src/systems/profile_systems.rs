@@ -0,0 +1,59 @@
+use bevy::prelude::*;
+use crate::persistence::{ConfigStorage, GameSettings, Profile, ProfileError};
+use crate::resources::{ActiveEncounter, Difficulty, FightStats, SimulationSettings};
+use crate::states::AppState;
+
+/// System to load the saved profile and apply its settings and difficulty. An unreadable profile is set aside and
+/// replaced by a fresh one; one from a newer version of the game is left untouched on disk.
+pub fn load_profile(
+    mut profile: ResMut<Profile>,
+    mut settings: ResMut<GameSettings>,
+    mut difficulty: ResMut<Difficulty>,
+    mut simulation_settings: ResMut<SimulationSettings>,
+    storage: Res<ConfigStorage>,
+) {
+    match storage.load_with(Profile::FILE_NAME, Profile::from_ron) {
+        None => info!("No saved profile, starting a new one"),
+        Some(Ok(saved)) => {
+            *profile = saved;
+            info!("Loaded profile");
+        }
+        Some(Err(error @ ProfileError::Newer(_))) => {
+            warn!("Not saving progress: {}", error);
+            profile.is_read_only = true;
+        }
+        Some(Err(error)) => {
+            warn!("Starting a new profile: {}", error);
+            storage.set_aside(Profile::FILE_NAME);
+        }
+    }
+    *settings = profile.settings.clone();
+    *difficulty = profile.difficulty;
+    simulation_settings.tick_rate = settings.tick_rate;
+}
+
+/// System to save the profile after it changed
+pub fn save_profile(profile: Res<Profile>, storage: Res<ConfigStorage>) {
+    if !profile.is_read_only {
+        storage.save(Profile::FILE_NAME, &*profile);
+    }
+}
+
+/// System to add the fight that just ended to the lifetime statistics
+pub fn record_fight_in_profile(
+    active: Option<Res<ActiveEncounter>>,
+    stats: Res<FightStats>,
+    state: Res<State<AppState>>,
+    mut profile: ResMut<Profile>,
+) {
+    if active.is_some_and(|active| active.is_counted) {
+        profile.record_fight(&stats, *state.get() == AppState::Victory);
+    }
+}
+
+/// System to keep the settings in the profile, which saves them if they changed
+pub fn store_settings_in_profile(settings: Res<GameSettings>, mut profile: ResMut<Profile>) {
+    if profile.settings != *settings {
+        profile.settings = settings.clone();
+    }
+}
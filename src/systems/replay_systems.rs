@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::assets::EncounterSequence;
+use crate::components::Player;
+use crate::input::{FixedActionState, Replay};
+use crate::persistence::ConfigStorage;
+use crate::resources::{ActiveEncounter, LastReplay, ReplayPlayback, ReplayRecorder, SimulationSettings};
+use crate::states::AppState;
+
+/// Virtual time speed while fast-forwarding to a seek target
+const SEEK_SPEED: f32 = 12.0;
+
+/// System to turn the tick's live actions into a `TickInput` and record it during a fight
+pub fn resolve_tick_input(
+    mut fixed_action_state: ResMut<FixedActionState>,
+    mut recorder: ResMut<ReplayRecorder>,
+    player_query: Query<&Transform, With<Player>>,
+) {
+    let origin = player_query.single().ok().map(|transform| transform.translation.truncate());
+    let input = fixed_action_state.resolve(origin);
+    if recorder.is_recording {
+        recorder.inputs.push(input);
+    }
+}
+
+/// System to feed the next recorded tick to the simulation during playback
+pub fn play_back_tick_input(
+    mut fixed_action_state: ResMut<FixedActionState>,
+    mut playback: ResMut<ReplayPlayback>,
+) {
+    let input = playback.next_input();
+    fixed_action_state.apply_tick_input(&input);
+}
+
+/// System to start a fight with no actions held, then record it, or set up the tick rate of the replay being played back
+pub fn begin_fight_input(
+    mut fixed_action_state: ResMut<FixedActionState>,
+    mut recorder: ResMut<ReplayRecorder>,
+    playback: Option<Res<ReplayPlayback>>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+) {
+    *fixed_action_state = FixedActionState::default();
+    recorder.inputs.clear();
+    match playback {
+        Some(playback) => fixed_time.set_timestep_hz(playback.tick_rate),
+        None => recorder.is_recording = true,
+    }
+}
+
+/// System to stop recording when the fight ends
+pub fn stop_fight_recording(mut recorder: ResMut<ReplayRecorder>) {
+    recorder.is_recording = false;
+}
+
+/// System to keep and save the replay of the fight that just ended
+pub fn save_fight_replay(
+    mut recorder: ResMut<ReplayRecorder>,
+    mut last_replay: ResMut<LastReplay>,
+    settings: Res<SimulationSettings>,
+    storage: Res<ConfigStorage>,
+    encounter: Option<Res<ActiveEncounter>>,
+) {
+    let Some(encounter) = encounter.filter(|_| !recorder.inputs.is_empty()) else {
+        return;
+    };
+    let replay = Replay::new(settings.tick_rate, encounter.index, encounter.loadout.clone(), encounter.difficulty, &recorder.inputs);
+    recorder.inputs.clear();
+
+    if storage.directory().is_some() {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |duration| duration.as_secs());
+        storage.save_compact(&Replay::file_name(timestamp), &replay);
+    }
+    last_replay.replay = Some(replay);
+}
+
+/// Play `replay` back from the start of its fight
+pub fn watch_replay(commands: &mut Commands, next_state: &mut NextState<AppState>, replay: &Replay) {
+    info!("Watching replay of {} ticks", replay.tick_count());
+    commands.insert_resource(ReplayPlayback::new(replay));
+    next_state.set(AppState::Game);
+}
+
+/// The replay of the latest fight, or else the newest saved replay if it can be played back against `sequence`
+pub fn find_last_replay(last_replay: &mut LastReplay, storage: &ConfigStorage, sequence: Option<&EncounterSequence>) -> Option<Replay> {
+    if last_replay.replay.is_none() {
+        last_replay.replay = storage
+            .latest_file(Replay::DIRECTORY)
+            .and_then(|file_name| storage.load::<Replay>(&file_name))
+            .filter(|replay| {
+                // Other versions, and edited replays of a tick rate or encounter the game doesn't have
+                replay.version == Replay::VERSION
+                    && SimulationSettings::supports_tick_rate(replay.tick_rate)
+                    && sequence.is_some_and(|sequence| replay.encounter < sequence.encounters.len())
+            });
+    }
+    last_replay.replay.clone()
+}
+
+/// System to run playback at normal speed once a seek target is reached
+pub fn update_replay_seek(
+    mut playback: ResMut<ReplayPlayback>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    let Some(target) = playback.seek_target else {
+        return;
+    };
+    if playback.tick >= target {
+        playback.seek_target = None;
+        virtual_time.set_relative_speed(1.0);
+    } else {
+        virtual_time.set_relative_speed(SEEK_SPEED);
+        virtual_time.unpause();
+    }
+}
+
+/// System to end playback, going back to the configured tick rate and normal speed
+pub fn stop_replay_playback(
+    mut commands: Commands,
+    playback: Option<Res<ReplayPlayback>>,
+    settings: Res<SimulationSettings>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    if playback.is_none() {
+        return;
+    }
+    commands.remove_resource::<ReplayPlayback>();
+    fixed_time.set_timestep_hz(settings.tick_rate);
+    virtual_time.set_relative_speed(1.0);
+    virtual_time.unpause();
+}
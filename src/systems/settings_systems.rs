@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use bevy::audio::{GlobalVolume, Volume};
+use bevy::window::PrimaryWindow;
+use crate::persistence::{GameSettings, WindowModeSetting};
+
+/// System to apply the video settings to the primary window, touching only what changed.
+/// On the web the canvas follows the page instead.
+pub fn apply_video_settings(
+    settings: Res<GameSettings>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if cfg!(target_arch = "wasm32") {
+        return;
+    }
+    let Ok(mut window) = window_query.single_mut() else {
+        return;
+    };
+    let mode = settings.window_mode();
+    if window.mode != mode {
+        window.mode = mode;
+    }
+    let present_mode = settings.present_mode();
+    if window.present_mode != present_mode {
+        window.present_mode = present_mode;
+    }
+    let (width, height) = settings.resolution;
+    if settings.window_mode == WindowModeSetting::Windowed
+        && (window.resolution.width() != width as f32 || window.resolution.height() != height as f32)
+    {
+        window.resolution.set(width as f32, height as f32);
+    }
+}
+
+/// System to apply the master volume to all audio
+pub fn apply_audio_settings(settings: Res<GameSettings>, global_volume: Option<ResMut<GlobalVolume>>) {
+    if let Some(mut global_volume) = global_volume {
+        global_volume.volume = Volume::Linear(settings.master_volume);
+    }
+}
@@ -0,0 +1,52 @@
+use bevy::prelude::*;
+use crate::components::InterpolatedTransform;
+use crate::resources::SimulationSettings;
+
+/// System to apply the configured simulation tick rate to the fixed clock
+pub fn apply_tick_rate(
+    settings: Res<SimulationSettings>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+) {
+    fixed_time.set_timestep_hz(settings.tick_rate);
+    info!("Simulation ticking at {} Hz", settings.tick_rate);
+}
+
+/// System to put the simulated transforms back before the simulation ticks.
+/// A transform that no longer matches what was rendered was moved from outside the simulation and is kept.
+pub fn restore_simulated_transforms(mut query: Query<(&mut Transform, &mut InterpolatedTransform)>) {
+    for (mut transform, mut interpolated) in &mut query {
+        if interpolated.rendered.is_some_and(|rendered| rendered != *transform) {
+            interpolated.teleport(*transform);
+        } else if let Some(current) = interpolated.current {
+            *transform = current;
+        }
+    }
+}
+
+/// System to remember the simulated transforms at the start of each tick
+pub fn record_previous_transforms(mut query: Query<(&Transform, &mut InterpolatedTransform)>) {
+    for (transform, mut interpolated) in &mut query {
+        interpolated.previous = Some(*transform);
+    }
+}
+
+/// System to remember the simulated transforms at the end of each tick
+pub fn record_current_transforms(mut query: Query<(&Transform, &mut InterpolatedTransform)>) {
+    for (transform, mut interpolated) in &mut query {
+        interpolated.current = Some(*transform);
+    }
+}
+
+/// System to blend the transforms between the last two ticks for rendering
+pub fn interpolate_transforms(
+    mut query: Query<(&mut Transform, &mut InterpolatedTransform)>,
+    fixed_time: Res<Time<Fixed>>,
+) {
+    let overstep = fixed_time.overstep_fraction();
+    for (mut transform, mut interpolated) in &mut query {
+        if let Some(blended) = interpolated.interpolate(overstep) {
+            *transform = blended;
+            interpolated.rendered = Some(blended);
+        }
+    }
+}
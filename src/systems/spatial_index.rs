@@ -0,0 +1,13 @@
+use bevy::prelude::*;
+use crate::collision::{SpatialIndex, WorldColliders};
+
+/// System to rebuild the spatial index from every collider, once everything has moved this tick
+pub fn rebuild_spatial_index(
+    mut index: ResMut<SpatialIndex>,
+    colliders: WorldColliders,
+) {
+    index.clear();
+    for collider in colliders.iter() {
+        index.insert(collider.entity, collider.shape.center(), collider.shape.bounding_radius());
+    }
+}
@@ -1,55 +1,108 @@
 use bevy::prelude::*;
-use crate::components::{Boss, MineBossBundle, MineSpikeBundle, DebugEntity};
+use bevy::asset::LoadState;
+use crate::assets::{BossDefinition, BossKind, BossPartLayout};
+use crate::components::{Boss, MineBoss, BossSkills, MineSkill, BulletSkills, MineSpike, BossPhase, BossBundle, BossPartBundle, DebugEntity};
+use crate::resources::PendingBossSpawn;
 use std::f32::consts::PI;
 
-/// System to spawn Mine boss in debug mode
-pub fn spawn_mine_boss(
+/// System to spawn the pending boss, scaled to its difficulty, as soon as its definition is available
+pub fn spawn_pending_boss(
     mut commands: Commands,
+    pending: Option<Res<PendingBossSpawn>>,
+    definitions: Res<Assets<BossDefinition>>,
+    asset_server: Res<AssetServer>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
-    // Create mesh and material for the mine body (circle)
-    let body_mesh = meshes.add(Circle::new(30.0)); // Main body radius
-    let body_material = materials.add(Color::srgb(0.8, 0.4, 0.2)); // Orange-brown color
-    
-    // Create mesh and material for the spikes (squares)
-    let spike_size = 12.0;
-    let spike_mesh = meshes.add(Rectangle::new(spike_size, spike_size));
-    let spike_material = materials.add(Color::srgb(0.6, 0.3, 0.1)); // Darker orange-brown
-    
-    // Spawn position for the mine boss
-    let boss_position = Vec3::new(200.0, 200.0, 1.0);
-    
-    // Spawn mine boss body
-    let boss_entity = commands.spawn((
-        MineBossBundle::new(
-            1000.0,                      // max health
-            200.0,
-            boss_position,
-            body_mesh,
-            body_material,
-        ),
+    let Some(pending) = pending else {
+        return;
+    };
+
+    if let Some(definition) = definitions.get(&pending.definition) {
+        let definition = definition.scaled(&pending.difficulty.multipliers());
+        spawn_boss(&mut commands, &mut meshes, &mut materials, &definition, pending.position);
+        commands.remove_resource::<PendingBossSpawn>();
+    } else if let Some(LoadState::Failed(error)) = asset_server.get_load_state(&pending.definition) {
+        error!("Failed to load boss definition: {}", error);
+        commands.remove_resource::<PendingBossSpawn>();
+    }
+}
+
+/// Spawn a boss, its skills and its visual parts from a definition
+pub fn spawn_boss(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+    definition: &BossDefinition,
+    position: Vec3,
+) -> Entity {
+    // Create mesh and material for the boss body
+    let body_mesh = meshes.add(definition.body.mesh());
+    let body_material = materials.add(definition.body.color());
+
+    let mut boss = commands.spawn((
+        BossBundle::new(definition, position, body_mesh, body_material),
         DebugEntity, // Mark as debug entity for cleanup
-    )).id();
-    
-    // Spawn 8 spikes around the mine body
-    let spike_distance = 42.0; // Distance from center to spike center
-    commands.entity(boss_entity).with_children(|parent| {
-        for i in 0..8 {
-            let angle = (i as f32) * (PI / 4.0); // 8 evenly spaced angles
-            let x = angle.cos() * spike_distance;
-            let y = angle.sin() * spike_distance;
-            
-            parent.spawn((
-                MineSpikeBundle::new(
-                    Vec3::new(x, y, 0.1), // Local position relative to parent
-                    spike_mesh.clone(),
-                    spike_material.clone(),
-                ),
-                DebugEntity, // Mark as debug entity for cleanup
-            ));
+    ));
+
+    match definition.kind {
+        BossKind::Mine => {
+            boss.insert(MineBoss);
+        }
+    }
+
+    if let Some(dash) = definition.dash_skill() {
+        boss.insert(BossSkills::new(dash));
+    } else {
+        warn!("Boss '{}' has no dash skill; its AI will stay idle", definition.name);
+    }
+
+    if let Some(mines) = definition.mine_skill() {
+        boss.insert(MineSkill::new(mines));
+    }
+
+    let bullet_skills = definition.bullet_skills();
+    if !bullet_skills.is_empty() {
+        boss.insert(BulletSkills::new(&bullet_skills));
+    }
+
+    if !definition.phases.is_empty() {
+        boss.insert(BossPhase::new(definition.phases.clone()));
+    }
+
+    // Spawn parts around the body, each with its own hitbox
+    boss.with_children(|parent| {
+        for layout in &definition.parts {
+            match layout {
+                BossPartLayout::Ring { count, distance, part, detachable } => {
+                    let part_mesh = meshes.add(part.mesh());
+                    let part_material = materials.add(part.color());
+
+                    for i in 0..*count {
+                        let angle = (i as f32) * (2.0 * PI / *count as f32); // Evenly spaced angles
+                        let x = angle.cos() * distance;
+                        let y = angle.sin() * distance;
+
+                        let mut spike = parent.spawn((
+                            BossPartBundle::new(
+                                Vec3::new(x, y, 0.1), // Local position relative to parent
+                                part.collider(),
+                                part_mesh.clone(),
+                                part_material.clone(),
+                            ),
+                            DebugEntity, // Mark as debug entity for cleanup
+                        ));
+                        if *detachable {
+                            spike.insert(MineSpike::new());
+                        }
+                    }
+                }
+            }
         }
     });
+
+    info!("Spawned boss '{}'", definition.name);
+    boss.id()
 }
 
 /// System to clean up boss entities
@@ -57,4 +110,5 @@ pub fn cleanup_boss_entities(mut commands: Commands, boss_query: Query<Entity, W
     for entity in &boss_query {
         commands.entity(entity).despawn(); // Despawn boss and all children (spikes)
     }
-} 
\ No newline at end of file
+    commands.remove_resource::<PendingBossSpawn>();
+}
@@ -1,5 +1,7 @@
 mod player_spawning;
 mod boss_spawning;
+mod obstacle_spawning;
 
 pub use player_spawning::*;
 pub use boss_spawning::*;
+pub use obstacle_spawning::*;
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use crate::assets::EncounterSequence;
+use crate::components::{Health, Obstacle, ObstacleBundle};
+use crate::resources::{ActiveEncounter, EncounterAssets};
+
+/// System to spawn the obstacles of the encounter's arena
+pub fn spawn_arena_obstacles(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    encounter: Option<Res<ActiveEncounter>>,
+    encounter_assets: Option<Res<EncounterAssets>>,
+    sequences: Res<Assets<EncounterSequence>>,
+) {
+    let Some(arena) = encounter
+        .zip(encounter_assets)
+        .and_then(|(encounter, assets)| sequences.get(&assets.sequence)?.encounters.get(encounter.index).map(|encounter| &encounter.arena))
+    else {
+        return;
+    };
+
+    for definition in &arena.obstacles {
+        let mut obstacle = commands.spawn(ObstacleBundle::new(
+            definition,
+            meshes.add(definition.mesh()),
+            materials.add(definition.color()),
+        ));
+        if let Some(health) = definition.health() {
+            obstacle.insert(Health::new(health));
+        }
+    }
+}
+
+/// System to clean up the arena obstacles
+pub fn cleanup_obstacles(mut commands: Commands, obstacle_query: Query<Entity, With<Obstacle>>) {
+    for entity in obstacle_query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -1,11 +1,20 @@
 use bevy::prelude::*;
 use crate::components::{Player, PlayerBundle, DebugEntity, DirectionIndicatorBundle, ShieldBundle, WeaponBundle};
+use crate::resources::{ActiveEncounter, WeaponAssets};
+
+/// System to start loading the weapon registry asset
+pub fn load_weapon_registry(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(WeaponAssets {
+        registry: asset_server.load("weapons/arsenal.weapons.ron"),
+    });
+}
 
 /// System to spawn the player in debug mode
 pub fn spawn_player(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    encounter: Option<Res<ActiveEncounter>>,
 ) {
     // Create mesh and material with better visibility
     let player_mesh = meshes.add(Circle::new(25.0)); // Slightly larger for better visibility
@@ -32,14 +41,18 @@ pub fn spawn_player(
     ));
     
     // Spawn player with proper positioning and debug marker
+    let mut player = PlayerBundle::new(
+        100.0,                                    // max health
+        300.0,                                    // speed
+        Vec3::new(0.0, 0.0, 1.0),                // position with proper z-index
+        player_mesh,
+        player_material,
+    );
+    if let Some(encounter) = &encounter {
+        player.energy.recharge_rate *= encounter.difficulty.multipliers().energy_recharge;
+    }
     let player_entity = commands.spawn((
-        PlayerBundle::new(
-            100.0,                                    // max health
-            300.0,                                    // speed
-            Vec3::new(0.0, 0.0, 1.0),                // position with proper z-index
-            player_mesh,
-            player_material,
-        ),
+        player,
         DebugEntity, // Mark as debug entity for cleanup
     )).id();
     
@@ -54,22 +67,22 @@ pub fn spawn_player(
             DebugEntity, // Mark as debug entity for cleanup
         ));
         
-        // Shield
+        // Shield, with the upgrades unlocked so far
+        let mut shield = ShieldBundle::new(
+            shield_mesh,
+            shield_material,
+        );
+        if let Some(encounter) = &encounter {
+            encounter.loadout.upgrade_shield(&mut shield.shield);
+        }
         parent.spawn((
-            ShieldBundle::new(
-                shield_mesh,
-                shield_material,
-            ),
+            shield,
             DebugEntity, // Mark as debug entity for cleanup
         ));
         
-        // Weapon - Default gun in debug mode
+        // Weapon - starts with the first weapon of the registry the loadout allows
         parent.spawn((
-            WeaponBundle::new(
-                5.0, // 5 shots per second
-                10.0, // 10 damage per shot
-                400.0, // 400 units per second projectile speed
-            ),
+            WeaponBundle::new(),
             DebugEntity, // Mark as debug entity for cleanup
         ));
     });
@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use crate::assets::WeaponRegistry;
+use crate::components::{Weapon, TriggerMode, AmmoDisplayUI};
+use crate::resources::WeaponAssets;
+
+/// System to spawn the ammo counter UI
+pub fn spawn_ammo_display(mut commands: Commands) {
+    // Ammo counter - positioned right of the energy bar
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: 14.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.9, 0.9, 0.9)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(48.0), // Aligned with the energy bar at 50px
+            left: Val::Px(230.0), // Energy bar is 200px wide starting at 20px
+            ..default()
+        },
+        ZIndex(100), // On top
+        AmmoDisplayUI,
+    ));
+}
+
+/// System to update the ammo counter from the equipped weapon
+pub fn update_ammo_display(
+    weapon_query: Query<&Weapon>,
+    weapon_assets: Res<WeaponAssets>,
+    registries: Res<Assets<WeaponRegistry>>,
+    mut ammo_text_query: Query<(&mut Text, &mut TextColor), With<AmmoDisplayUI>>,
+) {
+    let (Ok(weapon), Ok((mut text, mut color))) = (weapon_query.single(), ammo_text_query.single_mut()) else {
+        return;
+    };
+    let Some(definition) = registries
+        .get(&weapon_assets.registry)
+        .and_then(|registry| registry.weapons.get(weapon.current))
+    else {
+        return;
+    };
+
+    let mode = match weapon.trigger_mode {
+        TriggerMode::Auto => "AUTO",
+        TriggerMode::Semi => "SEMI",
+    };
+
+    if weapon.is_reloading {
+        **text = format!("{} [{}]  RELOADING {:.0}%", definition.name, mode, weapon.reload_progress() * 100.0);
+        *color = TextColor(Color::srgb(0.9, 0.7, 0.2)); // Yellow while reloading
+    } else {
+        let ammo = weapon.ammo(definition);
+        **text = format!("{} [{}]  {}/{}", definition.name, mode, ammo, definition.magazine_size);
+        *color = if ammo * 4 <= definition.magazine_size {
+            TextColor(Color::srgb(0.9, 0.3, 0.3)) // Red when a quarter or less is left
+        } else {
+            TextColor(Color::srgb(0.9, 0.9, 0.9))
+        };
+    }
+}
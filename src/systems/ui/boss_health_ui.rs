@@ -1,14 +1,21 @@
 use bevy::prelude::*;
-use crate::components::{Boss, Health, BossHealthBarUI, BossHealthBarFill};
+use crate::components::{Boss, BossPhase, Health, BossHealthBarUI, BossHealthBarFill, BossPhaseMarker};
+use crate::events::BossPhaseChanged;
 
 /// System to spawn boss health bar UI when boss is present
 pub fn spawn_boss_health_bar(
     mut commands: Commands,
-    boss_query: Query<Entity, (With<Boss>, With<Health>)>,
+    boss_query: Query<(Entity, Option<&Name>, Option<&BossPhase>), (With<Boss>, With<Health>)>,
     boss_health_bar_query: Query<Entity, With<BossHealthBarUI>>,
 ) {
     // Only spawn if boss exists and health bar doesn't already exist
-    if !boss_query.is_empty() && boss_health_bar_query.is_empty() {
+    if !boss_health_bar_query.is_empty() {
+        return;
+    }
+    if let Some((boss_entity, boss_name, boss_phase)) = boss_query.iter().next() {
+        // Title comes from the boss definition name
+        let title = boss_name.map_or_else(|| "BOSS".to_string(), |name| name.as_str().to_uppercase());
+
         // Boss health bar container - positioned at top center of screen
         commands.spawn((
             Node {
@@ -43,9 +50,29 @@ pub fn spawn_boss_health_bar(
                 BossHealthBarFill,
             ));
             
+            // Phase threshold markers on top of the fill
+            if let Some(boss_phase) = boss_phase {
+                for (index, threshold) in boss_phase.thresholds().enumerate() {
+                    parent.spawn((
+                        Node {
+                            position_type: PositionType::Absolute,
+                            left: Val::Percent(threshold * 100.0),
+                            width: Val::Px(3.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::WHITE),
+                        BossPhaseMarker {
+                            boss: boss_entity,
+                            phase: index + 1,
+                        },
+                    ));
+                }
+            }
+            
             // Boss name/title text
             parent.spawn((
-                Text::new("MINE BOSS"),
+                Text::new(title),
                 TextFont {
                     font_size: 20.0,
                     ..default()
@@ -68,6 +95,7 @@ pub fn spawn_boss_health_bar(
 }
 
 /// System to update boss health bar based on boss health
+#[allow(clippy::collapsible_if)]
 pub fn update_boss_health_bar(
     boss_query: Query<&Health, With<Boss>>,
     mut boss_health_fill_query: Query<&mut Node, With<BossHealthBarFill>>,
@@ -81,6 +109,7 @@ pub fn update_boss_health_bar(
 }
 
 /// System to update boss health bar color based on health percentage
+#[allow(clippy::collapsible_if)]
 pub fn update_boss_health_bar_color(
     boss_query: Query<&Health, With<Boss>>,
     mut boss_health_fill_query: Query<&mut BackgroundColor, With<BossHealthBarFill>>,
@@ -104,6 +133,20 @@ pub fn update_boss_health_bar_color(
     }
 }
 
+/// System to dim phase markers once the boss has entered their phase
+pub fn update_boss_phase_markers(
+    mut phase_events: EventReader<BossPhaseChanged>,
+    mut marker_query: Query<(&BossPhaseMarker, &mut BackgroundColor)>,
+) {
+    for event in phase_events.read() {
+        for (marker, mut bg_color) in marker_query.iter_mut() {
+            if marker.boss == event.boss && marker.phase <= event.phase {
+                *bg_color = BackgroundColor(Color::srgba(0.4, 0.4, 0.4, 0.8));
+            }
+        }
+    }
+}
+
 /// System to clean up boss health bar when boss is defeated or removed
 pub fn cleanup_boss_health_bar_on_boss_death(
     mut commands: Commands,
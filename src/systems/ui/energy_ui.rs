@@ -35,6 +35,7 @@ pub fn spawn_energy_bar(mut commands: Commands) {
 }
 
 /// System to update energy bar based on player energy
+#[allow(clippy::collapsible_if)]
 pub fn update_energy_bar(
     player_query: Query<&Energy, With<Player>>,
     mut energy_fill_query: Query<&mut Node, With<EnergyBarFill>>,
@@ -48,6 +49,7 @@ pub fn update_energy_bar(
 }
 
 /// System to update energy bar color based on energy percentage
+#[allow(clippy::collapsible_if)]
 pub fn update_energy_bar_color(
     player_query: Query<&Energy, With<Player>>,
     mut energy_fill_query: Query<&mut BackgroundColor, With<EnergyBarFill>>,
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use crate::components::{Player, Health, HealthBarUI, HealthBarFill};
+use crate::persistence::GameSettings;
 
 /// System to spawn health bar UI
 pub fn spawn_health_bar(mut commands: Commands) {
@@ -35,6 +36,7 @@ pub fn spawn_health_bar(mut commands: Commands) {
 }
 
 /// System to update health bar based on player health
+#[allow(clippy::collapsible_if)]
 pub fn update_health_bar(
     player_query: Query<&Health, With<Player>>,
     mut health_fill_query: Query<&mut Node, With<HealthBarFill>>,
@@ -51,26 +53,31 @@ pub fn update_health_bar(
 }
 
 /// System to update health bar color based on health percentage
+#[allow(clippy::collapsible_if)]
 pub fn update_health_bar_color(
     player_query: Query<&Health, With<Player>>,
     mut health_fill_query: Query<&mut BackgroundColor, With<HealthBarFill>>,
+    settings: Res<GameSettings>,
 ) {
     if let Ok(health) = player_query.single() {
         if let Ok(mut bg_color) = health_fill_query.single_mut() {
             let health_percentage = health.percentage();
-            
-            // Change color from red to yellow to green based on health
+            let palette = settings.palette;
+            let healthy = palette.healthy().to_srgba();
+            let caution = palette.caution().to_srgba();
+            let danger = palette.danger().to_srgba();
+
+            // Change color from danger to caution to healthy based on health
             if health_percentage > 0.6 {
-                // Green to yellow
-                let green_factor = (health_percentage - 0.6) / 0.4;
-                *bg_color = BackgroundColor(Color::srgb(0.8 * (1.0 - green_factor), 0.8, 0.2 * (1.0 - green_factor)));
+                // Caution to healthy
+                let healthy_factor = (health_percentage - 0.6) / 0.4;
+                *bg_color = BackgroundColor(caution.mix(&healthy, healthy_factor).into());
             } else if health_percentage > 0.3 {
-                // Yellow to red
-                let yellow_factor = (health_percentage - 0.3) / 0.3;
-                *bg_color = BackgroundColor(Color::srgb(0.8, 0.8 * yellow_factor, 0.2 * yellow_factor));
+                // Danger to caution
+                let caution_factor = (health_percentage - 0.3) / 0.3;
+                *bg_color = BackgroundColor(danger.mix(&caution, caution_factor).into());
             } else {
-                // Red
-                *bg_color = BackgroundColor(Color::srgb(0.8, 0.2, 0.2));
+                *bg_color = BackgroundColor(danger.into());
             }
         }
     }
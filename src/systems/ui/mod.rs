@@ -1,7 +1,11 @@
 pub mod health_ui;
 pub mod energy_ui;
+pub mod ammo_ui;
 pub mod boss_health_ui;
+pub mod replay_ui;
 
 pub use health_ui::*;
 pub use energy_ui::*;
-pub use boss_health_ui::*; 
\ No newline at end of file
+pub use ammo_ui::*;
+pub use boss_health_ui::*;
+pub use replay_ui::*; 
\ No newline at end of file
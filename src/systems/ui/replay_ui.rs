@@ -0,0 +1,183 @@
+use bevy::prelude::*;
+use bevy::ui::RelativeCursorPosition;
+use crate::components::{ReplayUI, ReplayTimeline, ReplayTimelineFill, ReplayTimeLabel, ReplayButton};
+use crate::resources::ReplayPlayback;
+use crate::states::AppState;
+
+/// System to spawn the replay controls: a time counter, play/pause and exit buttons, and a timeline to seek with
+pub fn spawn_replay_controls(mut commands: Commands) {
+    // Controls container - along the bottom of the screen
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(20.0),
+                left: Val::Percent(10.0),
+                width: Val::Percent(80.0),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            ZIndex(100), // On top
+            ReplayUI,
+        ))
+        .with_children(|parent| {
+            // Time counter and buttons
+            parent
+                .spawn(Node {
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    ..default()
+                })
+                .with_children(|parent| {
+                    parent.spawn((
+                        Text::new("REPLAY"),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        Node {
+                            flex_grow: 1.0,
+                            ..default()
+                        },
+                        ReplayTimeLabel,
+                    ));
+                    spawn_replay_button(parent, "Pause", ReplayButton::PlayPause);
+                    spawn_replay_button(parent, "Exit", ReplayButton::Exit);
+                });
+
+            // Timeline
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.0),
+                        height: Val::Px(16.0),
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.3, 0.3, 0.3)), // Dark gray background
+                    BorderColor(Color::WHITE),
+                    BorderRadius::all(Val::Px(4.0)),
+                    Interaction::default(),
+                    RelativeCursorPosition::default(),
+                    ReplayTimeline,
+                ))
+                .with_children(|parent| {
+                    parent.spawn((
+                        Node {
+                            width: Val::Percent(0.0), // Updated from the playback position
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.2, 0.6, 0.9)), // Blue played part
+                        BorderRadius::all(Val::Px(2.0)),
+                        ReplayTimelineFill,
+                    ));
+                });
+        });
+}
+
+/// Spawn a small button for the replay controls
+fn spawn_replay_button(parent: &mut ChildSpawnerCommands, text: &str, button: ReplayButton) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(90.0),
+                height: Val::Px(32.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.2, 0.2, 0.2)),
+            BorderRadius::all(Val::Px(5.0)),
+            button,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(text),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+/// System to update the timeline, time counter and play/pause label from the playback position
+pub fn update_replay_controls(
+    playback: Res<ReplayPlayback>,
+    virtual_time: Res<Time<Virtual>>,
+    mut fill_query: Query<&mut Node, With<ReplayTimelineFill>>,
+    mut label_query: Query<&mut Text, With<ReplayTimeLabel>>,
+    button_query: Query<(&ReplayButton, &Children)>,
+    mut text_query: Query<&mut Text, Without<ReplayTimeLabel>>,
+) {
+    let tick_count = playback.tick_count().max(1);
+    let tick = playback.tick.min(tick_count);
+
+    if let Ok(mut node) = fill_query.single_mut() {
+        node.width = Val::Percent(tick as f32 / tick_count as f32 * 100.0);
+    }
+
+    if let Ok(mut text) = label_query.single_mut() {
+        let seconds = |ticks: usize| ticks as f64 / playback.tick_rate;
+        let status = if playback.seek_target.is_some() { "  SEEKING" } else { "" };
+        **text = format!("REPLAY  {:.1}s / {:.1}s{}", seconds(tick), seconds(tick_count), status);
+    }
+
+    let play_pause_label = if virtual_time.is_paused() { "Play" } else { "Pause" };
+    for (button, children) in &button_query {
+        if matches!(button, ReplayButton::PlayPause) {
+            for child in children.iter() {
+                if let Ok(mut text) = text_query.get_mut(child) && **text != play_pause_label {
+                    **text = play_pause_label.to_string();
+                }
+            }
+        }
+    }
+}
+
+/// System to seek to the clicked point of the timeline, restarting the fight to seek backwards
+pub fn handle_replay_timeline(
+    timeline_query: Query<(&Interaction, &RelativeCursorPosition), (Changed<Interaction>, With<ReplayTimeline>)>,
+    mut playback: ResMut<ReplayPlayback>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, cursor) in &timeline_query {
+        let (Interaction::Pressed, Some(position)) = (interaction, cursor.normalized) else {
+            continue;
+        };
+        let target = (position.x.clamp(0.0, 1.0) * playback.tick_count() as f32).round() as usize;
+        if playback.seek(target) {
+            next_state.set(AppState::Game);
+        }
+    }
+}
+
+/// System to handle the replay play/pause and exit buttons
+pub fn handle_replay_buttons(
+    interaction_query: Query<(&Interaction, &ReplayButton), (Changed<Interaction>, With<Button>)>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for (interaction, button) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            match button {
+                ReplayButton::PlayPause => {
+                    if virtual_time.is_paused() {
+                        virtual_time.unpause();
+                    } else {
+                        virtual_time.pause();
+                    }
+                }
+                ReplayButton::Exit => {
+                    info!("Leaving replay");
+                    next_state.set(AppState::MainMenu);
+                }
+            }
+        }
+    }
+}
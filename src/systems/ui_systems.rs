@@ -64,3 +64,8 @@ pub fn button_hover_system(
         }
     }
 }
+
+/// Format a fight's duration in seconds as `m:ss.s`
+pub fn format_fight_time(seconds: f32) -> String {
+    format!("{}:{:04.1}", (seconds / 60.0) as u32, seconds % 60.0)
+}
@@ -1,68 +1,157 @@
 use bevy::prelude::*;
-use crate::components::{Player, Weapon, Projectile, ProjectileBundle, Boss, Health, Speed, Collider};
+use bevy::ecs::entity::EntityHashMap;
+use crate::assets::{WeaponRegistry, OnHitEffect};
+use crate::collision::{BossHitboxes, CollisionLayers, SpatialIndex};
+use crate::components::{Player, Weapon, Projectile, ProjectileBundle, Faction, Homing, ShieldBlockResponse, Boss, Speed, Energy, Burning, Shield, DirectionIndicator, ExplosionFlash, InterpolatedTransform, Obstacle};
+use crate::resources::{ActiveEncounter, FightStats, WeaponAssets};
+use crate::input::{FixedActionState, PlayerAction, WeaponRequest};
+use crate::events::{CollisionStarted, DamageEvent, DamageKind, ParryEvent};
+use crate::systems::first_obstacle_hits;
 
-/// System to handle automatic weapon firing
+/// System to read the fire, reload and trigger mode toggle actions
+pub fn weapon_input_system(
+    action_state: Res<FixedActionState>,
+    mut weapon_query: Query<&mut Weapon>,
+    weapon_assets: Res<WeaponAssets>,
+    registries: Res<Assets<WeaponRegistry>>,
+) {
+    let Ok(mut weapon) = weapon_query.single_mut() else {
+        return;
+    };
+
+    weapon.set_trigger(action_state.pressed(PlayerAction::Fire));
+
+    if action_state.just_pressed(PlayerAction::ToggleTriggerMode) {
+        weapon.toggle_trigger_mode();
+        info!("Weapon trigger mode: {:?}", weapon.trigger_mode);
+    }
+
+    if action_state.just_pressed(PlayerAction::Reload)
+        && let Some(definition) = registries.get(&weapon_assets.registry).and_then(|registry| registry.weapons.get(weapon.current))
+    {
+        weapon.start_reload(definition);
+    }
+}
+
+/// System to fire the equipped weapon towards the mouse
 pub fn weapon_firing_system(
     mut commands: Commands,
     mut weapon_query: Query<&mut Weapon>,
-    player_query: Query<&Transform, With<Player>>,
-    indicator_query: Query<&GlobalTransform, (With<crate::components::DirectionIndicator>, Without<Player>)>,
-    window_query: Query<&Window>,
-    camera_query: Query<(&Camera, &GlobalTransform)>,
-    time: Res<Time<Virtual>>,
+    player_query: Query<(Entity, &Transform), (With<Player>, Without<DirectionIndicator>)>,
+    indicator_query: Query<&Transform, (With<DirectionIndicator>, Without<Player>)>,
+    action_state: Res<FixedActionState>,
+    weapon_assets: Res<WeaponAssets>,
+    registries: Res<Assets<WeaponRegistry>>,
+    time: Res<Time>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    mut stats: ResMut<FightStats>,
 ) {
+    // Weapons can't fire until the registry has loaded
+    let Some(registry) = registries.get(&weapon_assets.registry) else {
+        return;
+    };
+
     if let Ok(mut weapon) = weapon_query.single_mut() {
-        // Update the weapon's internal timer
-        weapon.update(time.delta());
-        
-        // In debug mode, weapon automatically fires constantly
-        weapon.is_firing = true;
-        
-        if weapon.is_firing && weapon.can_fire() {
-            // Get direction indicator position and mouse position
-            if let (Ok(_player_transform), Ok(indicator_transform), Ok(window)) = 
-                (player_query.single(), indicator_query.single(), window_query.single()) {
-                
-                if let Ok((camera, camera_transform)) = camera_query.single() {
-                    if let Some(cursor_position) = window.cursor_position() {
-                        // Convert screen coordinates to world coordinates
-                        if let Ok(mouse_world_pos) = camera.viewport_to_world_2d(camera_transform, cursor_position) {
-                            // Fire from direction indicator position
-                            let spawn_position = indicator_transform.translation();
-                            
-                            // Calculate direction towards mouse
-                            let direction = (mouse_world_pos.extend(0.0) - spawn_position).normalize();
-                            
-                            // Create projectile mesh and material
-                            let projectile_mesh = meshes.add(Circle::new(3.0));
-                            let projectile_material = materials.add(ColorMaterial::from(Color::WHITE));
-                            
-                            // Spawn projectile
-                            commands.spawn(ProjectileBundle::new(
-                                weapon.damage,
-                                direction,
-                                weapon.projectile_speed,
-                                3.0, // 3 second lifetime
-                                spawn_position,
-                                projectile_mesh,
-                                projectile_material,
-                            ));
-                            
-                            weapon.fire();
-                        }
-                    }
+        let Some(definition) = registry.weapons.get(weapon.current) else {
+            return;
+        };
+
+        // Get direction indicator position, only fire while aiming
+        let aiming = match (player_query.single(), indicator_query.single(), action_state.aim()) {
+            (Ok((player_entity, player_transform)), Ok(indicator_transform), Some(aim_target)) => {
+                Some((player_entity, player_transform.mul_transform(*indicator_transform), aim_target))
+            }
+            _ => None,
+        };
+
+        // Update the weapon's internal timers
+        if weapon.update(time.delta(), definition, aiming.is_some())
+            && let Some((player_entity, indicator_transform, aim_target)) = aiming
+        {
+            // Fire from direction indicator position, placed with the simulated player as its
+            // `GlobalTransform` is only propagated after the simulation
+            let spawn_position = indicator_transform.translation;
+            
+            // Calculate direction towards the mouse or along the aim stick
+            let aim = aim_target.direction_from(spawn_position.truncate()).unwrap_or(Vec2::X);
+            
+            // Create projectile mesh and material, shared by the whole volley
+            let projectile_mesh = meshes.add(definition.projectile.mesh());
+            let projectile_material = materials.add(ColorMaterial::from(definition.projectile.color()));
+            
+            for direction in definition.volley_directions(aim) {
+                stats.shots_fired += 1;
+                let projectile = Projectile::new(
+                    definition.damage,
+                    direction.extend(0.0),
+                    definition.projectile.lifetime,
+                    Faction::Player,
+                    player_entity,
+                )
+                .with_pierce(definition.pierce)
+                .with_on_hit(definition.on_hit.clone());
+
+                let mut projectile_entity = commands.spawn(
+                    ProjectileBundle::new(
+                        projectile,
+                        definition.projectile.speed,
+                        definition.projectile.collider(),
+                        spawn_position,
+                        projectile_mesh.clone(),
+                        projectile_material.clone(),
+                    )
+                    .facing_direction(),
+                );
+
+                if let Some(turn_rate) = definition.projectile.homing {
+                    projectile_entity.insert(Homing { turn_rate });
                 }
             }
         }
     }
 }
 
+/// System to apply the weapon selected with the number keys or cycled with the mouse wheel,
+/// among the weapons the encounter's loadout allows
+pub fn weapon_switch_system(
+    action_state: Res<FixedActionState>,
+    mut weapon_query: Query<&mut Weapon>,
+    weapon_assets: Res<WeaponAssets>,
+    registries: Res<Assets<WeaponRegistry>>,
+    encounter: Option<Res<ActiveEncounter>>,
+) {
+    let (Some(registry), Ok(mut weapon)) = (registries.get(&weapon_assets.registry), weapon_query.single_mut()) else {
+        return;
+    };
+    let allowed: Vec<usize> = (0..registry.weapons.len())
+        .filter(|index| encounter.as_ref().is_none_or(|encounter| encounter.loadout.allows_weapon(&registry.weapons[*index].name)))
+        .collect();
+    let Some(position) = allowed.iter().position(|index| *index == weapon.current) else {
+        // The equipped weapon is locked, fall back to the first allowed one
+        if let Some(&index) = allowed.first() {
+            weapon.select(index);
+        }
+        return;
+    };
+
+    let selected = match action_state.weapon_request() {
+        Some(WeaponRequest::Select(index)) => Some(index).filter(|index| allowed.contains(index)),
+        Some(WeaponRequest::Next) => Some(allowed[(position + 1) % allowed.len()]),
+        Some(WeaponRequest::Previous) => Some(allowed[(position + allowed.len() - 1) % allowed.len()]),
+        None => None,
+    };
+
+    if let Some(index) = selected.filter(|index| *index != weapon.current) {
+        weapon.select(index);
+        info!("Switched weapon to {}", registry.weapons[index].name);
+    }
+}
+
 /// System to move projectiles
 pub fn projectile_movement_system(
     mut projectile_query: Query<(&mut Transform, &Projectile, &Speed)>,
-    time: Res<Time<Virtual>>,
+    time: Res<Time>,
 ) {
     for (mut transform, projectile, speed) in projectile_query.iter_mut() {
         let movement = projectile.direction * speed.value * time.delta_secs();
@@ -70,11 +159,38 @@ pub fn projectile_movement_system(
     }
 }
 
+/// System to steer homing projectiles towards the nearest boss
+pub fn homing_projectile_system(
+    mut projectile_query: Query<(&mut Transform, &mut Projectile, &Homing)>,
+    boss_query: Query<(), With<Boss>>,
+    spatial_index: Res<SpatialIndex>,
+    time: Res<Time>,
+) {
+    for (mut transform, mut projectile, homing) in projectile_query.iter_mut() {
+        if projectile.faction != Faction::Player {
+            continue;
+        }
+
+        let position = transform.translation.truncate();
+        let nearest_boss = spatial_index.nearest(position, f32::INFINITY, |entry| boss_query.contains(entry.entity));
+
+        if let Some(boss) = nearest_boss {
+            let direction = projectile.direction.truncate();
+            let desired = (boss.position - position).normalize_or(direction);
+            let max_turn = homing.turn_rate * time.delta_secs();
+            let turn = direction.angle_to(desired).clamp(-max_turn, max_turn);
+
+            projectile.direction = Vec2::from_angle(turn).rotate(direction).extend(0.0);
+            transform.rotation = Quat::from_rotation_z(projectile.direction.truncate().to_angle());
+        }
+    }
+}
+
 /// System to update projectile lifetimes and despawn expired ones
 pub fn projectile_lifetime_system(
     mut commands: Commands,
     mut projectile_query: Query<(Entity, &mut Projectile)>,
-    time: Res<Time<Virtual>>,
+    time: Res<Time>,
 ) {
     for (entity, mut projectile) in projectile_query.iter_mut() {
         if projectile.update(time.delta()) {
@@ -84,34 +200,208 @@ pub fn projectile_lifetime_system(
     }
 }
 
-/// System to handle projectile collision with bosses
+/// System to apply player projectile hits on the bosses, or boss parts, they started touching, in the
+/// order they were hit during the tick
 pub fn projectile_boss_collision_system(
     mut commands: Commands,
-    projectile_query: Query<(Entity, &Transform, &Projectile, &Collider), Without<Boss>>,
-    mut boss_query: Query<(Entity, &Transform, &mut Health, &Collider), With<Boss>>,
+    mut projectile_query: Query<(&Transform, &InterpolatedTransform, &mut Projectile), Without<Boss>>,
+    boss_query: Query<(), With<Boss>>,
+    obstacle_query: Query<(), With<Obstacle>>,
+    hitboxes: BossHitboxes,
+    spatial_index: Res<SpatialIndex>,
+    mut collisions: EventReader<CollisionStarted>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut stats: ResMut<FightStats>,
 ) {
-    for (projectile_entity, projectile_transform, projectile, projectile_collider) in projectile_query.iter() {
-        for (boss_entity, boss_transform, mut boss_health, boss_collider) in boss_query.iter_mut() {
-            let distance = projectile_transform.translation.distance(boss_transform.translation);
-            let collision_radius = projectile_collider.radius + boss_collider.radius;
-            
-            if distance < collision_radius {
-                // Deal damage to boss
-                boss_health.take_damage(projectile.damage);
-                
-                info!("Projectile hit boss for {} damage! Boss health: {:.1}/{:.1}", 
-                    projectile.damage, boss_health.current, boss_health.max);
-                
-                // Despawn the projectile
-                commands.entity(projectile_entity).despawn();
-                
-                if !boss_health.is_alive() {
-                    info!("Boss has been defeated! Despawning boss entity.");
-                    commands.entity(boss_entity).despawn();
+    let mut started: Vec<&CollisionStarted> = collisions.read().collect();
+    started.sort_by(|a, b| a.time_of_impact.total_cmp(&b.time_of_impact));
+    let blocked = first_obstacle_hits(started.iter().copied(), |entity| projectile_query.contains(entity), |entity| obstacle_query.contains(entity));
+
+    // Projectiles used up by an earlier hit this tick
+    let mut spent = Vec::new();
+    for collision in started {
+        // Hits on a boss part count as hits on the boss
+        let Some((projectile_entity, boss_entity)) = collision
+            .pair
+            .matching(|entity| projectile_query.contains(entity), |entity| hitboxes.boss_of(entity).is_some())
+            .and_then(|(projectile_entity, hitbox)| Some((projectile_entity, hitboxes.boss_of(hitbox)?)))
+        else {
+            continue;
+        };
+        let Ok((projectile_transform, interpolated, mut projectile)) = projectile_query.get_mut(projectile_entity) else {
+            continue;
+        };
+        if projectile.faction != Faction::Player || spent.contains(&projectile_entity) || projectile.hit_entities.contains(&boss_entity) {
+            continue;
+        }
+        if is_behind_obstacle(&blocked, projectile_entity, collision.time_of_impact) {
+            continue;
+        }
+        // Fast projectiles may have flown past the boss by the end of the tick
+        let impact = interpolated.translation_at(projectile_transform, collision.time_of_impact);
+        let position = impact.truncate();
+
+        if projectile.hit_entities.is_empty() {
+            stats.shots_hit += 1;
+        }
+        damage_events.write(DamageEvent {
+            source: projectile_entity,
+            target: boss_entity,
+            amount: projectile.damage,
+            kind: DamageKind::Projectile,
+            direction: projectile.direction.truncate(),
+        });
+
+        for effect in &projectile.on_hit {
+            match *effect {
+                OnHitEffect::Explode { radius, damage } => {
+                    let targets = spatial_index
+                        .query_circle(position, radius)
+                        .filter(|entry| boss_query.contains(entry.entity));
+                    for target in targets {
+                        damage_events.write(DamageEvent {
+                            source: projectile.owner,
+                            target: target.entity,
+                            amount: damage,
+                            kind: DamageKind::Explosion,
+                            direction: (target.position - position).normalize_or_zero(),
+                        });
+                    }
+
+                    // Short explosion flash
+                    commands.spawn((
+                        ExplosionFlash {
+                            timer: Timer::from_seconds(0.25, TimerMode::Once),
+                        },
+                        Transform::from_translation(impact),
+                        Mesh2d(meshes.add(Circle::new(radius))),
+                        MeshMaterial2d(materials.add(Color::srgba(1.0, 0.5, 0.1, 0.5))),
+                    ));
+                }
+                OnHitEffect::Burn { damage_per_second, duration } => {
+                    // Re-applying a burn refreshes it
+                    commands.entity(boss_entity).try_insert(Burning::new(projectile.owner, damage_per_second, duration));
+                }
+            }
+        }
+
+        // Piercing projectiles keep flying until they run out of pierce
+        if projectile.register_hit(boss_entity) {
+            commands.entity(projectile_entity).despawn();
+            spent.push(projectile_entity);
+        }
+    }
+}
+
+/// System to apply the hits of hostile projectiles that started touching the player. Those touching the
+/// raised shield are reflected or absorbed instead, unless they reached the player first.
+pub fn projectile_player_collision_system(
+    mut commands: Commands,
+    mut projectile_query: Query<(&Transform, &mut Projectile, &mut CollisionLayers, &mut MeshMaterial2d<ColorMaterial>, Option<&ShieldBlockResponse>), Without<Player>>,
+    mut player_query: Query<(Entity, &mut Energy, &Children), With<Player>>,
+    shield_query: Query<&Shield>,
+    boss_query: Query<&Transform, (With<Boss>, Without<Player>, Without<Projectile>)>,
+    obstacle_query: Query<(), With<Obstacle>>,
+    mut collisions: EventReader<CollisionStarted>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut parry_events: EventWriter<ParryEvent>,
+    mut stats: ResMut<FightStats>,
+) {
+    let Ok((player_entity, mut energy, children)) = player_query.single_mut() else {
+        return;
+    };
+    let started: Vec<&CollisionStarted> = collisions.read().collect();
+    let blocked = first_obstacle_hits(started.iter().copied(), |entity| projectile_query.contains(entity), |entity| obstacle_query.contains(entity));
+    // Projectiles that reached the player's body, with when they did
+    let body_hits: Vec<(Entity, f32)> = started
+        .iter()
+        .filter_map(|collision| {
+            let (projectile_entity, _) = collision.pair.matching(|entity| projectile_query.contains(entity), |entity| entity == player_entity)?;
+            Some((projectile_entity, collision.time_of_impact))
+        })
+        .filter(|&(projectile_entity, time_of_impact)| !is_behind_obstacle(&blocked, projectile_entity, time_of_impact))
+        .collect();
+
+    let mut shielded = Vec::new();
+    if let Some((shield_entity, shield)) = children.iter().find_map(|child| shield_query.get(child).ok().map(|shield| (child, shield))) {
+        for collision in &started {
+            let Some((projectile_entity, _)) = collision.pair.matching(|entity| projectile_query.contains(entity), |entity| entity == shield_entity) else {
+                continue;
+            };
+            // Fast projectiles from behind pass through the player before reaching the shield
+            if body_hits.iter().any(|&(hit, time_of_impact)| hit == projectile_entity && time_of_impact < collision.time_of_impact) {
+                continue;
+            }
+            if is_behind_obstacle(&blocked, projectile_entity, collision.time_of_impact) {
+                continue;
+            }
+            let Ok((projectile_transform, mut projectile, mut layers, mut material, Some(block_response))) = projectile_query.get_mut(projectile_entity) else {
+                continue;
+            };
+            if projectile.faction != Faction::Enemy {
+                continue;
+            }
+
+            // The shield's collider covers the whole parry arc while it can parry
+            if shield.is_parrying() {
+                parry_events.write(ParryEvent {
+                    player: player_entity,
+                    attacker: projectile.owner,
+                    kind: DamageKind::Projectile,
+                    position: projectile_transform.translation.truncate(),
+                    stagger_duration: shield.parry_stagger_duration,
+                });
+            } else {
+                stats.shield_blocks += 1;
+            }
+            match *block_response {
+                ShieldBlockResponse::Reflect => {
+                    // Send it back at the boss that fired it, or straight back if the boss is gone
+                    let attacker = projectile.owner;
+                    let direction = boss_query
+                        .get(attacker)
+                        .map(|boss_transform| (boss_transform.translation - projectile_transform.translation).truncate().normalize_or_zero())
+                        .unwrap_or(-projectile.direction.truncate());
+                    projectile.reflect(player_entity, Faction::Player, direction.extend(0.0));
+                    *layers = CollisionLayers::new(Faction::Player.collision_layer());
+                    material.0 = materials.add(ColorMaterial::from(Color::WHITE));
+                    info!("Shield reflected projectile from {:?}!", attacker);
+                }
+                ShieldBlockResponse::Absorb { energy: restored } => {
+                    energy.restore(restored);
+                    commands.entity(projectile_entity).despawn();
+                    info!("Shield absorbed projectile, restored {:.1} energy", restored);
                 }
             }
+            shielded.push(projectile_entity);
         }
     }
+
+    for (projectile_entity, _) in body_hits {
+        let Ok((_, projectile, ..)) = projectile_query.get(projectile_entity) else {
+            continue;
+        };
+        if projectile.faction != Faction::Enemy || shielded.contains(&projectile_entity) {
+            continue;
+        }
+        damage_events.write(DamageEvent {
+            source: projectile.owner,
+            target: player_entity,
+            amount: projectile.damage,
+            kind: DamageKind::Projectile,
+            direction: projectile.direction.truncate(),
+        });
+
+        commands.entity(projectile_entity).despawn();
+    }
+}
+
+/// Whether `projectile_entity` hit an obstacle before reaching what it touched at `time_of_impact`
+fn is_behind_obstacle(blocked: &EntityHashMap<(Entity, f32)>, projectile_entity: Entity, time_of_impact: f32) -> bool {
+    blocked.get(&projectile_entity).is_some_and(|&(_, blocked_at)| blocked_at < time_of_impact)
 }
 
 /// System to clean up projectiles
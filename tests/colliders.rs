@@ -0,0 +1,174 @@
+use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+use bevy::prelude::*;
+use gun_shield_elements::collision::WorldShape;
+use gun_shield_elements::components::Collider;
+
+fn at(x: f32, y: f32) -> Transform {
+    Transform::from_xyz(x, y, 0.0)
+}
+
+fn at_angle(x: f32, y: f32, angle: f32) -> Transform {
+    at(x, y).with_rotation(Quat::from_rotation_z(angle))
+}
+
+fn overlaps(a: Collider, a_at: Transform, b: Collider, b_at: Transform) -> bool {
+    let result = a.overlaps(&a_at, &b, &b_at);
+    assert_eq!(result, b.overlaps(&b_at, &a, &a_at), "overlap tests should be symmetric for {a:?} and {b:?}");
+    result
+}
+
+fn contains(collider: Collider, transform: Transform, point: Vec2) -> bool {
+    collider.overlaps(&transform, &Collider::circle(0.0), &at(point.x, point.y))
+}
+
+/// The shield's shape: a band of radius 32 facing +X over a quarter turn
+fn shield_arc() -> Collider {
+    Collider::Arc { radius: 32.0, thickness: 4.0, center_angle: 0.0, half_angle: FRAC_PI_4 }
+}
+
+#[test]
+fn boxes_overlap_by_their_sides_not_their_bounding_circles() {
+    let square = Collider::AlignedBox { half_size: Vec2::splat(10.0) };
+    let turned = Collider::OrientedBox { half_size: Vec2::splat(10.0) };
+    let ball = Collider::circle(3.0);
+
+    // Beside a side but within the bounding circle
+    assert!(!overlaps(square, at(0.0, 0.0), ball, at(0.0, 15.0)));
+    assert!(overlaps(square, at(0.0, 0.0), ball, at(12.0, 0.0)));
+    // Turned by 45 degrees the corner points along the X axis, aligned boxes ignore the rotation
+    assert!(overlaps(turned, at_angle(0.0, 0.0, FRAC_PI_4), ball, at(16.0, 0.0)));
+    assert!(!overlaps(square, at_angle(0.0, 0.0, FRAC_PI_4), ball, at(16.0, 0.0)));
+    // Two boxes, edge to corner
+    assert!(overlaps(turned, at_angle(0.0, 0.0, FRAC_PI_4), square, at(23.0, 0.0)));
+    assert!(!overlaps(turned, at_angle(0.0, 0.0, FRAC_PI_4), square, at(25.0, 0.0)));
+    // A small box inside a large one
+    assert!(overlaps(Collider::AlignedBox { half_size: Vec2::splat(1.0) }, at(2.0, 2.0), square, at(0.0, 0.0)));
+}
+
+#[test]
+fn capsules_cover_their_whole_length() {
+    let bolt = Collider::Capsule { radius: 2.0, half_length: 20.0 };
+
+    assert!(overlaps(bolt, at(0.0, 0.0), Collider::circle(5.0), at(26.0, 0.0)));
+    assert!(!overlaps(bolt, at(0.0, 0.0), Collider::circle(5.0), at(28.0, 0.0)));
+    assert!(!overlaps(bolt, at(0.0, 0.0), Collider::circle(5.0), at(0.0, 8.0)));
+    // Rotated a quarter turn it lies along the Y axis instead
+    assert!(overlaps(bolt, at_angle(0.0, 0.0, FRAC_PI_2), Collider::circle(5.0), at(0.0, 26.0)));
+    // Crossing capsules touch in the middle even though no end is near the other
+    assert!(overlaps(bolt, at(0.0, 0.0), bolt, at_angle(0.0, 0.0, FRAC_PI_2)));
+    assert!(overlaps(bolt, at(0.0, 0.0), Collider::AlignedBox { half_size: Vec2::new(1.0, 30.0) }, at(0.0, 0.0)));
+}
+
+#[test]
+fn arcs_only_cover_their_band_and_span() {
+    let arc = shield_arc();
+    let bullet = Collider::circle(4.0);
+
+    // In front of the arc, behind it and inside the ring it bends around
+    assert!(overlaps(arc, at(0.0, 0.0), bullet, at(37.0, 0.0)));
+    assert!(!overlaps(arc, at(0.0, 0.0), bullet, at(-37.0, 0.0)));
+    assert!(!overlaps(arc, at(0.0, 0.0), bullet, at(0.0, 0.0)));
+    assert!(!overlaps(arc, at(0.0, 0.0), bullet, at(0.0, 37.0)));
+    // Turning the entity turns the arc
+    assert!(overlaps(arc, at_angle(0.0, 0.0, PI), bullet, at(-37.0, 0.0)));
+    // Past the end of the arc, the rounded end still reaches a little further
+    let end = Vec2::from_angle(FRAC_PI_4) * 32.0;
+    assert!(overlaps(arc, at(0.0, 0.0), bullet, at(end.x - 1.0, end.y + 5.0)));
+    // A beam passing through the arc without touching the ends
+    let beam = Collider::Capsule { radius: 1.0, half_length: 30.0 };
+    assert!(overlaps(arc, at(0.0, 0.0), beam, at_angle(32.0, 0.0, FRAC_PI_2)));
+    assert!(!overlaps(arc, at(0.0, 0.0), beam, at_angle(18.0, 0.0, FRAC_PI_2)));
+    // A box the arc passes through, and one the arc fits inside
+    assert!(overlaps(arc, at(0.0, 0.0), Collider::AlignedBox { half_size: Vec2::splat(5.0) }, at(32.0, 0.0)));
+    assert!(overlaps(arc, at(0.0, 0.0), Collider::AlignedBox { half_size: Vec2::splat(50.0) }, at(0.0, 0.0)));
+    assert!(!overlaps(arc, at(0.0, 0.0), Collider::AlignedBox { half_size: Vec2::splat(5.0) }, at(0.0, 0.0)));
+}
+
+#[test]
+fn arcs_overlap_each_other_where_they_cross() {
+    let arc = shield_arc();
+
+    // Facing each other across a gap, then close enough to cross
+    assert!(!overlaps(arc, at(0.0, 0.0), arc, at_angle(70.0, 0.0, PI)));
+    assert!(overlaps(arc, at(0.0, 0.0), arc, at_angle(66.0, 0.0, PI)));
+    assert!(overlaps(arc, at(0.0, 0.0), arc, at_angle(48.0, 0.0, PI)));
+    // Back to back they never meet
+    assert!(!overlaps(arc, at(0.0, 0.0), arc, at(40.0, 0.0)));
+    // Same center, only the radius differs
+    let wide = Collider::Arc { radius: 35.0, thickness: 4.0, center_angle: FRAC_PI_2, half_angle: PI };
+    assert!(overlaps(arc, at(0.0, 0.0), wide, at(0.0, 0.0)));
+}
+
+/// Any point found inside both shapes means they overlap, for assorted shapes at evenly distributed but
+/// irregular positions and angles
+#[test]
+fn shapes_sharing_a_point_overlap() {
+    let shapes = [
+        Collider::circle(6.0),
+        Collider::AlignedBox { half_size: Vec2::new(8.0, 3.0) },
+        Collider::OrientedBox { half_size: Vec2::new(5.0, 9.0) },
+        Collider::Capsule { radius: 2.0, half_length: 10.0 },
+        Collider::Arc { radius: 12.0, thickness: 3.0, center_angle: 1.0, half_angle: 1.2 },
+    ];
+    let placed = |i: usize| {
+        let fraction = Vec2::new((i as f32 * 0.618_034).fract(), (i as f32 * 0.754_878).fract());
+        let position = (fraction - 0.5) * 40.0;
+        (shapes[i % shapes.len()], at_angle(position.x, position.y, i as f32 * 2.399_963))
+    };
+    let samples: Vec<Vec2> = (0..=80)
+        .flat_map(|x| (0..=80).map(move |y| Vec2::new(x as f32, y as f32) * 0.5 - 20.0))
+        .collect();
+
+    for i in 0..40 {
+        let (a, a_at) = placed(i);
+        for j in i + 1..40 {
+            let (b, b_at) = placed(j);
+            if samples.iter().any(|point| contains(a, a_at, *point) && contains(b, b_at, *point)) {
+                assert!(overlaps(a, a_at, b, b_at), "{a:?} at {a_at:?} and {b:?} at {b_at:?} share a point");
+            }
+        }
+    }
+}
+
+#[test]
+fn swept_shapes_report_when_they_first_touched() {
+    let bullet = Collider::circle(4.0);
+    let wall = WorldShape::of(&Collider::OrientedBox { half_size: Vec2::new(2.0, 30.0) }, &at(0.0, 0.0));
+    let time_of_impact = |end: Transform, displacement: Vec2| WorldShape::of(&bullet, &end).time_of_impact(displacement, &wall);
+
+    // Flew from x = -100 to x = 100 through the thin wall, first touching it at x = -6
+    let time = time_of_impact(at(100.0, 0.0), Vec2::new(200.0, 0.0)).expect("the bullet passed through the wall");
+    assert!((time - 0.47).abs() < 0.01, "touched at {time}");
+    // Passing beside the wall, and stopping short of it
+    assert_eq!(time_of_impact(at(100.0, 40.0), Vec2::new(200.0, 0.0)), None);
+    assert_eq!(time_of_impact(at(-10.0, 0.0), Vec2::new(90.0, 0.0)), None);
+    // Already touching at the start of the tick, and not moving at all
+    assert_eq!(time_of_impact(at(50.0, 0.0), Vec2::new(50.0, 0.0)), Some(0.0));
+    assert_eq!(time_of_impact(at(5.0, 0.0), Vec2::ZERO), Some(0.0));
+    // Turning the wall so it lies along the path
+    let turned = WorldShape::of(&Collider::OrientedBox { half_size: Vec2::new(2.0, 30.0) }, &at_angle(0.0, 0.0, FRAC_PI_2));
+    let time = WorldShape::of(&bullet, &at(100.0, 0.0)).time_of_impact(Vec2::new(200.0, 0.0), &turned).expect("the bullet flew along the wall");
+    assert!((time - 0.33).abs() < 0.01, "touched at {time}");
+}
+
+#[test]
+fn circles_are_pushed_out_of_obstacles_the_shortest_way() {
+    let push_out = |ball: Transform, obstacle: Collider, obstacle_at: Transform| {
+        WorldShape::of(&Collider::circle(10.0), &ball).push_out(&WorldShape::of(&obstacle, &obstacle_at))
+    };
+    let wall = Collider::OrientedBox { half_size: Vec2::new(50.0, 5.0) };
+
+    // Overlapping the top of the wall, and with its center inside it near the bottom
+    assert_eq!(push_out(at(20.0, 12.0), wall, at(0.0, 0.0)), Some(Vec2::new(0.0, 3.0)));
+    assert_eq!(push_out(at(20.0, -4.0), wall, at(0.0, 0.0)), Some(Vec2::new(0.0, -11.0)));
+    // Past the end, out along the diagonal from the corner
+    let pushed = push_out(at(53.0, 9.0), wall, at(0.0, 0.0)).expect("the ball touches the corner");
+    let distance = (Vec2::new(53.0, 9.0) + pushed).distance(Vec2::new(50.0, 5.0));
+    assert!((distance - 10.0).abs() < 1e-4, "pushed to {distance} from the corner");
+    // A turned wall pushes along its own sides
+    let pushed = push_out(at(2.0, 12.0), wall, at_angle(0.0, 0.0, FRAC_PI_2)).expect("the ball overlaps the wall");
+    assert!((pushed - Vec2::new(13.0, 0.0)).length() < 1e-4, "pushed by {pushed}");
+    // Pillars push straight away from their center
+    assert_eq!(push_out(at(0.0, 25.0), Collider::circle(20.0), at(0.0, 0.0)), Some(Vec2::new(0.0, 5.0)));
+    assert_eq!(push_out(at(0.0, 35.0), Collider::circle(20.0), at(0.0, 0.0)), None);
+}
@@ -0,0 +1,603 @@
+use std::time::Duration;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use gun_shield_elements::assets::{EncounterSequence, Loadout, ObstacleDefinition, ObstacleShape, WeaponRegistry};
+use gun_shield_elements::components::{Boss, BossSkills, AttackStage, Collider, Energy, Faction, Health, InterpolatedTransform, Mine, ObstacleBundle, Player, Projectile, ProjectileBundle, Shield, ShieldBlockResponse, Speed, Weapon};
+use gun_shield_elements::collision::CollisionPair;
+use gun_shield_elements::events::{CollisionEnded, CollisionOngoing, CollisionStarted, DamageEvent, DamageKind};
+use gun_shield_elements::input::{InputBinding, InputBindings, InputSlot, Replay, TickInput};
+use gun_shield_elements::headless::{headless_app, HeadlessAppExt, HEADLESS_TIMESTEP};
+use gun_shield_elements::persistence::{ConfigStorage, Profile, StorageLocation};
+use gun_shield_elements::resources::{Difficulty, EncounterAssets, FightStats, LastReplay, ReplayPlayback, SimulationSettings};
+use gun_shield_elements::states::AppState;
+use gun_shield_elements::systems::find_last_replay;
+
+/// Start the first encounter of the campaign and wait for its Mine boss to spawn
+fn start_game() -> App {
+    start_game_on(Difficulty::Normal)
+}
+
+/// Start the first encounter of the campaign on `difficulty` and wait for its Mine boss to spawn
+fn start_game_on(difficulty: Difficulty) -> App {
+    let mut app = headless_app();
+    load_fight_assets(&mut app);
+    // After the startup systems, which set the difficulty from the profile
+    app.insert_resource(difficulty);
+    app.enter_state(AppState::Game);
+    let spawned = app.wait_for(Duration::from_secs(10), |world| {
+        world.query_filtered::<(), With<Boss>>().iter(world).next().is_some()
+    });
+    assert!(spawned, "Mine boss should spawn once its definition has loaded");
+    app
+}
+
+fn player(app: &mut App) -> Entity {
+    let world = app.world_mut();
+    world.query_filtered::<Entity, With<Player>>().single(world).expect("player should exist")
+}
+
+fn boss(app: &mut App) -> Entity {
+    let world = app.world_mut();
+    world.query_filtered::<Entity, With<Boss>>().single(world).expect("boss should exist")
+}
+
+fn player_health(app: &mut App) -> f32 {
+    let world = app.world_mut();
+    world.query_filtered::<&Health, With<Player>>().single(world).expect("player should exist").current
+}
+
+fn shield_active(app: &mut App) -> bool {
+    let world = app.world_mut();
+    world.query::<&Shield>().single(world).expect("shield should exist").is_active
+}
+
+fn position(app: &mut App, entity: Entity) -> Vec2 {
+    app.world().get::<Transform>(entity).expect("entity should have a transform").translation.truncate()
+}
+
+fn current_state(world: &World) -> AppState {
+    world.resource::<State<AppState>>().get().clone()
+}
+
+/// Remove the boss so only injected damage reaches the player
+fn despawn_boss(app: &mut App) {
+    let boss = boss(app);
+    app.world_mut().entity_mut(boss).despawn();
+    app.update();
+}
+
+/// Raise the shield towards `target` and hold it until the parry window has passed
+fn raise_shield_towards(app: &mut App, target: Vec2) {
+    app.aim_at(target);
+    app.press_mouse_button(MouseButton::Right);
+    app.run_for(0.5);
+}
+
+/// Place an obstacle as the arena would
+fn spawn_obstacle(app: &mut App, shape: ObstacleShape, position: Vec2) -> Entity {
+    let definition = ObstacleDefinition { shape, position: (position.x, position.y), rotation: 0.0 };
+    let mut obstacle = app.world_mut().spawn(ObstacleBundle::new(&definition, Handle::default(), Handle::default()));
+    if let Some(health) = definition.health() {
+        obstacle.insert(Health::new(health));
+    }
+    obstacle.id()
+}
+
+fn dash_hit(source: Entity, target: Entity, from: Vec2, to: Vec2) -> DamageEvent {
+    DamageEvent {
+        source,
+        target,
+        amount: 25.0,
+        kind: DamageKind::Dash,
+        direction: (to - from).normalize(),
+    }
+}
+
+#[test]
+fn player_standing_still_dies_to_mine_boss() {
+    let mut app = start_game();
+
+    let time_to_death = app.run_until(20.0, |world| current_state(world) == AppState::GameOver);
+
+    assert!(time_to_death.is_some(), "idle player should die to the Mine boss within 20 seconds");
+}
+
+#[test]
+fn shield_facing_boss_halves_dash_damage() {
+    let mut app = start_game();
+    despawn_boss(&mut app);
+    let player = player(&mut app);
+    let player_position = position(&mut app, player);
+    let attacker_position = player_position + Vec2::new(200.0, 0.0);
+
+    raise_shield_towards(&mut app, attacker_position);
+    let health_before = player_health(&mut app);
+    app.world_mut().send_event(dash_hit(Entity::PLACEHOLDER, player, attacker_position, player_position));
+    app.update();
+
+    assert_eq!(health_before - player_health(&mut app), 12.5);
+}
+
+#[test]
+fn shield_facing_away_does_not_block_dash_damage() {
+    let mut app = start_game();
+    despawn_boss(&mut app);
+    let player = player(&mut app);
+    let player_position = position(&mut app, player);
+    let attacker_position = player_position + Vec2::new(200.0, 0.0);
+
+    raise_shield_towards(&mut app, player_position - Vec2::new(200.0, 0.0));
+    let health_before = player_health(&mut app);
+    app.world_mut().send_event(dash_hit(Entity::PLACEHOLDER, player, attacker_position, player_position));
+    app.update();
+
+    assert_eq!(health_before - player_health(&mut app), 25.0);
+}
+
+#[test]
+fn perfect_parry_negates_damage_and_staggers_boss() {
+    let mut app = start_game();
+    let player = player(&mut app);
+    let boss = boss(&mut app);
+    let player_position = position(&mut app, player);
+    let boss_position = position(&mut app, boss);
+
+    app.aim_at(boss_position);
+    app.press_mouse_button(MouseButton::Right);
+    app.update();
+    let health_before = player_health(&mut app);
+    app.world_mut().send_event(dash_hit(boss, player, boss_position, player_position));
+    app.update();
+    app.update();
+
+    assert_eq!(player_health(&mut app), health_before);
+    let skills = app.world().get::<BossSkills>(boss).expect("boss should have skills");
+    assert_eq!(skills.dash_attack.stage, AttackStage::Staggered);
+}
+
+#[test]
+fn raised_shield_stops_projectiles_from_the_front_only() {
+    let mut app = start_game();
+    despawn_boss(&mut app);
+    let player = player(&mut app);
+    let player_position = position(&mut app, player);
+
+    raise_shield_towards(&mut app, player_position + Vec2::new(200.0, 0.0));
+    let health_before = player_health(&mut app);
+    // One projectile flying at the shield and one at the player's back
+    for side in [1.0, -1.0] {
+        app.world_mut().spawn((
+            ProjectileBundle::new(
+                Projectile::new(10.0, Vec3::new(-side, 0.0, 0.0), 2.0, Faction::Enemy, Entity::PLACEHOLDER),
+                300.0,
+                Collider::circle(4.0),
+                (player_position + Vec2::new(side * 80.0, 0.0)).extend(0.9),
+                Handle::default(),
+                Handle::default(),
+            ),
+            ShieldBlockResponse::Absorb { energy: 0.0 },
+        ));
+    }
+    app.run_for(0.5);
+
+    assert_eq!(health_before - player_health(&mut app), 10.0, "only the projectile from behind should hit");
+    assert_eq!(app.world().resource::<FightStats>().shield_blocks, 1);
+}
+
+#[test]
+fn fast_projectiles_hit_what_they_pass_through_between_ticks() {
+    let mut app = start_game();
+    despawn_boss(&mut app);
+    let player = player(&mut app);
+    let player_position = position(&mut app, player);
+
+    raise_shield_towards(&mut app, player_position + Vec2::new(200.0, 0.0));
+    let health_before = player_health(&mut app);
+    // 150 units a tick, past the player and shield from either side in a single tick
+    for side in [1.0, -1.0] {
+        app.world_mut().spawn((
+            ProjectileBundle::new(
+                Projectile::new(10.0, Vec3::new(-side, 0.0, 0.0), 2.0, Faction::Enemy, Entity::PLACEHOLDER),
+                9000.0,
+                Collider::circle(4.0),
+                (player_position + Vec2::new(side * 225.0, 0.0)).extend(0.9),
+                Handle::default(),
+                Handle::default(),
+            ),
+            ShieldBlockResponse::Absorb { energy: 0.0 },
+        ));
+    }
+    app.run_for(0.1);
+
+    assert_eq!(health_before - player_health(&mut app), 10.0, "the projectile from behind should hit the player before the shield");
+    assert_eq!(app.world().resource::<FightStats>().shield_blocks, 1, "the projectile from the front should hit the shield");
+}
+
+#[test]
+fn holding_fire_without_aim_uses_no_rounds() {
+    let mut app = start_game();
+    despawn_boss(&mut app);
+
+    app.press_mouse_button(MouseButton::Left);
+    app.run_for(1.0);
+
+    let world = app.world_mut();
+    assert_eq!(world.query::<&Projectile>().iter(world).count(), 0, "nothing should be fired without a target");
+    let weapon = world.query::<&Weapon>().single(world).expect("player should have a weapon");
+    assert!(weapon.magazines.is_empty(), "the magazine should still be full");
+}
+
+#[test]
+fn walls_stop_the_player_walking_and_dashing() {
+    let mut app = start_game();
+    despawn_boss(&mut app);
+    let player = player(&mut app);
+    let start = position(&mut app, player);
+    spawn_obstacle(&mut app, ObstacleShape::Wall { length: 400.0, thickness: 20.0 }, start + Vec2::new(0.0, 150.0));
+
+    app.press_key(KeyCode::KeyW);
+    app.run_for(0.2);
+    app.press_key(KeyCode::Space);
+    app.run_for(1.0);
+
+    // The wall's near side is 140 up, the player's radius 25 below that
+    let reached = position(&mut app, player).y - start.y;
+    assert!((114.0..=115.5).contains(&reached), "player should stop against the wall, moved {reached}");
+}
+
+#[test]
+fn boss_dashing_into_a_pillar_stuns_itself() {
+    let mut app = start_game();
+    let (player, boss) = (player(&mut app), boss(&mut app));
+    let (player_position, boss_position) = (position(&mut app, player), position(&mut app, boss));
+    let pillar_position = player_position.midpoint(boss_position);
+    spawn_obstacle(&mut app, ObstacleShape::Pillar { radius: 20.0 }, pillar_position);
+
+    let stunned = app.run_until(6.0, |world| {
+        world.get::<BossSkills>(boss).expect("boss should have skills").dash_attack.stage == AttackStage::Staggered
+    });
+
+    assert!(stunned.is_some(), "boss should stun itself on the pillar");
+    let distance = position(&mut app, boss).distance(pillar_position);
+    assert!(distance >= 49.0, "boss should stop outside the pillar, {distance} from its center");
+}
+
+/// Drop the Mine boss to `health_fraction` of its health and record, after every tick for `seconds`,
+/// the time, its dash stage and the follow-up dashes left in its chain. The player can't die, and
+/// the boss only moves by dashing so it never settles next to the player, where it doesn't dash.
+fn dash_history(health_fraction: f32, seconds: f32) -> Vec<(f32, AttackStage, u32)> {
+    let mut app = start_game();
+    let (player, boss) = (player(&mut app), boss(&mut app));
+    app.world_mut().get_mut::<Health>(player).expect("player should have health").current = f32::MAX;
+    let mut boss_health = app.world_mut().get_mut::<Health>(boss).expect("boss should have health");
+    boss_health.current = boss_health.max * health_fraction;
+    // Entering the phase sets the boss's speed
+    app.update();
+    app.world_mut().get_mut::<Speed>(boss).expect("boss should have a speed").value = 0.0;
+
+    let ticks = (seconds / HEADLESS_TIMESTEP).round() as u32;
+    (1..=ticks)
+        .map(|tick| {
+            app.update();
+            let skills = app.world().get::<BossSkills>(boss).expect("boss should have skills");
+            (tick as f32 * HEADLESS_TIMESTEP, skills.dash_attack.stage, skills.dash_chain_remaining)
+        })
+        .collect()
+}
+
+#[test]
+fn boss_starts_the_next_dash_chain_as_soon_as_it_recovers_in_the_last_phase() {
+    // A last phase chain takes longer than the phase's dash cooldown, so nothing holds the next one back
+    let history = dash_history(0.2, 20.0);
+
+    let gaps: Vec<f32> = history
+        .windows(2)
+        .enumerate()
+        .filter(|(_, pair)| pair[0].1 == AttackStage::Recovery && pair[0].2 == 0 && pair[1].1 != AttackStage::Recovery)
+        .filter_map(|(index, pair)| {
+            let next_windup = history[index + 1..].iter().find(|(_, stage, _)| *stage == AttackStage::Windup)?;
+            Some(next_windup.0 - pair[1].0)
+        })
+        .collect();
+
+    assert!(gaps.len() >= 2, "boss should finish several dash chains, finished {}", gaps.len());
+    assert!(gaps.iter().all(|gap| *gap < 0.1), "boss should dash again right after a chain, waited {gaps:?}");
+}
+
+#[test]
+fn boss_dashes_more_often_in_later_phases() {
+    let dashes = |health_fraction: f32| {
+        let history = dash_history(health_fraction, 20.0);
+        history.windows(2).filter(|pair| pair[0].1 != AttackStage::Windup && pair[1].1 == AttackStage::Windup).count()
+    };
+    let (first_phase, second_phase, last_phase) = (dashes(1.0), dashes(0.5), dashes(0.2));
+
+    assert!(first_phase < second_phase, "second phase should dash more than the first, {second_phase} vs {first_phase}");
+    assert!(second_phase < last_phase, "last phase should dash more than the second, {last_phase} vs {second_phase}");
+}
+
+#[test]
+fn projectiles_break_crates_and_stop_at_pillars() {
+    let mut app = start_game();
+    despawn_boss(&mut app);
+    let player = player(&mut app);
+    let player_position = position(&mut app, player);
+    let crate_entity = spawn_obstacle(&mut app, ObstacleShape::Crate { size: 40.0, health: 15.0 }, player_position + Vec2::new(200.0, 0.0));
+    spawn_obstacle(&mut app, ObstacleShape::Pillar { radius: 30.0 }, player_position - Vec2::new(200.0, 0.0));
+
+    let health_before = player_health(&mut app);
+    // Two shots into the crate, and a hostile one at the player from behind the pillar
+    let shots = [
+        (Faction::Player, Vec3::X, player_position + Vec2::new(60.0, 10.0)),
+        (Faction::Player, Vec3::X, player_position + Vec2::new(60.0, -10.0)),
+        (Faction::Enemy, Vec3::X, player_position - Vec2::new(400.0, 0.0)),
+    ];
+    for (faction, direction, position) in shots {
+        app.world_mut().spawn(ProjectileBundle::new(
+            Projectile::new(10.0, direction, 2.0, faction, player),
+            600.0,
+            Collider::circle(4.0),
+            position.extend(0.9),
+            Handle::default(),
+            Handle::default(),
+        ));
+    }
+    app.run_for(1.0);
+
+    assert!(app.world().get_entity(crate_entity).is_err(), "two shots should break the crate");
+    assert_eq!(player_health(&mut app), health_before, "the pillar should stop the hostile shot");
+    let world = app.world_mut();
+    assert_eq!(world.query::<&Projectile>().iter(world).count(), 0, "obstacles should stop projectiles");
+}
+
+#[test]
+fn rebound_shield_action_follows_its_new_key() {
+    let mut app = start_game();
+    despawn_boss(&mut app);
+    app.world_mut()
+        .resource_mut::<InputBindings>()
+        .rebind(InputSlot::Shield, InputBinding::Key(KeyCode::KeyE));
+
+    app.press_mouse_button(MouseButton::Right);
+    app.run_for(0.1);
+    assert!(!shield_active(&mut app), "right mouse should no longer raise the shield");
+
+    app.release_mouse_button(MouseButton::Right);
+    app.press_key(KeyCode::KeyE);
+    app.run_for(0.1);
+    assert!(shield_active(&mut app), "the rebound key should raise the shield");
+}
+
+#[test]
+fn gamepad_sticks_move_and_aim_the_player() {
+    let mut app = start_game();
+    despawn_boss(&mut app);
+    let player = player(&mut app);
+    let start = position(&mut app, player);
+
+    let mut gamepad = Gamepad::default();
+    gamepad.analog_mut().set(GamepadAxis::LeftStickY, 1.0);
+    gamepad.analog_mut().set(GamepadAxis::RightStickX, -1.0);
+    app.world_mut().spawn(gamepad);
+    app.run_for(0.5);
+
+    assert!(position(&mut app, player).y > start.y + 50.0, "left stick should move the player up");
+    let facing = app.world().get::<Transform>(player).unwrap().rotation * Vec3::X;
+    assert!(facing.x < -0.99, "right stick should turn the player to face left, facing {facing}");
+}
+
+#[test]
+fn touching_the_boss_starts_continues_and_ends_a_collision() {
+    let mut app = start_game();
+    let (player, boss) = (player(&mut app), boss(&mut app));
+    let pair = CollisionPair::new(player, boss);
+    // Move the player to `offset` from the boss for a tick, reporting which collision events it caused
+    let step_to = |app: &mut App, offset: Vec2| {
+        let target = position(app, boss) + offset;
+        let mut transform = app.world_mut().get_mut::<Transform>(player).expect("player should have a transform");
+        transform.translation = target.extend(transform.translation.z);
+        app.update();
+        let world = app.world();
+        (
+            world.resource::<Events<CollisionStarted>>().iter_current_update_events().any(|event| event.pair == pair),
+            world.resource::<Events<CollisionOngoing>>().iter_current_update_events().any(|event| event.pair == pair),
+            world.resource::<Events<CollisionEnded>>().iter_current_update_events().any(|event| event.pair == pair),
+        )
+    };
+    // Towards the middle of the arena, well clear of the boss
+    let away = Vec2::new(-400.0_f32.copysign(position(&mut app, boss).x), 0.0);
+
+    assert_eq!(step_to(&mut app, Vec2::ZERO), (true, false, false));
+    assert_eq!(step_to(&mut app, Vec2::ZERO), (false, true, false));
+    assert_eq!(step_to(&mut app, away), (false, false, true));
+    assert_eq!(step_to(&mut app, away), (false, false, false));
+}
+
+/// Load the encounters, their bosses and the weapons before entering the game so the boss spawns on the first tick of every run
+fn load_fight_assets(app: &mut App) {
+    app.update();
+    let loaded = app.wait_for(Duration::from_secs(10), |world| {
+        let sequence = &world.resource::<EncounterAssets>().sequence;
+        world.resource::<AssetServer>().is_loaded_with_dependencies(sequence)
+            && !world.resource::<Assets<WeaponRegistry>>().is_empty()
+    });
+    assert!(loaded, "encounter sequence, boss definitions and weapon registry should load");
+}
+
+/// Where the simulation left an entity at the end of the last tick
+fn simulated_position(app: &mut App, entity: Entity) -> Vec2 {
+    let simulated = app.world().get::<InterpolatedTransform>(entity).and_then(|interpolated| interpolated.current);
+    simulated.expect("entity should have been simulated").translation.truncate()
+}
+
+/// Fight the Mine boss for `ticks` simulation ticks while walking diagonally and firing, running
+/// `ticks_per_frame` ticks per rendered frame. Returns the player's health and position, the boss's
+/// position and where the player's projectiles and the boss's mines are.
+fn simulate_fight(ticks_per_frame: u32, ticks: u32) -> (f32, Vec2, Vec2, Vec<Vec2>, Vec<Vec2>) {
+    let mut app = headless_app();
+    let tick = Time::<Fixed>::from_hz(SimulationSettings::DEFAULT_TICK_RATE).timestep();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(tick));
+    load_fight_assets(&mut app);
+    // The fight drops the actions held on the frame it starts, so start it on a frame of a single tick
+    app.enter_state(AppState::Game);
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(tick * ticks_per_frame));
+    app.aim_at(Vec2::new(0.0, 300.0));
+    app.press_key(KeyCode::KeyW);
+    app.press_key(KeyCode::KeyA);
+    app.press_mouse_button(MouseButton::Left);
+    for _ in 0..ticks / ticks_per_frame {
+        app.update();
+    }
+
+    let (player, boss) = (player(&mut app), boss(&mut app));
+    let world = app.world_mut();
+    let mut projectiles: Vec<Vec2> = world
+        .query_filtered::<&InterpolatedTransform, With<Projectile>>()
+        .iter(world)
+        .filter_map(|interpolated| interpolated.current.map(|current| current.translation.truncate()))
+        .collect();
+    projectiles.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    let mut mines: Vec<Vec2> = world
+        .query_filtered::<&Transform, With<Mine>>()
+        .iter(world)
+        .map(|transform| transform.translation.truncate())
+        .collect();
+    mines.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    (player_health(&mut app), simulated_position(&mut app, player), simulated_position(&mut app, boss), projectiles, mines)
+}
+
+#[test]
+fn simulation_does_not_depend_on_frame_rate() {
+    // Long enough for the boss to lay its first mines
+    let at_60_fps = simulate_fight(1, 480);
+    let at_20_fps = simulate_fight(3, 480);
+
+    assert!(!at_60_fps.3.is_empty(), "the player should have fired");
+    assert!(!at_60_fps.4.is_empty(), "the boss should have laid mines");
+    assert_eq!(at_60_fps, at_20_fps);
+}
+
+/// Player health and boss position after every frame until the game is over, with `drive` pressing
+/// keys and buttons before each frame
+fn fight_history(app: &mut App, mut drive: impl FnMut(&mut App, u32)) -> Vec<(f32, Option<Vec2>)> {
+    let mut history = Vec::new();
+    for frame in 0..3600 {
+        if current_state(app.world()) != AppState::Game {
+            return history;
+        }
+        drive(app, frame);
+        app.update();
+        let world = app.world_mut();
+        let health = world.query_filtered::<&Health, With<Player>>().single(world).map_or(0.0, |health| health.current);
+        let boss = world.query_filtered::<&Transform, With<Boss>>().single(world).ok().map(|transform| transform.translation.truncate());
+        history.push((health, boss));
+    }
+    panic!("fight should end within a minute");
+}
+
+#[test]
+fn replay_reproduces_the_recorded_fight() {
+    let mut app = headless_app();
+    load_fight_assets(&mut app);
+    app.enter_state(AppState::Game);
+
+    let recorded = fight_history(&mut app, |app, frame| match frame {
+        0 => {
+            app.aim_at(Vec2::new(0.0, 300.0));
+            app.press_key(KeyCode::KeyD);
+            app.press_mouse_button(MouseButton::Left);
+        }
+        40 => app.press_key(KeyCode::Digit2),
+        41 => app.release_key(KeyCode::Digit2),
+        60 => {
+            app.release_key(KeyCode::KeyD);
+            app.release_mouse_button(MouseButton::Left);
+            app.press_key(KeyCode::KeyW);
+            app.press_mouse_button(MouseButton::Right);
+        }
+        100 => app.press_key(KeyCode::Space),
+        120 => {
+            app.release_key(KeyCode::KeyW);
+            app.release_key(KeyCode::Space);
+            app.release_mouse_button(MouseButton::Right);
+        }
+        _ => {}
+    });
+    assert_eq!(current_state(app.world()), AppState::GameOver);
+
+    let replay = app.world().resource::<LastReplay>().replay.clone().expect("the fight should have been recorded");
+    assert!(replay.tick_count() >= recorded.len(), "every tick of the fight should be recorded");
+    app.insert_resource(ReplayPlayback::new(&replay));
+    app.enter_state(AppState::Game);
+    let replayed = fight_history(&mut app, |_, _| {});
+
+    assert_eq!(recorded, replayed);
+}
+
+#[test]
+fn saved_replays_that_cannot_be_played_back_are_not_offered() {
+    let mut app = headless_app();
+    load_fight_assets(&mut app);
+    let world = app.world();
+    let sequence = world.resource::<Assets<EncounterSequence>>().get(&world.resource::<EncounterAssets>().sequence).expect("sequence should be loaded");
+    let directory = std::env::temp_dir().join(format!("gun_shield_elements-replays-{}", std::process::id()));
+    let storage = ConfigStorage { location: StorageLocation::Directory(directory.clone()) };
+
+    let newest_playable = |tick_rate: f64, encounter: usize| {
+        let _ = std::fs::remove_dir_all(&directory);
+        let replay = Replay::new(tick_rate, encounter, Loadout::default(), Difficulty::Normal, &[TickInput::default()]);
+        storage.save_compact(&Replay::file_name(1), &replay);
+        find_last_replay(&mut LastReplay::default(), &storage, Some(sequence)).is_some()
+    };
+    assert!(newest_playable(SimulationSettings::DEFAULT_TICK_RATE, 0));
+    assert!(!newest_playable(0.0, 0), "a replay with no ticks per second can't be played");
+    assert!(!newest_playable(f64::NAN, 0), "a replay with no ticks per second can't be played");
+    assert!(!newest_playable(SimulationSettings::DEFAULT_TICK_RATE, sequence.encounters.len()), "a replay of a missing encounter can't be played");
+    let _ = std::fs::remove_dir_all(&directory);
+}
+
+#[test]
+fn defeating_the_boss_shows_victory_with_fight_stats() {
+    let mut app = start_game();
+    let player = player(&mut app);
+    let boss = boss(&mut app);
+    let boss_health = app.world().get::<Health>(boss).expect("boss should have health").current;
+
+    app.world_mut().send_event(DamageEvent {
+        source: player,
+        target: boss,
+        amount: boss_health * 10.0,
+        kind: DamageKind::Projectile,
+        direction: Vec2::ZERO,
+    });
+    app.update();
+    app.update();
+
+    assert_eq!(current_state(app.world()), AppState::Victory);
+    let stats = app.world().resource::<FightStats>();
+    assert!(stats.damage_dealt > 0.0 && stats.damage_dealt <= boss_health, "damage dealt should stop at the boss's health");
+    assert!(stats.duration > 0.0);
+    let profile = app.world().resource::<Profile>();
+    assert!(profile.campaign.beaten.contains("Minefield"), "winning should mark the encounter as beaten");
+    assert_eq!(profile.lifetime.victories, 1);
+    let best = profile.personal_best("Minefield", Difficulty::Normal).expect("winning should set a personal best");
+    assert_eq!(best.fastest_time, stats.duration);
+    assert!(profile.personal_best("Minefield", Difficulty::Hard).is_none(), "bests are kept per difficulty");
+}
+
+#[test]
+fn difficulty_scales_boss_health_and_player_energy_recharge() {
+    let scaled = |difficulty: Difficulty| {
+        let mut app = start_game_on(difficulty);
+        let (player, boss) = (player(&mut app), boss(&mut app));
+        let boss_health = app.world().get::<Health>(boss).expect("boss should have health").max;
+        let recharge = app.world().get::<Energy>(player).expect("player should have energy").recharge_rate;
+        (boss_health, recharge)
+    };
+    let (normal_health, normal_recharge) = scaled(Difficulty::Normal);
+    let (hard_health, hard_recharge) = scaled(Difficulty::Hard);
+
+    let multipliers = Difficulty::Hard.multipliers();
+    assert_eq!(hard_health, normal_health * multipliers.boss_health);
+    assert_eq!(hard_recharge, normal_recharge * multipliers.energy_recharge);
+}
@@ -0,0 +1,78 @@
+use std::path::PathBuf;
+use gun_shield_elements::persistence::{ConfigStorage, GameSettings, Profile, ProfileError, StorageLocation};
+use gun_shield_elements::resources::{Difficulty, FightStats, SimulationSettings};
+
+/// Storage in an empty directory of its own under the system temporary directory
+fn temporary_storage(name: &str) -> (ConfigStorage, PathBuf) {
+    let directory = std::env::temp_dir().join(format!("gun_shield_elements-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&directory);
+    (ConfigStorage { location: StorageLocation::Directory(directory.clone()) }, directory)
+}
+
+fn won_profile() -> Profile {
+    let mut profile = Profile::default();
+    let stats = FightStats { duration: 42.0, damage_taken: 30.0, ..Default::default() };
+    profile.record_fight(&stats, true);
+    profile.record_victory("Minefield", Difficulty::Normal, &stats);
+    profile
+}
+
+#[test]
+fn corrupted_profile_falls_back_to_its_backup() {
+    let (storage, directory) = temporary_storage("backup");
+    storage.save(Profile::FILE_NAME, &won_profile());
+    storage.save(Profile::FILE_NAME, &won_profile());
+    std::fs::write(directory.join(Profile::FILE_NAME), "(version: 1, campaign: (beat").unwrap();
+
+    let loaded = storage.load_with(Profile::FILE_NAME, Profile::from_ron).expect("profile should exist");
+
+    let profile = loaded.expect("the backup should be readable");
+    assert!(profile.campaign.beaten.contains("Minefield"));
+    assert_eq!(profile.lifetime.victories, 1);
+    assert_eq!(profile.personal_best("Minefield", Difficulty::Normal).map(|best| best.fastest_time), Some(42.0));
+    let _ = std::fs::remove_dir_all(directory);
+}
+
+#[test]
+fn profile_from_a_newer_version_is_rejected() {
+    let contents = format!("(version: {}, campaign: (beaten: [\"Minefield\"]))", Profile::VERSION + 1);
+
+    let result = Profile::from_ron(&contents);
+
+    assert!(matches!(result, Err(ProfileError::Newer(version)) if version == Profile::VERSION + 1));
+}
+
+#[test]
+fn fields_missing_from_a_saved_profile_get_defaults() {
+    let contents = format!("(version: {}, campaign: (beaten: [\"Minefield\"]), settings: (vsync: false))", Profile::VERSION);
+
+    let profile = Profile::from_ron(&contents).expect("an older profile of the same version should load");
+
+    assert!(profile.campaign.beaten.contains("Minefield"));
+    assert!(!profile.settings.vsync);
+    assert_eq!(profile.settings.screen_shake, GameSettings::default().screen_shake);
+}
+
+#[test]
+fn version_1_personal_bests_are_migrated_to_normal_difficulty() {
+    let contents = "(version: 1, campaign: (beaten: [\"Minefield\"]), personal_bests: {\"Minefield\": (fastest_time: 42.0, least_damage_taken: 30.0)})";
+
+    let profile = Profile::from_ron(contents).expect("a version 1 profile should be migrated");
+
+    assert_eq!(profile.version, Profile::VERSION);
+    assert!(profile.campaign.beaten.contains("Minefield"));
+    assert_eq!(profile.personal_best("Minefield", Difficulty::Normal).map(|best| best.fastest_time), Some(42.0));
+    assert!(profile.personal_best("Minefield", Difficulty::Hard).is_none());
+}
+
+#[test]
+fn unsupported_tick_rates_fall_back_to_the_default() {
+    for tick_rate in ["0.0", "-60.0", "inf", "NaN", "100000.0"] {
+        let contents = format!("(version: {}, settings: (tick_rate: {}, vsync: false))", Profile::VERSION, tick_rate);
+
+        let profile = Profile::from_ron(&contents).expect("a profile with an unsupported tick rate should still load");
+
+        assert_eq!(profile.settings.tick_rate, SimulationSettings::DEFAULT_TICK_RATE, "tick rate {tick_rate}");
+        assert!(!profile.settings.vsync, "the other settings should be kept");
+    }
+}
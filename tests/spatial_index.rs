@@ -0,0 +1,95 @@
+use bevy::prelude::*;
+use gun_shield_elements::collision::{SpatialEntry, SpatialIndex};
+use gun_shield_elements::constants::GameBoundaries;
+
+/// Colliders of assorted sizes spread over the arena and a little past its edges, at evenly
+/// distributed but irregular positions so they land on cell boundaries and in cell interiors alike
+fn scattered_entries(count: u32) -> Vec<SpatialEntry> {
+    let mut world = World::new();
+    let extent = Vec2::new(GameBoundaries::width(), GameBoundaries::height()) * 1.1;
+    (0..count)
+        .map(|i| {
+            let fraction = Vec2::new((i as f32 * 0.618_034).fract(), (i as f32 * 0.754_878).fract());
+            SpatialEntry {
+                entity: world.spawn_empty().id(),
+                position: (fraction - 0.5) * extent,
+                radius: [4.0, 10.0, 25.0, 60.0, 300.0][i as usize % 5],
+            }
+        })
+        .collect()
+}
+
+fn index_of(entries: &[SpatialEntry]) -> SpatialIndex {
+    let mut index = SpatialIndex::default();
+    for entry in entries {
+        index.insert(entry.entity, entry.position, entry.radius);
+    }
+    index
+}
+
+#[test]
+fn circle_queries_match_a_brute_force_search() {
+    let entries = scattered_entries(2000);
+    let index = index_of(&entries);
+
+    for (center, radius) in [(Vec2::ZERO, 100.0), (Vec2::new(3190.0, -1790.0), 40.0), (Vec2::new(-3500.0, 500.0), 250.0), (Vec2::new(128.0, 256.0), 0.0)] {
+        let mut found: Vec<Entity> = index.query_circle(center, radius).map(|entry| entry.entity).collect();
+        let mut expected: Vec<Entity> = entries
+            .iter()
+            .filter(|entry| entry.position.distance(center) < radius + entry.radius)
+            .map(|entry| entry.entity)
+            .collect();
+        found.sort();
+        expected.sort();
+        assert_eq!(found, expected, "query around {center} with radius {radius}");
+    }
+}
+
+#[test]
+fn nearest_matches_a_brute_force_search() {
+    let entries = scattered_entries(500);
+    let index = index_of(&entries);
+    let large = |entry: &SpatialEntry| entry.radius >= 60.0;
+
+    for (center, max_distance) in [(Vec2::ZERO, f32::INFINITY), (Vec2::new(-3300.0, 1900.0), f32::INFINITY), (Vec2::new(1000.0, 0.0), 150.0)] {
+        let nearest = index.nearest(center, max_distance, large).map(|entry| entry.entity);
+        let expected = entries
+            .iter()
+            .filter(|entry| large(entry) && entry.position.distance(center) <= max_distance)
+            .min_by(|a, b| a.position.distance(center).total_cmp(&b.position.distance(center)))
+            .map(|entry| entry.entity);
+        assert_eq!(nearest, expected, "nearest to {center} within {max_distance}");
+    }
+}
+
+#[test]
+fn ray_casts_hit_the_first_collider_along_the_ray() {
+    let entries = scattered_entries(1000);
+    let index = index_of(&entries);
+
+    for (origin, direction, max_distance) in [
+        (Vec2::ZERO, Vec2::X, f32::INFINITY),
+        (Vec2::new(-3600.0, -1000.0), Vec2::new(1.0, 0.3), 5000.0),
+        (Vec2::new(200.0, 1700.0), Vec2::NEG_Y, 300.0),
+        (Vec2::new(10.0, 10.0), Vec2::new(-0.5, -0.5), f32::INFINITY),
+    ] {
+        let hit = index.ray_cast(origin, direction, max_distance, |_| true).map(|hit| hit.entity);
+        let direction = direction.normalize();
+        let expected = entries
+            .iter()
+            .filter_map(|entry| {
+                // Closest approach of the ray to the center, then back to where it crosses the edge
+                let along = (entry.position - origin).dot(direction).max(0.0);
+                let miss = (origin + direction * along).distance(entry.position);
+                let distance = if origin.distance(entry.position) <= entry.radius {
+                    0.0
+                } else {
+                    along - (entry.radius * entry.radius - miss * miss).sqrt()
+                };
+                (miss <= entry.radius && distance <= max_distance).then_some((distance, entry.entity))
+            })
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, entity)| entity);
+        assert_eq!(hit, expected, "ray from {origin} along {direction}");
+    }
+}